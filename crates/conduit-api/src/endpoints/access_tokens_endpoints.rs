@@ -0,0 +1,76 @@
+use axum::extract::Path;
+use axum::routing::{delete, get, post};
+use axum::{Extension, Json, Router};
+use tracing::info;
+
+use conduit_core::access_tokens::service::DynAccessTokensService;
+use conduit_core::errors::ConduitResult;
+use conduit_domain::access_tokens::requests::CreateAccessTokenRequest;
+use conduit_domain::access_tokens::responses::{AccessTokenResponse, AccessTokensResponse};
+use conduit_infrastructure::service_register::ServiceRegister;
+
+use crate::extractors::required_authentication_extractor::RequiredAuthentication;
+use crate::extractors::validation_extractor::ValidationExtractor;
+
+pub struct AccessTokensRouter;
+
+impl AccessTokensRouter {
+    pub fn new_router(service_register: ServiceRegister) -> Router {
+        Router::new()
+            .route("/user/tokens", get(AccessTokensRouter::get_access_tokens_endpoint))
+            .route("/user/tokens", post(AccessTokensRouter::create_access_token_endpoint))
+            .route(
+                "/user/tokens/:id",
+                delete(AccessTokensRouter::revoke_access_token_endpoint),
+            )
+            .layer(Extension(service_register.access_tokens_service))
+            .layer(Extension(service_register.token_service))
+            .layer(Extension(service_register.sessions_service))
+    }
+
+    pub async fn get_access_tokens_endpoint(
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        Extension(access_tokens_service): Extension<DynAccessTokensService>,
+    ) -> ConduitResult<Json<AccessTokensResponse>> {
+        info!(
+            "recieved request to retrieve personal access tokens for user {:?}",
+            user_id
+        );
+
+        let tokens = access_tokens_service.get_access_tokens(user_id).await?;
+
+        Ok(Json(AccessTokensResponse { tokens }))
+    }
+
+    pub async fn create_access_token_endpoint(
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        ValidationExtractor(request): ValidationExtractor<CreateAccessTokenRequest>,
+        Extension(access_tokens_service): Extension<DynAccessTokensService>,
+    ) -> ConduitResult<Json<AccessTokenResponse>> {
+        info!(
+            "recieved request to create personal access token for user {:?}",
+            user_id
+        );
+
+        let token = access_tokens_service
+            .create_access_token(user_id, request.token)
+            .await?;
+
+        Ok(Json(AccessTokenResponse { token }))
+    }
+
+    pub async fn revoke_access_token_endpoint(
+        Path(id): Path<i64>,
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        Extension(access_tokens_service): Extension<DynAccessTokensService>,
+    ) -> ConduitResult<()> {
+        info!(
+            "recieved request to revoke personal access token {:?} for user {:?}",
+            id, user_id
+        );
+
+        access_tokens_service.revoke_access_token(user_id, id).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use std::str::FromStr;
+
+use axum::extract::{Path, Query};
+use axum::routing::{delete, get, post, put};
+use axum::{Extension, Json, Router};
+use tracing::info;
+
+use conduit_core::admin::service::DynAdminService;
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::users::roles::Role;
+use conduit_domain::admin::requests::{GetUsersApiRequest, UpdateUserRoleRequest};
+use conduit_domain::admin::responses::{AdminUserResponse, AdminUsersResponse};
+use conduit_infrastructure::service_register::ServiceRegister;
+
+use crate::extractors::required_authorization_extractor::RequiredAdministrator;
+use crate::extractors::validation_extractor::ValidationExtractor;
+
+pub struct AdminRouter;
+
+impl AdminRouter {
+    pub fn new_router(service_register: ServiceRegister) -> Router {
+        Router::new()
+            .route("/admin/users", get(AdminRouter::get_users_endpoint))
+            .route("/admin/users/:username", delete(AdminRouter::delete_user_endpoint))
+            .route(
+                "/admin/users/:username/role",
+                put(AdminRouter::update_user_role_endpoint),
+            )
+            .route(
+                "/admin/users/:username/suspend",
+                post(AdminRouter::suspend_user_endpoint),
+            )
+            .route(
+                "/admin/users/:username/suspend",
+                delete(AdminRouter::unsuspend_user_endpoint),
+            )
+            .route(
+                "/admin/users/:username/password-reset",
+                post(AdminRouter::force_password_reset_endpoint),
+            )
+            .layer(Extension(service_register.admin_service))
+            .layer(Extension(service_register.users_service))
+            .layer(Extension(service_register.token_service))
+            .layer(Extension(service_register.access_tokens_service))
+            .layer(Extension(service_register.sessions_service))
+    }
+
+    pub async fn get_users_endpoint(
+        query_params: Query<GetUsersApiRequest>,
+        RequiredAdministrator(admin_id): RequiredAdministrator,
+        Extension(admin_service): Extension<DynAdminService>,
+    ) -> ConduitResult<Json<AdminUsersResponse>> {
+        info!(
+            "recieved request from admin {:?} to retrieve users {:?}",
+            admin_id, query_params
+        );
+
+        let (users, users_count) = admin_service.get_users(query_params.0.into()).await?;
+
+        Ok(Json(AdminUsersResponse { users, users_count }))
+    }
+
+    pub async fn update_user_role_endpoint(
+        Path(username): Path<String>,
+        RequiredAdministrator(admin_id): RequiredAdministrator,
+        ValidationExtractor(request): ValidationExtractor<UpdateUserRoleRequest>,
+        Extension(admin_service): Extension<DynAdminService>,
+    ) -> ConduitResult<()> {
+        info!(
+            "recieved request from admin {:?} to update role for user {:?}",
+            admin_id, username
+        );
+
+        let role = Role::from_str(&request.user.role.unwrap()).map_err(ConduitError::BadRequest)?;
+
+        admin_service.update_user_role(admin_id, username, role).await?;
+
+        Ok(())
+    }
+
+    pub async fn suspend_user_endpoint(
+        Path(username): Path<String>,
+        RequiredAdministrator(admin_id): RequiredAdministrator,
+        Extension(admin_service): Extension<DynAdminService>,
+    ) -> ConduitResult<Json<AdminUserResponse>> {
+        info!(
+            "recieved request from admin {:?} to suspend user {:?}",
+            admin_id, username
+        );
+
+        let user = admin_service.suspend_user(admin_id, username).await?;
+
+        Ok(Json(AdminUserResponse { user }))
+    }
+
+    pub async fn unsuspend_user_endpoint(
+        Path(username): Path<String>,
+        RequiredAdministrator(admin_id): RequiredAdministrator,
+        Extension(admin_service): Extension<DynAdminService>,
+    ) -> ConduitResult<Json<AdminUserResponse>> {
+        info!(
+            "recieved request from admin {:?} to unsuspend user {:?}",
+            admin_id, username
+        );
+
+        let user = admin_service.unsuspend_user(username).await?;
+
+        Ok(Json(AdminUserResponse { user }))
+    }
+
+    pub async fn force_password_reset_endpoint(
+        Path(username): Path<String>,
+        RequiredAdministrator(admin_id): RequiredAdministrator,
+        Extension(admin_service): Extension<DynAdminService>,
+    ) -> ConduitResult<()> {
+        info!(
+            "recieved request from admin {:?} to force a password reset for user {:?}",
+            admin_id, username
+        );
+
+        admin_service.force_password_reset(admin_id, username).await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_user_endpoint(
+        Path(username): Path<String>,
+        RequiredAdministrator(admin_id): RequiredAdministrator,
+        Extension(admin_service): Extension<DynAdminService>,
+    ) -> ConduitResult<()> {
+        info!(
+            "recieved request from admin {:?} to delete user {:?}",
+            admin_id, username
+        );
+
+        admin_service.delete_user(admin_id, username).await?;
+
+        Ok(())
+    }
+}
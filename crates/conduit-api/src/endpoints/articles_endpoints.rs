@@ -6,16 +6,22 @@ use tracing::info;
 use conduit_core::articles::service::DynArticlesService;
 use conduit_core::comments::service::DynCommentsService;
 use conduit_core::errors::ConduitResult;
+use conduit_core::reactions::service::DynReactionsService;
 use conduit_domain::articles::requests::{
-    CreateArticleRequest, GetArticlesApiRequest, UpdateArticleRequest, LIMIT, OFFSET,
+    ArticleRevisionDiffQuery, CreateArticleRequest, GetArticlesApiRequest, UpdateArticleRequest,
 };
-use conduit_domain::articles::responses::{ArticleResponse, ArticlesResponse};
-use conduit_domain::comments::requests::CreateCommentRequest;
-use conduit_domain::comments::responses::{CommentResponse, CommentsResponse};
+use conduit_domain::articles::responses::{
+    ArticleResponse, ArticleRevisionDiffResponse, ArticleRevisionResponse, ArticleRevisionsResponse, ArticlesResponse,
+};
+use conduit_domain::comments::requests::{CreateCommentRequest, GetCommentsApiRequest, UpdateCommentRequest};
+use conduit_domain::comments::responses::{CommentResponse, CommentRevisionsResponse, CommentsResponse};
+use conduit_domain::reactions::requests::ToggleReactionRequest;
+use conduit_domain::reactions::responses::ReactionsResponse;
 use conduit_infrastructure::service_register::ServiceRegister;
 
 use crate::extractors::optional_authentication_extractor::OptionalAuthentication;
-use crate::extractors::required_authentication_extractor::RequiredAuthentication;
+use crate::extractors::required_authentication_extractor::{RequiredAuthentication, RequiredVerifiedAuthentication};
+use crate::extractors::required_authorization_extractor::RequiredAuthorization;
 use crate::extractors::validation_extractor::ValidationExtractor;
 
 pub struct ArticlesRouter;
@@ -26,17 +32,37 @@ impl ArticlesRouter {
             .route("/articles", get(get_articles))
             .route("/articles", post(create_article))
             .route("/articles/feed", get(get_article_feed))
+            .route("/user/drafts", get(get_user_drafts))
             .route("/articles/:slug", get(get_article))
             .route("/articles/:slug", put(update_article))
             .route("/articles/:slug", delete(delete_article))
+            .route("/articles/:slug/publish", post(publish_article))
+            .route("/articles/:slug/revisions", get(get_article_revisions))
+            .route("/articles/:slug/revisions/:revision", get(get_article_revision))
+            .route(
+                "/articles/:slug/revisions/:revision/diff",
+                get(get_article_revision_diff),
+            )
+            .route(
+                "/articles/:slug/revisions/:revision/restore",
+                post(restore_article_revision),
+            )
             .route("/articles/:slug/favorite", post(favorite_article))
             .route("/articles/:slug/favorite", delete(unfavorite_article))
+            .route("/articles/:slug/reactions", post(toggle_article_reaction))
             .route("/articles/:slug/comments", get(get_comments))
             .route("/articles/:slug/comments", post(add_comment))
+            .route("/articles/:slug/comments/:id", put(update_comment))
             .route("/articles/:slug/comments/:id", delete(remove_comment))
+            .route("/articles/:slug/comments/:id/revisions", get(get_comment_revisions))
+            .route("/articles/:slug/comments/:id/reactions", post(toggle_comment_reaction))
             .layer(Extension(service_register.articles_service))
             .layer(Extension(service_register.comments_service))
+            .layer(Extension(service_register.reactions_service))
+            .layer(Extension(service_register.users_service))
             .layer(Extension(service_register.token_service))
+            .layer(Extension(service_register.access_tokens_service))
+            .layer(Extension(service_register.sessions_service))
     }
 }
 
@@ -47,23 +73,9 @@ pub async fn get_articles(
 ) -> ConduitResult<Json<ArticlesResponse>> {
     info!("recieved request to retrieve articles {:?}", query_params.0);
 
-    let articles = articles_service
-        .get_articles(
-            user_id,
-            query_params.0.tag,
-            query_params.0.author,
-            query_params.0.favorited,
-            query_params.0.limit.unwrap_or_else(|| LIMIT.abs()),
-            query_params.0.offset.unwrap_or_else(|| OFFSET.abs()),
-        )
-        .await?;
-
-    let articles_count = articles.len();
+    let articles = articles_service.get_articles(user_id, query_params.0.into()).await?;
 
-    Ok(Json(ArticlesResponse {
-        articles,
-        articles_count,
-    }))
+    Ok(Json(articles))
 }
 
 pub async fn get_article_feed(
@@ -73,20 +85,21 @@ pub async fn get_article_feed(
 ) -> ConduitResult<Json<ArticlesResponse>> {
     info!("recieved request to retrieve article feed for user {:?}", user_id);
 
-    let articles = articles_service
-        .get_feed(
-            user_id,
-            query_params.0.limit.unwrap_or_else(|| LIMIT.abs()),
-            query_params.0.offset.unwrap_or_else(|| OFFSET.abs()),
-        )
-        .await?;
+    let articles = articles_service.get_feed(user_id, query_params.0.into()).await?;
 
-    let articles_count = articles.len();
+    Ok(Json(articles))
+}
 
-    Ok(Json(ArticlesResponse {
-        articles,
-        articles_count,
-    }))
+pub async fn get_user_drafts(
+    query_params: Query<GetArticlesApiRequest>,
+    Extension(articles_service): Extension<DynArticlesService>,
+    RequiredAuthentication(user_id): RequiredAuthentication,
+) -> ConduitResult<Json<ArticlesResponse>> {
+    info!("recieved request to retrieve drafts for user {:?}", user_id);
+
+    let drafts = articles_service.get_user_drafts(user_id, query_params.0.into()).await?;
+
+    Ok(Json(drafts))
 }
 
 pub async fn get_article(
@@ -96,64 +109,143 @@ pub async fn get_article(
 ) -> ConduitResult<Json<ArticleResponse>> {
     info!("recieved request to retrieve article {:?}", slug);
 
-    let article = articles_service.get_article(user_id, slug).await?;
+    let article = articles_service.get_article(user_id, slug.clone()).await?;
+
+    // articles requested by a former slug are returned all the same, hinting at the slug links should now use
+    let redirect_to = (article.slug != slug).then(|| article.slug.clone());
 
-    Ok(Json(ArticleResponse { article }))
+    Ok(Json(ArticleResponse { article, redirect_to }))
 }
 
 pub async fn create_article(
     ValidationExtractor(request): ValidationExtractor<CreateArticleRequest>,
     Extension(articles_service): Extension<DynArticlesService>,
-    RequiredAuthentication(user_id): RequiredAuthentication,
+    RequiredVerifiedAuthentication(user_id): RequiredVerifiedAuthentication,
 ) -> ConduitResult<Json<ArticleResponse>> {
     info!("recieved request to create article {:?}", request.article);
 
-    let article = articles_service
-        .create_article(
-            user_id,
-            request.article.title.unwrap(),
-            request.article.description.unwrap(),
-            request.article.body.unwrap(),
-            request.article.tag_list,
-        )
-        .await?;
+    let article = articles_service.create_article(user_id, request.article).await?;
 
-    Ok(Json(ArticleResponse { article }))
+    Ok(Json(ArticleResponse {
+        article,
+        redirect_to: None,
+    }))
 }
 
 pub async fn update_article(
     Path(slug): Path<String>,
     Json(request): Json<UpdateArticleRequest>,
     Extension(articles_service): Extension<DynArticlesService>,
-    RequiredAuthentication(user_id): RequiredAuthentication,
+    RequiredAuthorization(user_id, role): RequiredAuthorization,
 ) -> ConduitResult<Json<ArticleResponse>> {
     info!("recieved request to update article {:?}", request.article);
 
     let article = articles_service
-        .update_article(
-            user_id,
-            slug,
-            request.article.title,
-            request.article.description,
-            request.article.body,
-        )
+        .update_article(user_id, role, slug, request.article)
         .await?;
 
-    Ok(Json(ArticleResponse { article }))
+    Ok(Json(ArticleResponse {
+        article,
+        redirect_to: None,
+    }))
 }
 
 pub async fn delete_article(
     Path(slug): Path<String>,
     Extension(articles_service): Extension<DynArticlesService>,
-    RequiredAuthentication(user_id): RequiredAuthentication,
+    RequiredAuthorization(user_id, role): RequiredAuthorization,
 ) -> ConduitResult<()> {
     info!("recieved request to delete article {:?}", slug);
 
-    articles_service.delete_article(user_id, slug).await?;
+    articles_service.delete_article(user_id, role, slug).await?;
 
     Ok(())
 }
 
+pub async fn publish_article(
+    Path(slug): Path<String>,
+    Extension(articles_service): Extension<DynArticlesService>,
+    RequiredAuthentication(user_id): RequiredAuthentication,
+) -> ConduitResult<Json<ArticleResponse>> {
+    info!("recieved request to publish article {:?}", slug);
+
+    let article = articles_service.publish_article(user_id, slug).await?;
+
+    Ok(Json(ArticleResponse {
+        article,
+        redirect_to: None,
+    }))
+}
+
+pub async fn get_article_revisions(
+    Path(slug): Path<String>,
+    Extension(articles_service): Extension<DynArticlesService>,
+    OptionalAuthentication(user_id): OptionalAuthentication,
+) -> ConduitResult<Json<ArticleRevisionsResponse>> {
+    info!("recieved request to retrieve revisions for article {:?}", slug);
+
+    let revisions = articles_service.get_article_revisions(user_id, slug).await?;
+    let revisions_count = revisions.len();
+
+    Ok(Json(ArticleRevisionsResponse {
+        revisions,
+        revisions_count,
+    }))
+}
+
+pub async fn get_article_revision(
+    Path((slug, revision)): Path<(String, i32)>,
+    Extension(articles_service): Extension<DynArticlesService>,
+    OptionalAuthentication(user_id): OptionalAuthentication,
+) -> ConduitResult<Json<ArticleRevisionResponse>> {
+    info!(
+        "recieved request to retrieve revision {:?} of article {:?}",
+        revision, slug
+    );
+
+    let revision = articles_service.get_article_revision(user_id, slug, revision).await?;
+
+    Ok(Json(ArticleRevisionResponse { revision }))
+}
+
+pub async fn get_article_revision_diff(
+    Path((slug, revision)): Path<(String, i32)>,
+    Query(query): Query<ArticleRevisionDiffQuery>,
+    Extension(articles_service): Extension<DynArticlesService>,
+    OptionalAuthentication(user_id): OptionalAuthentication,
+) -> ConduitResult<Json<ArticleRevisionDiffResponse>> {
+    info!(
+        "recieved request to compare revision {:?} of article {:?}",
+        revision, slug
+    );
+
+    let diff = articles_service
+        .get_article_revision_diff(user_id, slug, revision, query.from)
+        .await?;
+
+    Ok(Json(ArticleRevisionDiffResponse { diff }))
+}
+
+pub async fn restore_article_revision(
+    Path((slug, revision)): Path<(String, i32)>,
+    Extension(articles_service): Extension<DynArticlesService>,
+    RequiredAuthentication(user_id): RequiredAuthentication,
+) -> ConduitResult<Json<ArticleResponse>> {
+    info!(
+        "recieved request to restore revision {:?} of article {:?}",
+        revision, slug
+    );
+
+    let article = articles_service
+        .restore_article_revision(user_id, slug, revision)
+        .await?;
+
+    Ok(Json(ArticleResponse {
+        article,
+        redirect_to: None,
+    }))
+}
+
 pub async fn favorite_article(
     Path(slug): Path<String>,
     Extension(articles_service): Extension<DynArticlesService>,
@@ -163,7 +255,10 @@ pub async fn favorite_article(
 
     let article = articles_service.favorite_article(user_id, slug).await?;
 
-    Ok(Json(ArticleResponse { article }))
+    Ok(Json(ArticleResponse {
+        article,
+        redirect_to: None,
+    }))
 }
 
 pub async fn unfavorite_article(
@@ -175,44 +270,120 @@ pub async fn unfavorite_article(
 
     let article = articles_service.unfavorite_article(user_id, slug).await?;
 
-    Ok(Json(ArticleResponse { article }))
+    Ok(Json(ArticleResponse {
+        article,
+        redirect_to: None,
+    }))
 }
 
 pub async fn get_comments(
     Path(slug): Path<String>,
+    query_params: Query<GetCommentsApiRequest>,
     Extension(comments_service): Extension<DynCommentsService>,
     OptionalAuthentication(user_id): OptionalAuthentication,
 ) -> ConduitResult<Json<CommentsResponse>> {
     info!("recieved request to retrieve comments for article {:?}", slug);
 
-    let comments = comments_service.get_comments(user_id, slug).await?;
+    let comments = comments_service
+        .get_comments(user_id, slug, query_params.0.into())
+        .await?;
 
-    Ok(Json(CommentsResponse { comments }))
+    Ok(Json(comments))
 }
 
 pub async fn add_comment(
     Path(slug): Path<String>,
     ValidationExtractor(request): ValidationExtractor<CreateCommentRequest>,
     Extension(comments_service): Extension<DynCommentsService>,
-    RequiredAuthentication(user_id): RequiredAuthentication,
+    RequiredVerifiedAuthentication(user_id): RequiredVerifiedAuthentication,
 ) -> ConduitResult<Json<CommentResponse>> {
     info!("recieved request to add comment for article {:?}", slug);
 
     let comment = comments_service
-        .add_comment(user_id, slug, request.comment.body.unwrap())
+        .add_comment(user_id, slug, request.comment.parent_id, request.comment.body.unwrap())
         .await?;
 
     Ok(Json(CommentResponse { comment }))
 }
 
+pub async fn update_comment(
+    Path((slug, comment_id)): Path<(String, i64)>,
+    ValidationExtractor(request): ValidationExtractor<UpdateCommentRequest>,
+    Extension(comments_service): Extension<DynCommentsService>,
+    RequiredVerifiedAuthentication(user_id): RequiredVerifiedAuthentication,
+) -> ConduitResult<Json<CommentResponse>> {
+    info!(
+        "recieved request to update comment {:?} on article {:?}",
+        comment_id, slug
+    );
+
+    let comment = comments_service
+        .update_comment(user_id, slug, comment_id, request.comment.body.unwrap())
+        .await?;
+
+    Ok(Json(CommentResponse { comment }))
+}
+
+pub async fn get_comment_revisions(
+    Path((slug, comment_id)): Path<(String, i64)>,
+    Extension(comments_service): Extension<DynCommentsService>,
+    RequiredAuthorization(_, role): RequiredAuthorization,
+) -> ConduitResult<Json<CommentRevisionsResponse>> {
+    info!(
+        "recieved request to retrieve revisions for comment {:?} on article {:?}",
+        comment_id, slug
+    );
+
+    let revisions = comments_service.get_comment_revisions(role, slug, comment_id).await?;
+    let revisions_count = revisions.len();
+
+    Ok(Json(CommentRevisionsResponse {
+        revisions,
+        revisions_count,
+    }))
+}
+
 pub async fn remove_comment(
-    Path(comment_id): Path<i64>,
+    Path((_, comment_id)): Path<(String, i64)>,
     Extension(comments_service): Extension<DynCommentsService>,
-    RequiredAuthentication(user_id): RequiredAuthentication,
+    RequiredAuthorization(user_id, role): RequiredAuthorization,
 ) -> ConduitResult<()> {
     info!("recieved request to remove comment {:?}", comment_id);
 
-    comments_service.remove_comment(user_id, comment_id).await?;
+    comments_service.remove_comment(user_id, role, comment_id).await?;
 
     Ok(())
 }
+
+pub async fn toggle_article_reaction(
+    Path(slug): Path<String>,
+    ValidationExtractor(request): ValidationExtractor<ToggleReactionRequest>,
+    Extension(reactions_service): Extension<DynReactionsService>,
+    RequiredVerifiedAuthentication(user_id): RequiredVerifiedAuthentication,
+) -> ConduitResult<Json<ReactionsResponse>> {
+    info!("recieved request to toggle reaction on article {:?}", slug);
+
+    let reactions = reactions_service
+        .toggle_article_reaction(user_id, slug, request.reaction.emoji.unwrap())
+        .await?;
+
+    Ok(Json(reactions))
+}
+
+pub async fn toggle_comment_reaction(
+    Path((slug, comment_id)): Path<(String, i64)>,
+    ValidationExtractor(request): ValidationExtractor<ToggleReactionRequest>,
+    Extension(reactions_service): Extension<DynReactionsService>,
+    RequiredVerifiedAuthentication(user_id): RequiredVerifiedAuthentication,
+) -> ConduitResult<Json<ReactionsResponse>> {
+    info!(
+        "recieved request to toggle reaction on comment {:?} on article {:?}",
+        comment_id, slug
+    );
+
+    let reactions = reactions_service
+        .toggle_comment_reaction(user_id, slug, comment_id, request.reaction.emoji.unwrap())
+        .await?;
+
+    Ok(Json(reactions))
+}
@@ -0,0 +1,102 @@
+use axum::extract::Path;
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Extension, Router};
+use tracing::info;
+
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::feeds::service::DynFeedsService;
+use conduit_core::feeds::{Feed, FeedFormat};
+use conduit_infrastructure::service_register::ServiceRegister;
+
+pub struct FeedsRouter;
+
+impl FeedsRouter {
+    pub fn new_router(service_register: ServiceRegister) -> Router {
+        Router::new()
+            .route("/feeds/:file_name", get(FeedsRouter::get_articles_feed_endpoint))
+            .route(
+                "/feeds/profiles/:file_name",
+                get(FeedsRouter::get_profile_feed_endpoint),
+            )
+            .route("/feeds/tags/:file_name", get(FeedsRouter::get_tag_feed_endpoint))
+            .layer(Extension(service_register.feeds_service))
+    }
+
+    pub async fn get_articles_feed_endpoint(
+        Path(file_name): Path<String>,
+        headers: HeaderMap,
+        Extension(feeds_service): Extension<DynFeedsService>,
+    ) -> ConduitResult<Response> {
+        info!("recieved request to retrieve articles feed {:?}", file_name);
+
+        let format = match FeedFormat::split_file_name(&file_name) {
+            Some((name, format)) if name == "articles" => format,
+            _ => return Err(ConduitError::NotFound(String::from("feed was not found"))),
+        };
+
+        let feed = feeds_service.get_articles_feed(format, None, None).await?;
+
+        Ok(Self::feed_response(format, feed, &headers))
+    }
+
+    pub async fn get_profile_feed_endpoint(
+        Path(file_name): Path<String>,
+        headers: HeaderMap,
+        Extension(feeds_service): Extension<DynFeedsService>,
+    ) -> ConduitResult<Response> {
+        info!("recieved request to retrieve profile feed {:?}", file_name);
+
+        let (username, format) = FeedFormat::split_file_name(&file_name)
+            .ok_or_else(|| ConduitError::NotFound(String::from("feed was not found")))?;
+
+        let feed = feeds_service.get_articles_feed(format, Some(username), None).await?;
+
+        Ok(Self::feed_response(format, feed, &headers))
+    }
+
+    pub async fn get_tag_feed_endpoint(
+        Path(file_name): Path<String>,
+        headers: HeaderMap,
+        Extension(feeds_service): Extension<DynFeedsService>,
+    ) -> ConduitResult<Response> {
+        info!("recieved request to retrieve tag feed {:?}", file_name);
+
+        let (tag, format) = FeedFormat::split_file_name(&file_name)
+            .ok_or_else(|| ConduitError::NotFound(String::from("feed was not found")))?;
+
+        let feed = feeds_service.get_articles_feed(format, None, Some(tag)).await?;
+
+        Ok(Self::feed_response(format, feed, &headers))
+    }
+
+    /// Responds with the feed, or with `304 Not Modified` when the client's cached copy is still current.
+    /// Only the entity tag is offered as a validator, as the latest update to the feed's articles does not change
+    /// when an article leaves the feed.
+    fn feed_response(format: FeedFormat, feed: Feed, request_headers: &HeaderMap) -> Response {
+        let not_modified = request_headers
+            .get(header::IF_NONE_MATCH)
+            .and_then(|if_none_match| if_none_match.to_str().ok())
+            .map(|etags| {
+                etags
+                    .split(',')
+                    .any(|etag| etag.trim() == feed.etag || etag.trim() == "*")
+            })
+            .unwrap_or(false);
+
+        let mut response_headers = HeaderMap::new();
+
+        if let Ok(etag) = HeaderValue::from_str(&feed.etag) {
+            response_headers.insert(header::ETAG, etag);
+        }
+
+        if not_modified {
+            return (StatusCode::NOT_MODIFIED, response_headers).into_response();
+        }
+
+        response_headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(format.content_type()));
+
+        (StatusCode::OK, response_headers, feed.content).into_response()
+    }
+}
@@ -1,4 +1,10 @@
+pub mod access_tokens_endpoints;
+pub mod admin_endpoints;
 pub mod articles_endpoints;
+pub mod feeds_endpoints;
+pub mod notifications_endpoints;
 pub mod profiles_endpoints;
 pub mod tags_endpoints;
+pub mod trash_endpoints;
+pub mod two_factor_endpoints;
 pub mod users_endpoints;
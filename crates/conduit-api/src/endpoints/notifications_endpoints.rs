@@ -0,0 +1,76 @@
+use axum::extract::{Path, Query};
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use tracing::info;
+
+use conduit_core::errors::ConduitResult;
+use conduit_core::notifications::service::DynNotificationsService;
+use conduit_domain::notifications::requests::GetNotificationsApiRequest;
+use conduit_domain::notifications::responses::NotificationsResponse;
+use conduit_infrastructure::service_register::ServiceRegister;
+
+use crate::extractors::required_authentication_extractor::RequiredAuthentication;
+
+pub struct NotificationsRouter;
+
+impl NotificationsRouter {
+    pub fn new_router(service_register: ServiceRegister) -> Router {
+        Router::new()
+            .route(
+                "/user/notifications",
+                get(NotificationsRouter::get_notifications_endpoint),
+            )
+            .route(
+                "/user/notifications/read",
+                post(NotificationsRouter::mark_all_notifications_read_endpoint),
+            )
+            .route(
+                "/user/notifications/:id/read",
+                post(NotificationsRouter::mark_notification_read_endpoint),
+            )
+            .layer(Extension(service_register.notifications_service))
+            .layer(Extension(service_register.token_service))
+            .layer(Extension(service_register.access_tokens_service))
+            .layer(Extension(service_register.sessions_service))
+    }
+
+    pub async fn get_notifications_endpoint(
+        query_params: Query<GetNotificationsApiRequest>,
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        Extension(notifications_service): Extension<DynNotificationsService>,
+    ) -> ConduitResult<Json<NotificationsResponse>> {
+        info!("recieved request to retrieve notifications for user {:?}", user_id);
+
+        let notifications = notifications_service
+            .get_notifications(user_id, query_params.0.into())
+            .await?;
+
+        Ok(Json(notifications))
+    }
+
+    pub async fn mark_all_notifications_read_endpoint(
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        Extension(notifications_service): Extension<DynNotificationsService>,
+    ) -> ConduitResult<()> {
+        info!("recieved request to mark all notifications read for user {:?}", user_id);
+
+        notifications_service.mark_all_notifications_read(user_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn mark_notification_read_endpoint(
+        Path(id): Path<i64>,
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        Extension(notifications_service): Extension<DynNotificationsService>,
+    ) -> ConduitResult<()> {
+        info!(
+            "recieved request to mark notification {:?} read for user {:?}",
+            id, user_id
+        );
+
+        notifications_service.mark_notification_read(user_id, id).await?;
+
+        Ok(())
+    }
+}
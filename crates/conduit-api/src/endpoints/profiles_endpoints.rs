@@ -23,6 +23,8 @@ impl ProfilesRouter {
             .route("/profiles/:username/follow", delete(unfollow_user))
             .layer(Extension(service_register.profiles_service))
             .layer(Extension(service_register.token_service))
+            .layer(Extension(service_register.access_tokens_service))
+            .layer(Extension(service_register.sessions_service))
     }
 }
 
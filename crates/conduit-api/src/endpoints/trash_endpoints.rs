@@ -0,0 +1,67 @@
+use axum::extract::Path;
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use tracing::info;
+
+use conduit_core::errors::ConduitResult;
+use conduit_core::trash::service::DynTrashService;
+use conduit_domain::trash::responses::TrashResponse;
+use conduit_infrastructure::service_register::ServiceRegister;
+
+use crate::extractors::required_authentication_extractor::RequiredAuthentication;
+
+pub struct TrashRouter;
+
+impl TrashRouter {
+    pub fn new_router(service_register: ServiceRegister) -> Router {
+        Router::new()
+            .route("/user/trash", get(TrashRouter::get_trash_endpoint))
+            .route(
+                "/user/trash/articles/:slug/restore",
+                post(TrashRouter::restore_article_endpoint),
+            )
+            .route(
+                "/user/trash/comments/:id/restore",
+                post(TrashRouter::restore_comment_endpoint),
+            )
+            .layer(Extension(service_register.trash_service))
+            .layer(Extension(service_register.token_service))
+            .layer(Extension(service_register.access_tokens_service))
+            .layer(Extension(service_register.sessions_service))
+    }
+
+    pub async fn get_trash_endpoint(
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        Extension(trash_service): Extension<DynTrashService>,
+    ) -> ConduitResult<Json<TrashResponse>> {
+        info!("recieved request to retrieve trash for user {:?}", user_id);
+
+        let (articles, comments) = trash_service.get_trash(user_id).await?;
+
+        Ok(Json(TrashResponse { articles, comments }))
+    }
+
+    pub async fn restore_article_endpoint(
+        Path(slug): Path<String>,
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        Extension(trash_service): Extension<DynTrashService>,
+    ) -> ConduitResult<()> {
+        info!("recieved request to restore article {:?} for user {:?}", slug, user_id);
+
+        trash_service.restore_article(user_id, slug).await?;
+
+        Ok(())
+    }
+
+    pub async fn restore_comment_endpoint(
+        Path(id): Path<i64>,
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        Extension(trash_service): Extension<DynTrashService>,
+    ) -> ConduitResult<()> {
+        info!("recieved request to restore comment {:?} for user {:?}", id, user_id);
+
+        trash_service.restore_comment(user_id, id).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use axum::routing::post;
+use axum::{Extension, Json, Router};
+use tracing::info;
+
+use conduit_core::errors::ConduitResult;
+use conduit_core::sessions::service::DynSessionsService;
+use conduit_core::two_factor::service::DynTwoFactorService;
+use conduit_domain::two_factor::requests::{TwoFactorCodeRequest, TwoFactorLoginRequest};
+use conduit_domain::two_factor::responses::{RecoveryCodesResponse, TwoFactorEnrollmentResponse};
+use conduit_domain::users::responses::UserAuthenicationResponse;
+use conduit_infrastructure::service_register::ServiceRegister;
+
+use crate::extractors::required_authentication_extractor::RequiredAuthentication;
+use crate::extractors::validation_extractor::ValidationExtractor;
+
+pub struct TwoFactorRouter;
+
+impl TwoFactorRouter {
+    pub fn new_router(service_register: ServiceRegister) -> Router {
+        Router::new()
+            .route("/users/login/2fa", post(TwoFactorRouter::complete_login_endpoint))
+            .route("/user/2fa", post(TwoFactorRouter::begin_enrollment_endpoint))
+            .route("/user/2fa/confirm", post(TwoFactorRouter::confirm_enrollment_endpoint))
+            .route("/user/2fa/disable", post(TwoFactorRouter::disable_two_factor_endpoint))
+            .layer(Extension(service_register.two_factor_service))
+            .layer(Extension(service_register.token_service))
+            .layer(Extension(service_register.access_tokens_service))
+            .layer(Extension(service_register.sessions_service))
+    }
+
+    pub async fn complete_login_endpoint(
+        ValidationExtractor(request): ValidationExtractor<TwoFactorLoginRequest>,
+        Extension(two_factor_service): Extension<DynTwoFactorService>,
+        Extension(sessions_service): Extension<DynSessionsService>,
+    ) -> ConduitResult<Json<UserAuthenicationResponse>> {
+        info!("recieved request to complete two-factor login");
+
+        let mut user = two_factor_service
+            .complete_login(
+                request.two_factor.challenge_token.unwrap(),
+                request.two_factor.code.unwrap(),
+            )
+            .await?;
+        user.refresh_token = Some(sessions_service.new_refresh_token(user.id).await?);
+
+        Ok(Json(UserAuthenicationResponse { user }))
+    }
+
+    pub async fn begin_enrollment_endpoint(
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        Extension(two_factor_service): Extension<DynTwoFactorService>,
+    ) -> ConduitResult<Json<TwoFactorEnrollmentResponse>> {
+        info!("recieved request to begin two-factor enrollment for user {:?}", user_id);
+
+        let two_factor = two_factor_service.begin_enrollment(user_id).await?;
+
+        Ok(Json(TwoFactorEnrollmentResponse { two_factor }))
+    }
+
+    pub async fn confirm_enrollment_endpoint(
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        ValidationExtractor(request): ValidationExtractor<TwoFactorCodeRequest>,
+        Extension(two_factor_service): Extension<DynTwoFactorService>,
+    ) -> ConduitResult<Json<RecoveryCodesResponse>> {
+        info!(
+            "recieved request to confirm two-factor enrollment for user {:?}",
+            user_id
+        );
+
+        let recovery_codes = two_factor_service
+            .confirm_enrollment(user_id, request.two_factor.code.unwrap())
+            .await?;
+
+        Ok(Json(RecoveryCodesResponse { recovery_codes }))
+    }
+
+    pub async fn disable_two_factor_endpoint(
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        ValidationExtractor(request): ValidationExtractor<TwoFactorCodeRequest>,
+        Extension(two_factor_service): Extension<DynTwoFactorService>,
+    ) -> ConduitResult<()> {
+        info!(
+            "recieved request to disable two-factor authentication for user {:?}",
+            user_id
+        );
+
+        two_factor_service
+            .disable_two_factor(user_id, request.two_factor.code.unwrap())
+            .await?;
+
+        Ok(())
+    }
+}
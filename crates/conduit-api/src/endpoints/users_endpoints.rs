@@ -1,14 +1,22 @@
+use axum::extract::Query;
 use axum::routing::{get, post, put};
 use axum::{Extension, Json, Router};
 use tracing::info;
 
 use conduit_core::errors::ConduitResult;
+use conduit_core::sessions::service::DynSessionsService;
 use conduit_core::users::service::DynUsersService;
-use conduit_domain::users::requests::{LoginUserRequest, RegisterUserRequest, UpdateUserRequest};
-use conduit_domain::users::responses::UserAuthenicationResponse;
+use conduit_domain::users::requests::{
+    ConfirmPasswordResetRequest, LoginUserRequest, PasswordResetRequest, RefreshTokenRequest, RegisterUserRequest,
+    UpdateUserRequest, VerifyEmailQuery,
+};
+use conduit_domain::two_factor::responses::TwoFactorChallengeResponse;
+use conduit_domain::users::responses::{LoginUserResponse, UserAuthenicationResponse};
+use conduit_domain::users::LoginResultDto;
 use conduit_infrastructure::service_register::ServiceRegister;
 
-use crate::extractors::required_authentication_extractor::RequiredAuthentication;
+use crate::extractors::client_address_extractor::ClientAddress;
+use crate::extractors::required_authentication_extractor::{RequiredAuthentication, RequiredAuthenticationClaims};
 use crate::extractors::validation_extractor::ValidationExtractor;
 
 pub struct UsersRouter;
@@ -18,15 +26,31 @@ impl UsersRouter {
         Router::new()
             .route("/users", post(UsersRouter::register_user_endpoint))
             .route("/users/login", post(UsersRouter::login_user_endpoint))
+            .route("/users/refresh", post(UsersRouter::refresh_token_endpoint))
+            .route("/users/logout", post(UsersRouter::logout_user_endpoint))
+            .route("/users/verify", get(UsersRouter::verify_email_endpoint))
+            .route("/user/verify", post(UsersRouter::resend_email_verification_endpoint))
+            .route(
+                "/users/password-reset",
+                post(UsersRouter::request_password_reset_endpoint),
+            )
+            .route(
+                "/users/password-reset/confirm",
+                post(UsersRouter::confirm_password_reset_endpoint),
+            )
             .route("/user", get(UsersRouter::get_current_user_endpoint))
             .route("/user", put(UsersRouter::update_user_endpoint))
             .layer(Extension(service_register.users_service))
+            .layer(Extension(service_register.config))
             .layer(Extension(service_register.token_service))
+            .layer(Extension(service_register.access_tokens_service))
+            .layer(Extension(service_register.sessions_service))
     }
 
     pub async fn register_user_endpoint(
         ValidationExtractor(request): ValidationExtractor<RegisterUserRequest>,
         Extension(users_service): Extension<DynUsersService>,
+        Extension(sessions_service): Extension<DynSessionsService>,
     ) -> ConduitResult<Json<UserAuthenicationResponse>> {
         info!(
             "recieved request to create user {:?}/{:?}",
@@ -34,23 +58,109 @@ impl UsersRouter {
             request.user.username.as_ref().unwrap()
         );
 
-        let created_user = users_service.register_user(request.user).await?;
+        let mut created_user = users_service.register_user(request.user).await?;
+        created_user.refresh_token = Some(sessions_service.new_refresh_token(created_user.id).await?);
 
         Ok(Json(UserAuthenicationResponse { user: created_user }))
     }
 
     pub async fn login_user_endpoint(
+        ClientAddress(client_address): ClientAddress,
         ValidationExtractor(request): ValidationExtractor<LoginUserRequest>,
         Extension(users_service): Extension<DynUsersService>,
-    ) -> ConduitResult<Json<UserAuthenicationResponse>> {
+        Extension(sessions_service): Extension<DynSessionsService>,
+    ) -> ConduitResult<Json<LoginUserResponse>> {
         info!(
             "recieved request to login user {:?}",
             request.user.email.as_ref().unwrap()
         );
 
-        let created_user = users_service.login_user(request.user).await?;
+        let response = match users_service.login_user(request.user, client_address).await? {
+            LoginResultDto::Authenticated(mut user) => {
+                user.refresh_token = Some(sessions_service.new_refresh_token(user.id).await?);
+                LoginUserResponse::Authenticated(UserAuthenicationResponse { user })
+            },
+            LoginResultDto::TwoFactorRequired(two_factor) => {
+                LoginUserResponse::TwoFactorRequired(TwoFactorChallengeResponse { two_factor })
+            },
+        };
+
+        Ok(Json(response))
+    }
 
-        Ok(Json(UserAuthenicationResponse { user: created_user }))
+    pub async fn refresh_token_endpoint(
+        ValidationExtractor(request): ValidationExtractor<RefreshTokenRequest>,
+        Extension(sessions_service): Extension<DynSessionsService>,
+    ) -> ConduitResult<Json<UserAuthenicationResponse>> {
+        info!("recieved request to refresh user token");
+
+        let refreshed_user = sessions_service
+            .refresh_session(request.user.refresh_token.unwrap())
+            .await?;
+
+        Ok(Json(UserAuthenicationResponse { user: refreshed_user }))
+    }
+
+    pub async fn logout_user_endpoint(
+        RequiredAuthenticationClaims(claims): RequiredAuthenticationClaims,
+        ValidationExtractor(request): ValidationExtractor<RefreshTokenRequest>,
+        Extension(sessions_service): Extension<DynSessionsService>,
+    ) -> ConduitResult<()> {
+        info!("recieved request to logout user {:?}", claims.user_id);
+
+        sessions_service
+            .revoke_session(claims, request.user.refresh_token.unwrap())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn verify_email_endpoint(
+        Query(query): Query<VerifyEmailQuery>,
+        Extension(users_service): Extension<DynUsersService>,
+    ) -> ConduitResult<()> {
+        info!("recieved request to verify user email");
+
+        users_service.verify_email(query.token).await?;
+
+        Ok(())
+    }
+
+    pub async fn resend_email_verification_endpoint(
+        RequiredAuthentication(user_id): RequiredAuthentication,
+        Extension(users_service): Extension<DynUsersService>,
+    ) -> ConduitResult<()> {
+        info!("recieved request to resend email verification for user {:?}", user_id);
+
+        users_service.resend_email_verification(user_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn request_password_reset_endpoint(
+        ValidationExtractor(request): ValidationExtractor<PasswordResetRequest>,
+        Extension(users_service): Extension<DynUsersService>,
+    ) -> ConduitResult<()> {
+        info!("recieved request to reset user password");
+
+        users_service
+            .request_password_reset(request.user.email.unwrap())
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn confirm_password_reset_endpoint(
+        ValidationExtractor(request): ValidationExtractor<ConfirmPasswordResetRequest>,
+        Extension(users_service): Extension<DynUsersService>,
+    ) -> ConduitResult<()> {
+        info!("recieved request to confirm user password reset");
+
+        users_service
+            .confirm_password_reset(request.user.token.unwrap(), request.user.password.unwrap())
+            .await?;
+
+        Ok(())
     }
 
     pub async fn get_current_user_endpoint(
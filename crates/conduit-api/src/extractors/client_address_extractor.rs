@@ -0,0 +1,48 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::extract::{ConnectInfo, FromRequest, RequestParts};
+use axum::Extension;
+
+use conduit_core::config::AppConfig;
+use conduit_core::errors::ConduitError;
+
+/// Extracts the address of the client making the request. Behind a reverse proxy every request appears to come from
+/// the proxy, so the `X-Forwarded-For` header is used instead when the proxy is trusted to set it.
+pub struct ClientAddress(pub Option<String>);
+
+#[async_trait]
+impl<B> FromRequest<B> for ClientAddress
+where
+    B: Send + Sync,
+{
+    type Rejection = ConduitError;
+
+    async fn from_request(request: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let Extension(config): Extension<Arc<AppConfig>> = Extension::from_request(request)
+            .await
+            .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
+
+        if config.trust_proxy_headers {
+            let forwarded_address = request
+                .headers()
+                .get("x-forwarded-for")
+                .and_then(|header| header.to_str().ok())
+                .and_then(|header| header.split(',').next())
+                .map(|address| address.trim().to_owned())
+                .filter(|address| !address.is_empty());
+
+            if forwarded_address.is_some() {
+                return Ok(ClientAddress(forwarded_address));
+            }
+        }
+
+        let peer_address = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(address)| address.ip().to_string());
+
+        Ok(ClientAddress(peer_address))
+    }
+}
@@ -1,3 +1,5 @@
+pub mod client_address_extractor;
 pub mod optional_authentication_extractor;
 pub mod required_authentication_extractor;
+pub mod required_authorization_extractor;
 pub mod validation_extractor;
@@ -1,13 +1,13 @@
 use async_trait::async_trait;
 use axum::extract::{FromRequest, RequestParts};
-use axum::Extension;
 use http::header::AUTHORIZATION;
 use tracing::warn;
 
 use conduit_core::errors::ConduitError;
-use conduit_core::utils::token_service::DynTokenService;
 
-/// Extracts the JWT from the Authorization token header, optional and will not return errors if none is found.
+use crate::extractors::required_authentication_extractor::get_claims_from_token;
+
+/// Extracts the JWT, or personal access token, from the Authorization token header, optional and will not return errors if none is found.
 pub struct OptionalAuthentication(pub Option<i64>);
 
 #[async_trait]
@@ -20,10 +20,6 @@ where
     async fn from_request(request: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
         let optional_token_response = Ok(OptionalAuthentication(None));
 
-        let Extension(token_service): Extension<DynTokenService> = Extension::from_request(request)
-            .await
-            .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
-
         if let Some(authorization_header) = request.headers().get(AUTHORIZATION) {
             if let Ok(header_value) = authorization_header.to_str() {
                 if !header_value.contains("Token") {
@@ -38,11 +34,16 @@ where
                     return optional_token_response;
                 }
 
-                let token_value = tokenized_value.into_iter().nth(1).unwrap();
+                let token_value = String::from(tokenized_value.into_iter().nth(1).unwrap());
 
-                if let Ok(user_id) = token_service.get_user_id_from_token(String::from(token_value)) {
-                    return Ok(OptionalAuthentication(Some(user_id)));
-                }
+                return match get_claims_from_token(request, token_value).await {
+                    Ok(claims) => Ok(OptionalAuthentication(Some(claims.user_id))),
+                    Err(ConduitError::Unauthorized | ConduitError::Forbidden) => {
+                        warn!("request token is not valid, continuing without authentication");
+                        optional_token_response
+                    },
+                    Err(err) => Err(err),
+                };
             }
         }
 
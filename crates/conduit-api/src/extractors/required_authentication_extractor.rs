@@ -2,14 +2,27 @@ use async_trait::async_trait;
 use axum::extract::{FromRequest, RequestParts};
 use axum::Extension;
 use http::header::AUTHORIZATION;
+use http::Method;
 use tracing::error;
 
-use conduit_core::errors::ConduitError;
-use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::access_tokens::scopes::Scope;
+use conduit_core::access_tokens::service::{DynAccessTokensService, ACCESS_TOKEN_PREFIX};
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::sessions::service::DynSessionsService;
+use conduit_core::users::service::DynUsersService;
+use conduit_core::utils::token_service::{DynTokenService, TokenClaims};
 
-/// Extracts the JWT from the Authorization token header.
+/// Extracts the JWT, or personal access token, from the Authorization token header.
 pub struct RequiredAuthentication(pub i64);
 
+/// Extracts the user ID from the JWT in the Authorization token header, rejecting users that are required to verify
+/// their email before authoring content.
+pub struct RequiredVerifiedAuthentication(pub i64);
+
+/// Extracts the full set of claims from the JWT in the Authorization token header, for endpoints that
+/// need more than the user ID.
+pub struct RequiredAuthenticationClaims(pub TokenClaims);
+
 #[async_trait]
 impl<B> FromRequest<B> for RequiredAuthentication
 where
@@ -18,10 +31,40 @@ where
     type Rejection = ConduitError;
 
     async fn from_request(request: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
-        let Extension(token_service): Extension<DynTokenService> = Extension::from_request(request)
+        let RequiredAuthenticationClaims(claims) = RequiredAuthenticationClaims::from_request(request).await?;
+
+        Ok(RequiredAuthentication(claims.user_id))
+    }
+}
+
+#[async_trait]
+impl<B> FromRequest<B> for RequiredVerifiedAuthentication
+where
+    B: Send + Sync,
+{
+    type Rejection = ConduitError;
+
+    async fn from_request(request: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let RequiredAuthentication(user_id) = RequiredAuthentication::from_request(request).await?;
+
+        let Extension(users_service): Extension<DynUsersService> = Extension::from_request(request)
             .await
             .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
 
+        users_service.ensure_verified(user_id).await?;
+
+        Ok(RequiredVerifiedAuthentication(user_id))
+    }
+}
+
+#[async_trait]
+impl<B> FromRequest<B> for RequiredAuthenticationClaims
+where
+    B: Send + Sync,
+{
+    type Rejection = ConduitError;
+
+    async fn from_request(request: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
         if let Some(authorization_header) = request.headers().get(AUTHORIZATION) {
             let header_value = authorization_header.to_str().map_err(|_| ConduitError::Unauthorized)?;
 
@@ -37,17 +80,90 @@ where
                 return Err(ConduitError::Unauthorized);
             }
 
-            let token_value = tokenized_value.into_iter().nth(1).unwrap();
-            let user_id = token_service
-                .get_user_id_from_token(String::from(token_value))
-                .map_err(|err| {
-                    error!("could not validate user ID from token: {:?}", err);
-                    ConduitError::Unauthorized
-                })?;
+            let token_value = String::from(tokenized_value.into_iter().nth(1).unwrap());
+            let claims = get_claims_from_token(request, token_value).await?;
 
-            Ok(RequiredAuthentication(user_id))
+            Ok(RequiredAuthenticationClaims(claims))
         } else {
             Err(ConduitError::Unauthorized)
         }
     }
 }
+
+/// Validates either a JWT or a personal access token, rejecting personal access tokens without the scope
+/// the request requires.
+pub(crate) async fn get_claims_from_token<B>(request: &mut RequestParts<B>, token: String) -> ConduitResult<TokenClaims>
+where
+    B: Send + Sync,
+{
+    if token.starts_with(ACCESS_TOKEN_PREFIX) {
+        let Extension(access_tokens_service): Extension<DynAccessTokensService> = Extension::from_request(request)
+            .await
+            .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
+
+        let claims = access_tokens_service.get_claims_from_access_token(token).await?;
+        let required_scope = required_scope(request.method(), request.uri().path());
+
+        if !required_scope.is_some_and(|scope| claims.has_scope(scope)) {
+            error!(
+                "personal access token for user {:?} does not allow {:?} {:?}",
+                claims.user_id,
+                request.method(),
+                request.uri().path()
+            );
+            return Err(ConduitError::Forbidden);
+        }
+
+        return Ok(claims);
+    }
+
+    let Extension(token_service): Extension<DynTokenService> = Extension::from_request(request)
+        .await
+        .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
+
+    let Extension(sessions_service): Extension<DynSessionsService> = Extension::from_request(request)
+        .await
+        .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
+
+    let claims = token_service.get_claims_from_token(token).map_err(|err| {
+        error!("could not validate user ID from token: {:?}", err);
+        ConduitError::Unauthorized
+    })?;
+
+    if sessions_service
+        .is_session_revoked(claims.token_id.clone(), claims.user_id, claims.role)
+        .await?
+    {
+        error!("token for user {:?} has been revoked", claims.user_id);
+        return Err(ConduitError::Unauthorized);
+    }
+
+    Ok(claims)
+}
+
+/// Maps the request to the scope a personal access token needs to make it. Managing the account itself, including
+/// its tokens, is never allowed with a personal access token and requires signing in.
+fn required_scope(method: &Method, path: &str) -> Option<Scope> {
+    let segments: Vec<&str> = path
+        .trim_start_matches("/api")
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    let is_read = method == Method::GET || method == Method::HEAD;
+
+    match (segments.first().copied(), is_read) {
+        (Some("articles" | "profiles" | "tags"), true) => Some(Scope::Read),
+        (Some("user"), true) if matches!(segments.get(1), Some(&"drafts" | &"trash" | &"notifications")) => {
+            Some(Scope::Read)
+        },
+        (Some("user"), false) if segments.get(1) == Some(&"trash") && segments.get(2) == Some(&"comments") => {
+            Some(Scope::CommentsWrite)
+        },
+        (Some("user"), false) if segments.get(1) == Some(&"trash") => Some(Scope::ArticlesWrite),
+        (Some("articles"), false) if segments.get(2) == Some(&"comments") => Some(Scope::CommentsWrite),
+        (Some("articles"), false) => Some(Scope::ArticlesWrite),
+        (Some("profiles"), false) => Some(Scope::ProfilesWrite),
+        _ => None,
+    }
+}
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use axum::extract::{FromRequest, RequestParts};
+use tracing::error;
+
+use conduit_core::errors::ConduitError;
+use conduit_core::users::roles::{Permission, Role};
+
+use crate::extractors::required_authentication_extractor::RequiredAuthenticationClaims;
+
+/// Extracts the user ID and role from the JWT in the Authorization token header, for endpoints where
+/// what a user is allowed to do depends on their role.
+pub struct RequiredAuthorization(pub i64, pub Role);
+
+/// Extracts the user ID from the JWT in the Authorization token header, rejecting users without permission to manage users.
+pub struct RequiredAdministrator(pub i64);
+
+#[async_trait]
+impl<B> FromRequest<B> for RequiredAuthorization
+where
+    B: Send + Sync,
+{
+    type Rejection = ConduitError;
+
+    async fn from_request(request: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let RequiredAuthenticationClaims(claims) = RequiredAuthenticationClaims::from_request(request).await?;
+
+        Ok(RequiredAuthorization(claims.user_id, claims.role))
+    }
+}
+
+#[async_trait]
+impl<B> FromRequest<B> for RequiredAdministrator
+where
+    B: Send + Sync,
+{
+    type Rejection = ConduitError;
+
+    async fn from_request(request: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let RequiredAuthorization(user_id, role) = RequiredAuthorization::from_request(request).await?;
+
+        if !role.has_permission(Permission::ManageUsers) {
+            error!("user {:?} with role {:?} cannot manage users", user_id, role);
+            return Err(ConduitError::Forbidden);
+        }
+
+        Ok(RequiredAdministrator(user_id))
+    }
+}
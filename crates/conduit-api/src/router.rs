@@ -1,4 +1,5 @@
 use std::future::ready;
+use std::net::SocketAddr;
 use std::time::{Duration, Instant};
 
 use anyhow::Context;
@@ -20,9 +21,15 @@ use tracing::info;
 
 use conduit_infrastructure::service_register::ServiceRegister;
 
+use crate::endpoints::access_tokens_endpoints::AccessTokensRouter;
+use crate::endpoints::admin_endpoints::AdminRouter;
 use crate::endpoints::articles_endpoints::ArticlesRouter;
+use crate::endpoints::feeds_endpoints::FeedsRouter;
+use crate::endpoints::notifications_endpoints::NotificationsRouter;
 use crate::endpoints::profiles_endpoints::ProfilesRouter;
 use crate::endpoints::tags_endpoints::TagsRouter;
+use crate::endpoints::trash_endpoints::TrashRouter;
+use crate::endpoints::two_factor_endpoints::TwoFactorRouter;
 use crate::endpoints::users_endpoints::UsersRouter;
 
 lazy_static! {
@@ -47,7 +54,13 @@ impl ConduitApplicationController {
             .nest("/api", UsersRouter::new_router(service_register.clone()))
             .nest("/api", ProfilesRouter::new_router(service_register.clone()))
             .nest("/api", ArticlesRouter::new_router(service_register.clone()))
-            .nest("/api", TagsRouter::new_router(service_register))
+            .nest("/api", TagsRouter::new_router(service_register.clone()))
+            .nest("/api", AccessTokensRouter::new_router(service_register.clone()))
+            .nest("/api", TwoFactorRouter::new_router(service_register.clone()))
+            .nest("/api", TrashRouter::new_router(service_register.clone()))
+            .nest("/api", NotificationsRouter::new_router(service_register.clone()))
+            .nest("/api", AdminRouter::new_router(service_register.clone()))
+            .merge(FeedsRouter::new_router(service_register))
             .route("/api/ping", get(Self::ping))
             .route("/metrics", get(move || ready(recorder_handle.render())))
             .layer(
@@ -65,7 +78,7 @@ impl ConduitApplicationController {
 
         info!("routes initialized, listening on port {}", port);
         axum::Server::bind(&format!("0.0.0.0:{}", port).parse().unwrap())
-            .serve(router.into_make_service())
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .await
             .context("error while starting API server")?;
 
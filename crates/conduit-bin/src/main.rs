@@ -1,17 +1,22 @@
+mod scheduler;
+
 use std::sync::Arc;
 
 use anyhow::Context;
 use clap::Parser;
-use tracing::info;
+use tracing::{info, warn};
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 
 use conduit_api::router::ConduitApplicationController;
 use conduit_core::config::AppConfig;
+use conduit_core::users::roles::Role;
 use conduit_infrastructure::connection_pool::ConduitConnectionManager;
 use conduit_infrastructure::service_register::ServiceRegister;
 use conduit_infrastructure::services::utils::conduit_seed_service::ConduitSeedService;
 
+use crate::scheduler::ConduitScheduler;
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     dotenv::dotenv().ok();
@@ -39,6 +44,19 @@ async fn main() -> anyhow::Result<()> {
             .expect("unexpected error occurred while seeding application data");
     }
 
+    if let Some(admin_username) = config.admin_username.as_ref().filter(|username| !username.is_empty()) {
+        info!("granting admin role to {:?}...", admin_username);
+        if let Err(err) = service_register
+            .users_service
+            .update_user_role(admin_username.clone(), Role::Admin)
+            .await
+        {
+            warn!("could not grant admin role to {:?}: {:?}", admin_username, err);
+        }
+    }
+
+    ConduitScheduler::new(service_register.clone(), config.scheduler_interval_seconds).start();
+
     info!("migrations successfully ran, initializing axum server...");
     ConduitApplicationController::serve(port, &config.cors_origin, service_register)
         .await
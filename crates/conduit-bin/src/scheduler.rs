@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use tracing::{error, info};
+
+use conduit_infrastructure::service_register::ServiceRegister;
+
+/// Runs the application's background jobs on a fixed interval for as long as the server is running.
+pub struct ConduitScheduler {
+    service_register: ServiceRegister,
+    interval: Duration,
+}
+
+impl ConduitScheduler {
+    pub fn new(service_register: ServiceRegister, interval_seconds: u64) -> Self {
+        Self {
+            service_register,
+            interval: Duration::from_secs(interval_seconds),
+        }
+    }
+
+    pub fn start(self) {
+        info!("starting background scheduler, running jobs every {:?}", self.interval);
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.interval);
+
+            loop {
+                interval.tick().await;
+                self.run_jobs().await;
+            }
+        });
+    }
+
+    async fn run_jobs(&self) {
+        // a failing job is retried on the next tick, so log the error rather than stopping the scheduler
+        if let Err(err) = self
+            .service_register
+            .articles_service
+            .publish_scheduled_articles()
+            .await
+        {
+            error!("could not publish scheduled articles: {:?}", err);
+        }
+
+        if let Err(err) = self.service_register.trash_service.purge_expired().await {
+            error!("could not purge expired trash: {:?}", err);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod repository;
+pub mod scopes;
+pub mod service;
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use sqlx::types::time::OffsetDateTime;
+use sqlx::FromRow;
+use time::Format;
+
+use conduit_domain::access_tokens::AccessTokenDto;
+
+pub type DynAccessTokensRepository = Arc<dyn AccessTokensRepository + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait AccessTokensRepository {
+    async fn create_access_token(
+        &self,
+        user_id: i64,
+        name: String,
+        token_hash: String,
+        scopes: Vec<String>,
+        expires_at: Option<OffsetDateTime>,
+    ) -> anyhow::Result<AccessTokenEntity>;
+
+    async fn get_user_access_tokens(&self, user_id: i64) -> anyhow::Result<Vec<AccessTokenEntity>>;
+
+    async fn get_access_token(&self, token_hash: String) -> anyhow::Result<Option<AccessTokenEntity>>;
+
+    /// Deletes the user's token, returning false if the user has no token with the ID.
+    async fn delete_access_token(&self, user_id: i64, id: i64) -> anyhow::Result<bool>;
+
+    async fn update_access_token_last_used(&self, id: i64) -> anyhow::Result<()>;
+}
+
+#[derive(FromRow)]
+pub struct AccessTokenEntity {
+    pub id: i64,
+    pub user_id: i64,
+    pub name: String,
+    pub token_hash: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<OffsetDateTime>,
+    pub last_used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+impl AccessTokenEntity {
+    pub fn into_dto(self, token: Option<String>) -> AccessTokenDto {
+        AccessTokenDto {
+            id: self.id,
+            name: self.name,
+            scopes: self.scopes,
+            token,
+            expires_at: self
+                .expires_at
+                .map(|expires_at| expires_at.lazy_format(Format::Rfc3339).to_string()),
+            last_used_at: self
+                .last_used_at
+                .map(|last_used_at| last_used_at.lazy_format(Format::Rfc3339).to_string()),
+            created_at: self.created_at.lazy_format(Format::Rfc3339).to_string(),
+        }
+    }
+}
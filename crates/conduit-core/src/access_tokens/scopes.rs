@@ -0,0 +1,43 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// The scopes a personal access token may be granted. Every token may read, so a token granted only
+/// the read scope is read-only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    Read,
+    ArticlesWrite,
+    CommentsWrite,
+    ProfilesWrite,
+}
+
+impl Scope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::Read => "read",
+            Scope::ArticlesWrite => "articles:write",
+            Scope::CommentsWrite => "comments:write",
+            Scope::ProfilesWrite => "profiles:write",
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Scope {
+    type Err = String;
+
+    fn from_str(scope: &str) -> Result<Self, Self::Err> {
+        match scope {
+            "read" => Ok(Scope::Read),
+            "articles:write" => Ok(Scope::ArticlesWrite),
+            "comments:write" => Ok(Scope::CommentsWrite),
+            "profiles:write" => Ok(Scope::ProfilesWrite),
+            _ => Err(format!("scope {:?} is not supported", scope)),
+        }
+    }
+}
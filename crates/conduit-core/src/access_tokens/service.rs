@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use conduit_domain::access_tokens::requests::CreateAccessTokenDto;
+use conduit_domain::access_tokens::AccessTokenDto;
+
+use crate::errors::ConduitResult;
+use crate::utils::token_service::TokenClaims;
+
+/// A reference counter for our personal access tokens service, allowing scripts and integrations to authenticate
+/// without a user's password.
+pub type DynAccessTokensService = Arc<dyn AccessTokensService + Send + Sync>;
+
+/// Personal access tokens carry a recognizable prefix, distinguishing them from JWTs in the Authorization header.
+pub const ACCESS_TOKEN_PREFIX: &str = "cpat_";
+
+#[automock]
+#[async_trait]
+pub trait AccessTokensService {
+    /// Creates a new token for the user, the raw token is only ever returned here.
+    async fn create_access_token(&self, user_id: i64, request: CreateAccessTokenDto) -> ConduitResult<AccessTokenDto>;
+
+    async fn get_access_tokens(&self, user_id: i64) -> ConduitResult<Vec<AccessTokenDto>>;
+
+    async fn revoke_access_token(&self, user_id: i64, id: i64) -> ConduitResult<()>;
+
+    /// Validates the raw token, returning claims restricted to the scopes the token was granted.
+    async fn get_claims_from_access_token(&self, token: String) -> ConduitResult<TokenClaims>;
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use conduit_domain::admin::requests::GetUsersServiceRequest;
+use conduit_domain::admin::AdminUserDto;
+
+use crate::errors::ConduitResult;
+use crate::users::roles::Role;
+
+/// A reference counter for our admin service, responsible for the user management operations reserved for administrators.
+pub type DynAdminService = Arc<dyn AdminService + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait AdminService {
+    /// Retrieves a page of users matching the search term, along with the total number of matching users.
+    async fn get_users(&self, request: GetUsersServiceRequest) -> ConduitResult<(Vec<AdminUserDto>, i64)>;
+
+    /// Suspends the user, ending all of their existing sessions.
+    async fn suspend_user(&self, admin_id: i64, username: String) -> ConduitResult<AdminUserDto>;
+
+    async fn unsuspend_user(&self, username: String) -> ConduitResult<AdminUserDto>;
+
+    /// Assigns the role to the user, whose existing access tokens stop working until their session is refreshed.
+    async fn update_user_role(&self, admin_id: i64, username: String, role: Role) -> ConduitResult<()>;
+
+    /// Replaces the user's password with an unusable one and mails them a reset token to choose a new password.
+    async fn force_password_reset(&self, admin_id: i64, username: String) -> ConduitResult<()>;
+
+    /// Permanently removes the user along with all of their articles, comments and sessions.
+    async fn delete_user(&self, admin_id: i64, username: String) -> ConduitResult<()>;
+}
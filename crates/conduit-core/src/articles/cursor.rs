@@ -0,0 +1,77 @@
+use std::fmt::Write;
+
+use sqlx::types::time::OffsetDateTime;
+
+use crate::errors::{ConduitError, ConduitResult};
+
+/// The latest timestamp, in microseconds since the unix epoch, that a cursor may point to (the end of the year 9999).
+const MAX_CURSOR_MICROS: i128 = 253_402_300_799_999_999;
+
+/// A keyset position within the article listings, pointing at the last article of a page by the timestamp the
+/// listing is ordered on and its id.
+///
+/// Cursors are handed to clients as opaque strings, so the encoding is free to change between releases.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArticleCursor {
+    pub timestamp: OffsetDateTime,
+    pub id: i64,
+}
+
+impl ArticleCursor {
+    pub fn new(timestamp: OffsetDateTime, id: i64) -> Self {
+        Self { timestamp, id }
+    }
+
+    pub fn encode(&self) -> String {
+        encode_position(&format!("{}:{}", timestamp_micros(self.timestamp), self.id))
+    }
+
+    pub fn decode(cursor: &str) -> ConduitResult<Self> {
+        let invalid_cursor = || ConduitError::BadRequest(String::from("article cursor is invalid"));
+
+        let decoded = decode_position(cursor).ok_or_else(invalid_cursor)?;
+        let (micros, id) = decoded.split_once(':').ok_or_else(invalid_cursor)?;
+        let timestamp = parse_timestamp_micros(micros).ok_or_else(invalid_cursor)?;
+        let id = id.parse::<i64>().map_err(|_| invalid_cursor())?;
+
+        Ok(Self::new(timestamp, id))
+    }
+}
+
+/// Hex encodes a cursor's position, so clients have no reason to look into it.
+pub(crate) fn encode_position(position: &str) -> String {
+    position.bytes().fold(String::new(), |mut encoded, byte| {
+        let _ = write!(encoded, "{:02x}", byte);
+        encoded
+    })
+}
+
+/// Decodes a position hex encoded by `encode_position`, returning nothing when the cursor was not.
+pub(crate) fn decode_position(cursor: &str) -> Option<String> {
+    if !cursor.len().is_multiple_of(2) || !cursor.is_ascii() {
+        return None;
+    }
+
+    let bytes = (0..cursor.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&cursor[index..index + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .ok()?;
+
+    String::from_utf8(bytes).ok()
+}
+
+pub(crate) fn timestamp_micros(timestamp: OffsetDateTime) -> i128 {
+    // postgres stores timestamps to the microsecond, so anything finer would never match
+    timestamp.unix_timestamp_nanos() / 1_000
+}
+
+pub(crate) fn parse_timestamp_micros(micros: &str) -> Option<OffsetDateTime> {
+    let micros = micros.parse::<i128>().ok()?;
+
+    if !(0..=MAX_CURSOR_MICROS).contains(&micros) {
+        return None;
+    }
+
+    Some(OffsetDateTime::from_unix_timestamp_nanos(micros * 1_000))
+}
@@ -1,2 +1,6 @@
+pub mod cursor;
+pub mod reading;
 pub mod repository;
 pub mod service;
+pub mod slug;
+pub mod status;
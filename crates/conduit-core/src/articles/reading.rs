@@ -0,0 +1,14 @@
+/// The average reading speed of an adult, in words per minute.
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Counts the words of the body, ignoring Markdown syntax such as heading markers and list bullets.
+pub fn count_words(body: &str) -> usize {
+    body.split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// Estimates the minutes it takes to read the words, rounded up to at least a minute.
+pub fn reading_time_minutes(word_count: usize) -> usize {
+    word_count.div_ceil(WORDS_PER_MINUTE).max(1)
+}
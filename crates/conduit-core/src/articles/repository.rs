@@ -6,7 +6,14 @@ use sqlx::types::time::OffsetDateTime;
 use sqlx::FromRow;
 use time::Format;
 
-use conduit_domain::articles::models::{ArticleDto, AuthorDto};
+use conduit_domain::articles::models::{ArticleDto, ArticleRevisionDto, AuthorDto};
+use conduit_domain::articles::requests::GetArticlesServiceRequest;
+use conduit_domain::trash::TrashedArticleDto;
+
+use crate::articles::cursor::ArticleCursor;
+use crate::articles::reading::{count_words, reading_time_minutes};
+use crate::articles::status::ArticleStatus;
+use crate::utils::unit_of_work::DynUnitOfWork;
 
 pub type DynArticlesRepository = Arc<dyn ArticlesRepository + Send + Sync>;
 
@@ -15,15 +22,13 @@ pub type DynArticlesRepository = Arc<dyn ArticlesRepository + Send + Sync>;
 pub trait ArticlesRepository {
     async fn create_article(
         &self,
-        user_id: i64,
-        title: String,
-        slug: String,
-        description: String,
-        body: String,
+        unit_of_work: &mut DynUnitOfWork,
+        article: NewArticle,
     ) -> anyhow::Result<UpsertArticleQuery>;
 
     async fn update_article(
         &self,
+        unit_of_work: &mut DynUnitOfWork,
         id: i64,
         title: String,
         slug: String,
@@ -31,19 +36,101 @@ pub trait ArticlesRepository {
         body: String,
     ) -> anyhow::Result<UpsertArticleQuery>;
 
+    /// Retrieves published articles matching the filters, ranked by relevance when searching and most recent first
+    /// otherwise, starting after the cursor when one is given.
     async fn get_articles(
         &self,
         user_id: Option<i64>,
-        tag: Option<String>,
-        author: Option<String>,
-        favorited: Option<String>,
+        request: GetArticlesServiceRequest,
+        cursor: Option<ArticleCursor>,
+    ) -> anyhow::Result<Vec<GetArticleQuery>>;
+
+    /// Counts every published article matching the filters, regardless of the requested page.
+    async fn get_articles_count(&self, request: GetArticlesServiceRequest) -> anyhow::Result<i64>;
+
+    /// Retrieves the article with the slug, including drafts only when they belong to the user.
+    async fn get_article_by_slug(&self, user_id: Option<i64>, slug: String) -> anyhow::Result<Option<GetArticleQuery>>;
+
+    /// Retrieves the slugs, current or former, held by articles other than the article that are either the slug or the
+    /// slug followed by a suffix, regardless of the articles' visibility. Slugs are allocated by one unit of work at a
+    /// time, so a slug found free remains free until the unit of work is committed or rolled back.
+    async fn get_taken_slugs(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        slug: String,
+        article_id: Option<i64>,
+    ) -> anyhow::Result<Vec<String>>;
+
+    /// Keeps the article's previous slug in its history, releasing the new slug should the article have held it before.
+    async fn record_slug_change(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        article_id: i64,
+        previous_slug: String,
+        slug: String,
+    ) -> anyhow::Result<()>;
+
+    /// Retrieves the current slug of the article that was previously known by the slug.
+    async fn get_renamed_article_slug(&self, previous_slug: String) -> anyhow::Result<Option<String>>;
+
+    async fn get_user_drafts(
+        &self,
+        user_id: i64,
         limit: i64,
         offset: i64,
+        cursor: Option<ArticleCursor>,
     ) -> anyhow::Result<Vec<GetArticleQuery>>;
 
-    async fn get_article_by_slug(&self, user_id: Option<i64>, slug: String) -> anyhow::Result<Option<GetArticleQuery>>;
+    async fn get_user_drafts_count(&self, user_id: i64) -> anyhow::Result<i64>;
 
-    async fn delete_article(&self, id: i64) -> anyhow::Result<()>;
+    /// Publishes the article, keeping the original publish date of articles that have been published before.
+    async fn publish_article(&self, unit_of_work: &mut DynUnitOfWork, id: i64) -> anyhow::Result<UpsertArticleQuery>;
+
+    async fn update_article_publish_at(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        id: i64,
+        publish_at: OffsetDateTime,
+    ) -> anyhow::Result<UpsertArticleQuery>;
+
+    /// Publishes scheduled articles whose publish time has passed, returning the articles published.
+    async fn publish_due_articles(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+    ) -> anyhow::Result<Vec<PublishedArticleEntity>>;
+
+    /// Records the article's current content as its next revision.
+    async fn create_article_revision(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        article_id: i64,
+        editor_id: i64,
+    ) -> anyhow::Result<()>;
+
+    async fn get_article_revisions(&self, article_id: i64) -> anyhow::Result<Vec<ArticleRevisionEntity>>;
+
+    async fn get_article_revision(
+        &self,
+        article_id: i64,
+        revision: i32,
+    ) -> anyhow::Result<Option<ArticleRevisionEntity>>;
+
+    /// Moves the article to the trash, hiding it until it is restored or purged.
+    async fn delete_article(&self, unit_of_work: &mut DynUnitOfWork, id: i64, deleted_by: i64) -> anyhow::Result<()>;
+
+    /// Retrieves the articles the user moved to the trash since the time, most recently deleted first.
+    async fn get_deleted_articles(
+        &self,
+        deleted_by: i64,
+        deleted_after: OffsetDateTime,
+    ) -> anyhow::Result<Vec<DeletedArticleEntity>>;
+
+    async fn get_deleted_article(&self, slug: String) -> anyhow::Result<Option<DeletedArticleEntity>>;
+
+    async fn restore_article(&self, id: i64) -> anyhow::Result<()>;
+
+    /// Permanently removes articles moved to the trash before the time, returning the number of articles removed.
+    async fn purge_deleted_articles(&self, deleted_before: OffsetDateTime) -> anyhow::Result<u64>;
 
     async fn favorite_article(&self, article_id: i64, user_id: i64) -> anyhow::Result<GetArticleQuery>;
 
@@ -52,6 +139,17 @@ pub trait ArticlesRepository {
     async fn get_user_favorites(&self, article_id: i64) -> anyhow::Result<Vec<GetArticleFavoritesQuery>>;
 }
 
+#[derive(Debug)]
+pub struct NewArticle {
+    pub user_id: i64,
+    pub title: String,
+    pub slug: String,
+    pub description: String,
+    pub body: String,
+    pub status: ArticleStatus,
+    pub publish_at: Option<OffsetDateTime>,
+}
+
 #[derive(FromRow)]
 pub struct UpsertArticleQuery {
     pub id: i64,
@@ -61,6 +159,9 @@ pub struct UpsertArticleQuery {
     pub body: String,
     pub description: String,
     pub slug: String,
+    pub status: String,
+    pub published_at: Option<OffsetDateTime>,
+    pub publish_at: Option<OffsetDateTime>,
     pub author_username: String,
     pub author_image: String,
     pub author_bio: String,
@@ -75,6 +176,9 @@ pub struct GetArticleQuery {
     pub body: String,
     pub description: String,
     pub slug: String,
+    pub status: String,
+    pub published_at: Option<OffsetDateTime>,
+    pub publish_at: Option<OffsetDateTime>,
     pub user_id: i64,
     pub favorites: i64,
     pub favorited: bool,
@@ -82,6 +186,35 @@ pub struct GetArticleQuery {
     pub author_username: String,
     pub author_image: String,
     pub author_bio: String,
+    pub snippet: Option<String>,
+}
+
+#[derive(FromRow)]
+pub struct ArticleRevisionEntity {
+    pub id: i64,
+    pub article_id: i64,
+    pub revision: i32,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub editor_username: Option<String>,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(FromRow)]
+pub struct PublishedArticleEntity {
+    pub id: i64,
+    pub user_id: i64,
+}
+
+#[derive(FromRow)]
+pub struct DeletedArticleEntity {
+    pub id: i64,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub deleted_by: Option<i64>,
+    pub deleted_at: OffsetDateTime,
 }
 
 #[derive(FromRow)]
@@ -93,46 +226,103 @@ pub struct GetArticleFavoritesQuery {
 
 impl UpsertArticleQuery {
     pub fn into_dto(self, tag_list: Vec<String>) -> ArticleDto {
+        let word_count = count_words(&self.body);
+
         ArticleDto {
             id: self.id,
             title: self.title,
             body: self.body,
+            body_html: None,
+            word_count,
+            reading_time_minutes: reading_time_minutes(word_count),
             tag_list,
             created_at: self.created_at.lazy_format(Format::Rfc3339).to_string(),
             updated_at: self.updated_at.lazy_format(Format::Rfc3339).to_string(),
             description: self.description,
             slug: self.slug,
+            status: self.status,
+            published_at: self
+                .published_at
+                .map(|published_at| published_at.lazy_format(Format::Rfc3339).to_string()),
+            publish_at: self
+                .publish_at
+                .map(|publish_at| publish_at.lazy_format(Format::Rfc3339).to_string()),
             favorited: false,
             favorites_count: 0,
+            reactions: Vec::new(),
+            my_reactions: Vec::new(),
+            mentions: Vec::new(),
             author: AuthorDto {
                 username: self.author_username,
                 bio: Some(self.author_bio),
                 image: Some(self.author_image),
                 following: false,
             },
+            snippet: None,
         }
     }
 }
 
 impl GetArticleQuery {
     pub fn into_dto(self, tag_list: Vec<String>) -> ArticleDto {
+        let word_count = count_words(&self.body);
+
         ArticleDto {
             id: self.id,
             title: self.title,
             body: self.body,
+            body_html: None,
+            word_count,
+            reading_time_minutes: reading_time_minutes(word_count),
             tag_list,
             created_at: self.created_at.lazy_format(Format::Rfc3339).to_string(),
             updated_at: self.updated_at.lazy_format(Format::Rfc3339).to_string(),
             description: self.description,
             slug: self.slug,
+            status: self.status,
+            published_at: self
+                .published_at
+                .map(|published_at| published_at.lazy_format(Format::Rfc3339).to_string()),
+            publish_at: self
+                .publish_at
+                .map(|publish_at| publish_at.lazy_format(Format::Rfc3339).to_string()),
             favorited: self.favorited,
             favorites_count: self.favorites,
+            reactions: Vec::new(),
+            my_reactions: Vec::new(),
+            mentions: Vec::new(),
             author: AuthorDto {
                 username: self.author_username,
                 bio: Some(self.author_bio),
                 image: Some(self.author_image),
                 following: self.following_author,
             },
+            snippet: self.snippet,
+        }
+    }
+}
+
+impl ArticleRevisionEntity {
+    pub fn into_dto(self) -> ArticleRevisionDto {
+        ArticleRevisionDto {
+            revision: self.revision,
+            title: self.title,
+            description: self.description,
+            body: self.body,
+            editor: self.editor_username,
+            created_at: self.created_at.lazy_format(Format::Rfc3339).to_string(),
+        }
+    }
+}
+
+impl DeletedArticleEntity {
+    pub fn into_dto(self, purge_at: OffsetDateTime) -> TrashedArticleDto {
+        TrashedArticleDto {
+            slug: self.slug,
+            title: self.title,
+            description: self.description,
+            deleted_at: self.deleted_at.lazy_format(Format::Rfc3339).to_string(),
+            purge_at: purge_at.lazy_format(Format::Rfc3339).to_string(),
         }
     }
 }
@@ -3,48 +3,79 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use mockall::automock;
 
-use conduit_domain::articles::models::ArticleDto;
+use conduit_domain::articles::models::{
+    ArticleDto, ArticleRevisionDiffDto, ArticleRevisionDto, CreateArticleDto, UpdateArticleDto,
+};
+use conduit_domain::articles::requests::GetArticlesServiceRequest;
+use conduit_domain::articles::responses::ArticlesResponse;
 
 use crate::errors::ConduitResult;
+use crate::users::roles::Role;
 
 pub type DynArticlesService = Arc<dyn ArticlesService + Send + Sync>;
 
 #[automock]
 #[async_trait]
 pub trait ArticlesService {
-    async fn create_article(
-        &self,
-        user_id: i64,
-        title: String,
-        description: String,
-        body: String,
-        tag_list: Vec<String>,
-    ) -> ConduitResult<ArticleDto>;
+    /// Creates the article, holding it back as a draft until its publish time when the article is scheduled.
+    async fn create_article(&self, user_id: i64, request: CreateArticleDto) -> ConduitResult<ArticleDto>;
 
     async fn update_article(
         &self,
         user_id: i64,
+        role: Role,
         slug: String,
-        title: Option<String>,
-        description: Option<String>,
-        body: Option<String>,
+        request: UpdateArticleDto,
     ) -> ConduitResult<ArticleDto>;
 
+    /// Retrieves a page of articles along with the total matching the request and, unless searching, the cursor of the
+    /// next page.
     async fn get_articles(
         &self,
         user_id: Option<i64>,
-        tag: Option<String>,
-        author: Option<String>,
-        favorited: Option<String>,
-        limit: i64,
-        offset: i64,
-    ) -> ConduitResult<Vec<ArticleDto>>;
+        request: GetArticlesServiceRequest,
+    ) -> ConduitResult<ArticlesResponse>;
 
     async fn get_article(&self, user_id: Option<i64>, slug: String) -> ConduitResult<ArticleDto>;
 
-    async fn get_feed(&self, user_id: i64, limit: i64, offset: i64) -> ConduitResult<Vec<ArticleDto>>;
+    /// Retrieves the user's unpublished drafts, which are excluded from article listings and feeds.
+    async fn get_user_drafts(
+        &self,
+        user_id: i64,
+        request: GetArticlesServiceRequest,
+    ) -> ConduitResult<ArticlesResponse>;
+
+    async fn publish_article(&self, user_id: i64, slug: String) -> ConduitResult<ArticleDto>;
+
+    /// Publishes every scheduled article whose publish time has passed, returning the number of articles published.
+    async fn publish_scheduled_articles(&self) -> ConduitResult<u64>;
+
+    async fn get_article_revisions(&self, user_id: Option<i64>, slug: String)
+        -> ConduitResult<Vec<ArticleRevisionDto>>;
+
+    async fn get_article_revision(
+        &self,
+        user_id: Option<i64>,
+        slug: String,
+        revision: i32,
+    ) -> ConduitResult<ArticleRevisionDto>;
+
+    /// Compares the revision against an earlier revision, defaulting to the revision immediately before it; the first
+    /// revision is compared against an empty article.
+    async fn get_article_revision_diff(
+        &self,
+        user_id: Option<i64>,
+        slug: String,
+        revision: i32,
+        from: Option<i32>,
+    ) -> ConduitResult<ArticleRevisionDiffDto>;
+
+    /// Restores the article's content from the revision, keeping the article's slug and recording a new revision.
+    async fn restore_article_revision(&self, user_id: i64, slug: String, revision: i32) -> ConduitResult<ArticleDto>;
+
+    async fn get_feed(&self, user_id: i64, request: GetArticlesServiceRequest) -> ConduitResult<ArticlesResponse>;
 
-    async fn delete_article(&self, user_id: i64, slug: String) -> ConduitResult<()>;
+    async fn delete_article(&self, user_id: i64, role: Role, slug: String) -> ConduitResult<()>;
 
     async fn favorite_article(&self, user_id: i64, slug: String) -> ConduitResult<ArticleDto>;
 
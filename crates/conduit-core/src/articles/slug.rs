@@ -0,0 +1,14 @@
+/// Picks the first of the slug, or the slug with an increasing numeric suffix, that has not been taken.
+pub fn next_available_slug(slug: &str, taken_slugs: &[String]) -> String {
+    let is_taken = |candidate: &str| taken_slugs.iter().any(|taken_slug| taken_slug == candidate);
+
+    if !is_taken(slug) {
+        return slug.to_owned();
+    }
+
+    // suffixes start from two, as the unsuffixed slug is the first article with the title
+    (2..)
+        .map(|suffix| format!("{}-{}", slug, suffix))
+        .find(|candidate| !is_taken(candidate))
+        .unwrap_or_default()
+}
@@ -0,0 +1,52 @@
+use std::fmt;
+use std::str::FromStr;
+
+use sqlx::types::time::OffsetDateTime;
+use time::Format;
+
+/// The publication states of an article, controlling where the article is visible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArticleStatus {
+    /// Only visible to the author.
+    Draft,
+    /// Visible to everyone, and included in article listings and feeds.
+    #[default]
+    Published,
+    /// Visible to anyone with the article's slug, but excluded from article listings and feeds.
+    Unlisted,
+}
+
+impl ArticleStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArticleStatus::Draft => "draft",
+            ArticleStatus::Published => "published",
+            ArticleStatus::Unlisted => "unlisted",
+        }
+    }
+}
+
+impl fmt::Display for ArticleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ArticleStatus {
+    type Err = String;
+
+    fn from_str(status: &str) -> Result<Self, Self::Err> {
+        match status {
+            "draft" => Ok(ArticleStatus::Draft),
+            "published" => Ok(ArticleStatus::Published),
+            "unlisted" => Ok(ArticleStatus::Unlisted),
+            _ => Err(format!("article status {:?} is not supported", status)),
+        }
+    }
+}
+
+/// Parses the RFC 3339 time an article is scheduled to be published at.
+pub fn parse_publish_at(publish_at: &str) -> Result<OffsetDateTime, String> {
+    OffsetDateTime::parse(publish_at, Format::Rfc3339)
+        .map_err(|_| format!("publish time {:?} is not a valid RFC 3339 timestamp", publish_at))
+}
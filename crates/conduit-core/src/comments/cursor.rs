@@ -0,0 +1,55 @@
+use sqlx::types::time::OffsetDateTime;
+
+use crate::articles::cursor::{decode_position, encode_position, parse_timestamp_micros, timestamp_micros};
+use crate::errors::{ConduitError, ConduitResult};
+
+/// A keyset position within an article's top-level comments, pointing at the last comment of a page.
+///
+/// The reaction count is part of the position, as comments sorted by most reacted are ordered on it before the time
+/// they were posted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CommentCursor {
+    pub reactions_count: i64,
+    pub created_at: OffsetDateTime,
+    pub id: i64,
+}
+
+impl CommentCursor {
+    pub fn new(reactions_count: i64, created_at: OffsetDateTime, id: i64) -> Self {
+        Self {
+            reactions_count,
+            created_at,
+            id,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        encode_position(&format!(
+            "{}:{}:{}",
+            self.reactions_count,
+            timestamp_micros(self.created_at),
+            self.id
+        ))
+    }
+
+    pub fn decode(cursor: &str) -> ConduitResult<Self> {
+        let invalid_cursor = || ConduitError::BadRequest(String::from("comment cursor is invalid"));
+
+        let decoded = decode_position(cursor).ok_or_else(invalid_cursor)?;
+        let mut parts = decoded.splitn(3, ':');
+        let (reactions_count, micros, id) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(reactions_count), Some(micros), Some(id)) => (reactions_count, micros, id),
+            _ => return Err(invalid_cursor()),
+        };
+
+        let reactions_count = reactions_count
+            .parse::<i64>()
+            .ok()
+            .filter(|reactions_count| *reactions_count >= 0)
+            .ok_or_else(invalid_cursor)?;
+        let created_at = parse_timestamp_micros(micros).ok_or_else(invalid_cursor)?;
+        let id = id.parse::<i64>().map_err(|_| invalid_cursor())?;
+
+        Ok(Self::new(reactions_count, created_at, id))
+    }
+}
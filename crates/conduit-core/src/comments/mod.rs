@@ -1,2 +1,4 @@
+pub mod cursor;
 pub mod repository;
 pub mod service;
+pub mod thread;
@@ -7,20 +7,86 @@ use sqlx::FromRow;
 use time::Format;
 
 use conduit_domain::articles::models::AuthorDto;
-use conduit_domain::comments::CommentDto;
+use conduit_domain::comments::requests::CommentSort;
+use conduit_domain::comments::{CommentDto, CommentRevisionDto};
+use conduit_domain::trash::TrashedCommentDto;
+
+use crate::comments::cursor::CommentCursor;
+use crate::utils::unit_of_work::DynUnitOfWork;
 
 pub type DynCommentsRepository = Arc<dyn CommentsRepository + Send + Sync>;
 
 #[automock]
 #[async_trait]
 pub trait CommentsRepository {
-    async fn get_comments(&self, user_id: Option<i64>, article_id: i64) -> anyhow::Result<Vec<CommentQuery>>;
+    /// Retrieves a page of the article's top-level comments after the cursor in the sort order, including deleted
+    /// comments that have replies so the replies can be threaded under them.
+    async fn get_comments(
+        &self,
+        user_id: Option<i64>,
+        article_id: i64,
+        sort: CommentSort,
+        limit: i64,
+        cursor: Option<CommentCursor>,
+    ) -> anyhow::Result<Vec<CommentQuery>>;
+
+    /// Retrieves every reply, however deeply nested, to the comments oldest first, including deleted replies.
+    async fn get_comment_replies(
+        &self,
+        user_id: Option<i64>,
+        comment_ids: Vec<i64>,
+    ) -> anyhow::Result<Vec<CommentQuery>>;
+
+    /// Counts the article's comments, including replies, that have not been deleted.
+    async fn get_comments_count(&self, article_id: i64) -> anyhow::Result<i64>;
 
     async fn get_comment(&self, comment_id: i64) -> anyhow::Result<Option<CommentEntity>>;
 
-    async fn create_comment(&self, article_id: i64, user_id: i64, body: String) -> anyhow::Result<CommentQuery>;
+    async fn create_comment(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        article_id: i64,
+        user_id: i64,
+        parent_id: Option<i64>,
+        body: String,
+    ) -> anyhow::Result<CommentQuery>;
+
+    /// Replaces the comment's body, recording the time it was edited.
+    async fn update_comment(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        comment_id: i64,
+        body: String,
+    ) -> anyhow::Result<CommentQuery>;
+
+    /// Records the comment's current body as a previous version, before it is replaced by an edit.
+    async fn create_comment_revision(&self, unit_of_work: &mut DynUnitOfWork, comment_id: i64) -> anyhow::Result<()>;
+
+    /// Retrieves the previous versions of the comment, oldest first.
+    async fn get_comment_revisions(&self, comment_id: i64) -> anyhow::Result<Vec<CommentRevisionEntity>>;
+
+    /// Moves the comment to the trash, hiding it until it is restored or purged.
+    async fn delete_comment(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        comment_id: i64,
+        deleted_by: i64,
+    ) -> anyhow::Result<()>;
+
+    /// Retrieves the comments the user moved to the trash since the time, most recently deleted first.
+    async fn get_deleted_comments(
+        &self,
+        deleted_by: i64,
+        deleted_after: OffsetDateTime,
+    ) -> anyhow::Result<Vec<DeletedCommentEntity>>;
 
-    async fn delete_comment(&self, comment_id: i64) -> anyhow::Result<()>;
+    async fn get_deleted_comment(&self, comment_id: i64) -> anyhow::Result<Option<DeletedCommentEntity>>;
+
+    async fn restore_comment(&self, comment_id: i64) -> anyhow::Result<()>;
+
+    /// Permanently removes comments moved to the trash before the time, returning the number of comments removed.
+    /// Comments with replies that remain are kept, as placeholders the replies are threaded under.
+    async fn purge_deleted_comments(&self, deleted_before: OffsetDateTime) -> anyhow::Result<u64>;
 }
 
 #[derive(FromRow)]
@@ -29,10 +95,20 @@ pub struct CommentEntity {
     pub body: String,
     pub user_id: i64,
     pub article_id: i64,
+    pub parent_id: Option<i64>,
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime,
 }
 
+#[derive(FromRow)]
+pub struct DeletedCommentEntity {
+    pub id: i64,
+    pub body: String,
+    pub article_slug: String,
+    pub deleted_by: Option<i64>,
+    pub deleted_at: OffsetDateTime,
+}
+
 #[derive(FromRow)]
 pub struct CommentQuery {
     pub id: i64,
@@ -43,21 +119,88 @@ pub struct CommentQuery {
     pub author_bio: String,
     pub author_image: String,
     pub following_author: bool,
+    pub parent_id: Option<i64>,
+    pub deleted: bool,
+    pub edited: bool,
+    pub reactions_count: i64,
+}
+
+#[derive(FromRow)]
+pub struct CommentRevisionEntity {
+    pub id: i64,
+    pub comment_id: i64,
+    pub body: String,
+    pub created_at: OffsetDateTime,
 }
 
+/// The body and author name shown in place of a deleted comment.
+pub const DELETED_COMMENT_PLACEHOLDER: &str = "[deleted]";
+
 impl From<CommentQuery> for CommentDto {
     fn from(query: CommentQuery) -> Self {
+        if query.deleted {
+            return Self {
+                id: query.id,
+                created_at: query.created_at.lazy_format(Format::Rfc3339).to_string(),
+                updated_at: query.updated_at.lazy_format(Format::Rfc3339).to_string(),
+                body: String::from(DELETED_COMMENT_PLACEHOLDER),
+                body_html: None,
+                author: AuthorDto {
+                    username: String::from(DELETED_COMMENT_PLACEHOLDER),
+                    bio: None,
+                    image: None,
+                    following: false,
+                },
+                parent_id: query.parent_id,
+                deleted: true,
+                edited: false,
+                reactions: Vec::new(),
+                my_reactions: Vec::new(),
+                mentions: Vec::new(),
+                replies: Vec::new(),
+            };
+        }
+
         Self {
             id: query.id,
             created_at: query.created_at.lazy_format(Format::Rfc3339).to_string(),
             updated_at: query.updated_at.lazy_format(Format::Rfc3339).to_string(),
             body: query.body,
+            body_html: None,
             author: AuthorDto {
                 username: query.author_username,
                 bio: Some(query.author_bio),
                 image: Some(query.author_image),
                 following: query.following_author,
             },
+            parent_id: query.parent_id,
+            deleted: false,
+            edited: query.edited,
+            reactions: Vec::new(),
+            my_reactions: Vec::new(),
+            mentions: Vec::new(),
+            replies: Vec::new(),
+        }
+    }
+}
+
+impl CommentRevisionEntity {
+    pub fn into_dto(self) -> CommentRevisionDto {
+        CommentRevisionDto {
+            body: self.body,
+            created_at: self.created_at.lazy_format(Format::Rfc3339).to_string(),
+        }
+    }
+}
+
+impl DeletedCommentEntity {
+    pub fn into_dto(self, purge_at: OffsetDateTime) -> TrashedCommentDto {
+        TrashedCommentDto {
+            id: self.id,
+            body: self.body,
+            article_slug: self.article_slug,
+            deleted_at: self.deleted_at.lazy_format(Format::Rfc3339).to_string(),
+            purge_at: purge_at.lazy_format(Format::Rfc3339).to_string(),
         }
     }
 }
@@ -3,18 +3,51 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use mockall::automock;
 
-use conduit_domain::comments::CommentDto;
+use conduit_domain::comments::requests::GetCommentsServiceRequest;
+use conduit_domain::comments::responses::CommentsResponse;
+use conduit_domain::comments::{CommentDto, CommentRevisionDto};
 
 use crate::errors::ConduitResult;
+use crate::users::roles::Role;
 
 pub type DynCommentsService = Arc<dyn CommentsService + Send + Sync>;
 
 #[automock]
 #[async_trait]
 pub trait CommentsService {
-    async fn get_comments(&self, user_id: Option<i64>, slug: String) -> ConduitResult<Vec<CommentDto>>;
+    /// Retrieves a page of the article's comments, with replies nested under the comments they reply to.
+    async fn get_comments(
+        &self,
+        user_id: Option<i64>,
+        slug: String,
+        request: GetCommentsServiceRequest,
+    ) -> ConduitResult<CommentsResponse>;
 
-    async fn add_comment(&self, user_id: i64, slug: String, body: String) -> ConduitResult<CommentDto>;
+    async fn add_comment(
+        &self,
+        user_id: i64,
+        slug: String,
+        parent_id: Option<i64>,
+        body: String,
+    ) -> ConduitResult<CommentDto>;
 
-    async fn remove_comment(&self, user_id: i64, comment_id: i64) -> ConduitResult<()>;
+    /// Replaces the body of the user's comment on the article, keeping the previous body, while the comment is within
+    /// its edit window.
+    async fn update_comment(
+        &self,
+        user_id: i64,
+        slug: String,
+        comment_id: i64,
+        body: String,
+    ) -> ConduitResult<CommentDto>;
+
+    /// Retrieves the previous versions of the comment on the article, oldest first, available only to moderators.
+    async fn get_comment_revisions(
+        &self,
+        role: Role,
+        slug: String,
+        comment_id: i64,
+    ) -> ConduitResult<Vec<CommentRevisionDto>>;
+
+    async fn remove_comment(&self, user_id: i64, role: Role, comment_id: i64) -> ConduitResult<()>;
 }
@@ -0,0 +1,70 @@
+use std::collections::{HashMap, HashSet};
+
+use conduit_domain::comments::CommentDto;
+
+/// Nests the article's comments, ordered oldest first, under the comments they reply to. Replies nested deeper than
+/// the max depth are listed, oldest first, as replies of their ancestor at the max depth, and deleted comments are
+/// kept only as placeholders for replies that remain.
+pub fn build_comment_tree(comments: Vec<CommentDto>, max_depth: usize) -> Vec<CommentDto> {
+    let comment_ids: HashSet<i64> = comments.iter().map(|comment| comment.id).collect();
+
+    let mut top_level_comments = Vec::new();
+    let mut replies: HashMap<i64, Vec<CommentDto>> = HashMap::new();
+
+    for comment in comments {
+        // replies to comments no longer listed, such as purged comments, are shown as comments on the article
+        match comment.parent_id.filter(|parent_id| comment_ids.contains(parent_id)) {
+            Some(parent_id) => replies.entry(parent_id).or_default().push(comment),
+            None => top_level_comments.push(comment),
+        }
+    }
+
+    top_level_comments
+        .into_iter()
+        .filter_map(|comment| attach_replies(comment, 0, max_depth, &mut replies))
+        .collect()
+}
+
+fn attach_replies(
+    mut comment: CommentDto,
+    depth: usize,
+    max_depth: usize,
+    replies: &mut HashMap<i64, Vec<CommentDto>>,
+) -> Option<CommentDto> {
+    let comment_replies = replies.remove(&comment.id).unwrap_or_default();
+
+    if depth < max_depth {
+        comment.replies = comment_replies
+            .into_iter()
+            .filter_map(|reply| attach_replies(reply, depth + 1, max_depth, replies))
+            .collect();
+    } else {
+        let mut descendants = Vec::new();
+        collect_descendants(comment_replies, replies, &mut descendants);
+
+        descendants.sort_by_key(|descendant| descendant.id);
+        comment.replies = descendants;
+    }
+
+    if comment.deleted && comment.replies.is_empty() {
+        return None;
+    }
+
+    Some(comment)
+}
+
+fn collect_descendants(
+    comments: Vec<CommentDto>,
+    replies: &mut HashMap<i64, Vec<CommentDto>>,
+    descendants: &mut Vec<CommentDto>,
+) {
+    for comment in comments {
+        let comment_replies = replies.remove(&comment.id).unwrap_or_default();
+
+        if !comment.deleted {
+            descendants.push(comment);
+        }
+
+        collect_descendants(comment_replies, replies, descendants);
+    }
+}
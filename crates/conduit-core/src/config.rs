@@ -6,8 +6,50 @@ pub struct AppConfig {
     pub rust_log: String,
     #[clap(long, env)]
     pub argon_salt: String,
+    #[clap(long, env, default_value = "argon2id")]
+    pub argon_variant: String,
+    #[clap(long, env, default_value = "19456")]
+    pub argon_memory_cost_kib: u32,
+    #[clap(long, env, default_value = "2")]
+    pub argon_iterations: u32,
     #[clap(long, env)]
     pub token_secret: String,
+    #[clap(long, env, default_value = "3600")]
+    pub access_token_lifetime_seconds: u64,
+    #[clap(long, env, default_value = "30")]
+    pub refresh_token_lifetime_days: u64,
+    #[clap(long, env, default_value = "60")]
+    pub password_reset_token_lifetime_minutes: u64,
+    #[clap(long, env, default_value = "24")]
+    pub email_verification_token_lifetime_hours: u64,
+    #[clap(long, env)]
+    pub require_email_verification: bool,
+    #[clap(long, env, default_value = "Conduit")]
+    pub two_factor_issuer: String,
+    #[clap(long, env, default_value = "5")]
+    pub two_factor_challenge_lifetime_minutes: u64,
+    #[clap(long, env, default_value = "5")]
+    pub login_max_failed_attempts: i32,
+    #[clap(long, env, default_value = "20")]
+    pub login_max_failed_attempts_per_ip: i32,
+    #[clap(long, env, default_value = "15")]
+    pub login_failure_window_minutes: i64,
+    #[clap(long, env, default_value = "30")]
+    pub login_lockout_seconds: u64,
+    #[clap(long, env, default_value = "900")]
+    pub login_max_lockout_seconds: u64,
+    #[clap(long, env)]
+    pub trust_proxy_headers: bool,
+    #[clap(long, env, default_value = "60")]
+    pub scheduler_interval_seconds: u64,
+    #[clap(long, env, default_value = "30")]
+    pub trash_retention_days: u64,
+    #[clap(long, env, default_value = "5")]
+    pub comment_max_depth: usize,
+    #[clap(long, env, default_value = "15")]
+    pub comment_edit_window_minutes: u64,
+    #[clap(long, env, default_value = "👍,👎,😄,🎉,😕,❤️,🚀,👀")]
+    pub reaction_emojis: String,
     #[clap(long, env)]
     pub port: u32,
     #[clap(long, env)]
@@ -15,5 +57,25 @@ pub struct AppConfig {
     #[clap(long, env)]
     pub seed: bool,
     #[clap(long, env)]
+    pub admin_username: Option<String>,
+    #[clap(long, env)]
     pub cors_origin: String,
+    #[clap(long, env, default_value = "http://localhost:8080/api")]
+    pub api_url: String,
+    #[clap(long, env, default_value = "http://localhost:3000")]
+    pub site_url: String,
+    #[clap(long, env, default_value = "log")]
+    pub mail_transport: String,
+    #[clap(long, env, default_value = "noreply@conduit.local")]
+    pub mail_from: String,
+    #[clap(long, env)]
+    pub mail_directory: Option<String>,
+    #[clap(long, env)]
+    pub smtp_host: Option<String>,
+    #[clap(long, env, default_value = "587")]
+    pub smtp_port: u16,
+    #[clap(long, env)]
+    pub smtp_username: Option<String>,
+    #[clap(long, env)]
+    pub smtp_password: Option<String>,
 }
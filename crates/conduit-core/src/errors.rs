@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 use std::{collections::HashMap, fmt::Debug};
 
+use axum::http::header::RETRY_AFTER;
 use axum::response::Response;
 use axum::{http::StatusCode, response::IntoResponse, Json};
 use conduit_domain::ApiError;
@@ -21,6 +22,8 @@ pub enum ConduitError {
     InvalidLoginAttmpt,
     #[error("user does not have privilege to access this resource")]
     Forbidden,
+    #[error("too many failed login attempts, try again in {0} seconds")]
+    TooManyLoginAttempts(u64),
     #[error("{0}")]
     NotFound(String),
     #[error("{0}")]
@@ -83,10 +86,19 @@ impl IntoResponse for ConduitError {
             return Self::unprocessable_entity(e);
         }
 
+        if let Self::TooManyLoginAttempts(retry_after_seconds) = self {
+            let body = Json(ApiError::new(self.to_string()));
+            let headers = [(RETRY_AFTER, retry_after_seconds.to_string())];
+
+            return (StatusCode::TOO_MANY_REQUESTS, headers, body).into_response();
+        }
+
         let (status, error_message) = match self {
             Self::InternalServerErrorWithContext(err) => (StatusCode::INTERNAL_SERVER_ERROR, err),
             Self::NotFound(err) => (StatusCode::NOT_FOUND, err),
             Self::ObjectConflict(err) => (StatusCode::CONFLICT, err),
+            Self::BadRequest(err) => (StatusCode::BAD_REQUEST, err),
+            Self::Forbidden => (StatusCode::FORBIDDEN, Self::Forbidden.to_string()),
             Self::InvalidLoginAttmpt => (StatusCode::BAD_REQUEST, Self::InvalidLoginAttmpt.to_string()),
             Self::Unauthorized => (StatusCode::UNAUTHORIZED, Self::Unauthorized.to_string()),
             _ => (
@@ -0,0 +1,66 @@
+use std::fmt;
+use std::str::FromStr;
+
+pub mod service;
+
+/// The syndication formats articles are published in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    Atom,
+    Rss,
+}
+
+impl FeedFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            FeedFormat::Atom => "atom",
+            FeedFormat::Rss => "rss",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+        }
+    }
+
+    /// Splits a feed file name, such as `rust.atom`, into the name and the format of the feed.
+    pub fn split_file_name(file_name: &str) -> Option<(String, FeedFormat)> {
+        let (name, extension) = file_name.rsplit_once('.')?;
+        let format = FeedFormat::from_str(extension).ok()?;
+
+        if name.is_empty() {
+            return None;
+        }
+
+        Some((name.to_owned(), format))
+    }
+}
+
+impl fmt::Display for FeedFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+impl FromStr for FeedFormat {
+    type Err = String;
+
+    fn from_str(extension: &str) -> Result<Self, Self::Err> {
+        match extension {
+            "atom" => Ok(FeedFormat::Atom),
+            "rss" => Ok(FeedFormat::Rss),
+            _ => Err(format!("feed format {:?} is not supported", extension)),
+        }
+    }
+}
+
+/// A rendered feed, along with the validators clients use to make conditional requests for it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Feed {
+    pub content: String,
+    /// A strong entity tag derived from the content, changing whenever any part of the feed does, including when
+    /// articles leave the feed.
+    pub etag: String,
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::errors::ConduitResult;
+use crate::feeds::{Feed, FeedFormat};
+
+/// A reference counter for our feeds service, responsible for syndicating published articles as Atom and RSS feeds.
+pub type DynFeedsService = Arc<dyn FeedsService + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait FeedsService {
+    /// Builds a feed of the most recently published articles, narrowed to those of the author or tagged with the tag
+    /// when either is given.
+    async fn get_articles_feed(
+        &self,
+        format: FeedFormat,
+        author: Option<String>,
+        tag: Option<String>,
+    ) -> ConduitResult<Feed>;
+}
@@ -1,8 +1,17 @@
+pub mod access_tokens;
+pub mod admin;
 pub mod articles;
 pub mod comments;
 pub mod config;
 pub mod errors;
+pub mod feeds;
+pub mod mentions;
+pub mod notifications;
 pub mod profiles;
+pub mod reactions;
+pub mod sessions;
 pub mod tags;
+pub mod trash;
+pub mod two_factor;
 pub mod users;
 pub mod utils;
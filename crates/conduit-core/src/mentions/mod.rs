@@ -0,0 +1,59 @@
+pub mod repository;
+
+/// The most distinct users a single article or comment may mention, beyond which mentions are ignored.
+pub const MAX_MENTIONS: usize = 10;
+
+/// Parses the distinct usernames `@mentioned` in the body, in the order they are first mentioned. Mentions must
+/// follow whitespace or punctuation, so email addresses are not mistaken for mentions, and mentions within code
+/// are ignored.
+pub fn parse_mentions(body: &str) -> Vec<String> {
+    let mut mentions: Vec<String> = Vec::new();
+    let mut in_code = false;
+    let mut previous: Option<char> = None;
+    let mut chars = body.char_indices().peekable();
+
+    while let Some((index, current)) = chars.next() {
+        if current == '`' {
+            in_code = !in_code;
+        }
+
+        let follows_boundary = !matches!(previous, Some(previous) if is_username_char(previous) || previous == '@');
+
+        if current == '@' && !in_code && follows_boundary {
+            let start = index + current.len_utf8();
+            let mut end = start;
+
+            while let Some(&(next_index, next)) = chars.peek() {
+                if !is_username_char(next) {
+                    break;
+                }
+
+                end = next_index + next.len_utf8();
+                previous = Some(next);
+                chars.next();
+            }
+
+            let username = &body[start..end];
+
+            if !username.is_empty() && !mentions.iter().any(|mention| mention == username) {
+                if mentions.len() == MAX_MENTIONS {
+                    break;
+                }
+
+                mentions.push(String::from(username));
+            }
+
+            if end > start {
+                continue;
+            }
+        }
+
+        previous = Some(current);
+    }
+
+    mentions
+}
+
+fn is_username_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
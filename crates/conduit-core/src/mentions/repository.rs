@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use sqlx::FromRow;
+
+use crate::utils::unit_of_work::DynUnitOfWork;
+
+pub type DynMentionsRepository = Arc<dyn MentionsRepository + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait MentionsRepository {
+    /// Replaces the users mentioned in the article, or in one of its comments when a comment is given, returning the
+    /// users that were not mentioned in it before.
+    async fn replace_mentions(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        article_id: i64,
+        comment_id: Option<i64>,
+        user_ids: Vec<i64>,
+    ) -> anyhow::Result<Vec<i64>>;
+
+    /// Retrieves the usernames of the users mentioned in the article's body.
+    async fn get_article_mentions(&self, article_id: i64) -> anyhow::Result<Vec<String>>;
+
+    /// Retrieves the ids of the users mentioned in the article's body.
+    async fn get_article_mentioned_user_ids(&self, article_id: i64) -> anyhow::Result<Vec<i64>>;
+
+    /// Retrieves the usernames of the users mentioned in each of the comments.
+    async fn get_comment_mentions(&self, comment_ids: Vec<i64>) -> anyhow::Result<Vec<CommentMentionQuery>>;
+}
+
+#[derive(FromRow)]
+pub struct CommentMentionQuery {
+    pub comment_id: i64,
+    pub username: String,
+}
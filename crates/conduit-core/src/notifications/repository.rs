@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use sqlx::types::time::OffsetDateTime;
+use sqlx::FromRow;
+use time::Format;
+
+use conduit_domain::notifications::NotificationDto;
+
+use crate::utils::unit_of_work::DynUnitOfWork;
+
+pub type DynNotificationsRepository = Arc<dyn NotificationsRepository + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait NotificationsRepository {
+    /// Notifies each of the users that the actor mentioned them in the article, or in one of its comments when a
+    /// comment is given.
+    async fn create_mention_notifications(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        actor_id: i64,
+        article_id: i64,
+        comment_id: Option<i64>,
+        user_ids: Vec<i64>,
+    ) -> anyhow::Result<()>;
+
+    async fn get_notifications(&self, user_id: i64, limit: i64, offset: i64) -> anyhow::Result<Vec<NotificationQuery>>;
+
+    async fn get_unread_notifications_count(&self, user_id: i64) -> anyhow::Result<i64>;
+
+    /// Marks the user's notification as read, or all of their notifications when none is given, returning the number
+    /// of notifications marked.
+    async fn mark_notifications_read(&self, user_id: i64, notification_id: Option<i64>) -> anyhow::Result<u64>;
+}
+
+#[derive(FromRow)]
+pub struct NotificationQuery {
+    pub id: i64,
+    pub kind: String,
+    pub actor_username: String,
+    pub article_slug: String,
+    pub article_title: String,
+    pub comment_id: Option<i64>,
+    pub read_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+impl From<NotificationQuery> for NotificationDto {
+    fn from(query: NotificationQuery) -> Self {
+        Self {
+            id: query.id,
+            kind: query.kind,
+            actor: query.actor_username,
+            article_slug: query.article_slug,
+            article_title: query.article_title,
+            comment_id: query.comment_id,
+            read: query.read_at.is_some(),
+            created_at: query.created_at.lazy_format(Format::Rfc3339).to_string(),
+        }
+    }
+}
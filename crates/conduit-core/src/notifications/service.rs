@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use conduit_domain::notifications::requests::GetNotificationsServiceRequest;
+use conduit_domain::notifications::responses::NotificationsResponse;
+
+use crate::errors::ConduitResult;
+
+/// A reference counter for our notifications service, letting users catch up on where they were mentioned.
+pub type DynNotificationsService = Arc<dyn NotificationsService + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait NotificationsService {
+    /// Retrieves the user's notifications, newest first, along with the number they have yet to read.
+    async fn get_notifications(
+        &self,
+        user_id: i64,
+        request: GetNotificationsServiceRequest,
+    ) -> ConduitResult<NotificationsResponse>;
+
+    /// Marks one of the user's notifications as read.
+    async fn mark_notification_read(&self, user_id: i64, notification_id: i64) -> ConduitResult<()>;
+
+    /// Marks all of the user's notifications as read.
+    async fn mark_all_notifications_read(&self, user_id: i64) -> ConduitResult<()>;
+}
@@ -0,0 +1,51 @@
+use conduit_domain::reactions::ReactionDto;
+
+use crate::reactions::repository::ReactionCountQuery;
+
+pub mod repository;
+pub mod service;
+
+/// The article or comment a reaction is given to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReactionTarget {
+    Article(i64),
+    Comment(i64),
+}
+
+/// Parses the configured comma separated emoji set, ignoring surrounding whitespace and empty entries.
+pub fn parse_reaction_emojis(reaction_emojis: &str) -> Vec<String> {
+    let mut emojis: Vec<String> = Vec::new();
+
+    for emoji in reaction_emojis.split(',').map(str::trim) {
+        if !emoji.is_empty() && !emojis.iter().any(|existing| existing == emoji) {
+            emojis.push(String::from(emoji));
+        }
+    }
+
+    emojis
+}
+
+/// Collects the reaction counts for the target from the counts of several targets, returning the reactions along with
+/// the emojis the requesting user reacted with.
+pub fn collect_reactions(target_id: i64, reactions: &[ReactionCountQuery]) -> (Vec<ReactionDto>, Vec<String>) {
+    let target_reactions = reactions
+        .iter()
+        .filter(|reaction| reaction.target_id == target_id)
+        .collect::<Vec<_>>();
+
+    let my_reactions = target_reactions
+        .iter()
+        .filter(|reaction| reaction.reacted)
+        .map(|reaction| reaction.emoji.clone())
+        .collect();
+
+    let reactions = target_reactions
+        .into_iter()
+        .map(|reaction| ReactionDto {
+            emoji: reaction.emoji.clone(),
+            count: reaction.count,
+        })
+        .collect();
+
+    (reactions, my_reactions)
+}
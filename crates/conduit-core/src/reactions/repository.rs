@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use sqlx::FromRow;
+
+use crate::reactions::ReactionTarget;
+use crate::utils::unit_of_work::DynUnitOfWork;
+
+pub type DynReactionsRepository = Arc<dyn ReactionsRepository + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait ReactionsRepository {
+    async fn add_reaction(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        target: ReactionTarget,
+        emoji: String,
+    ) -> anyhow::Result<()>;
+
+    /// Removes the user's reaction, returning the number of reactions removed.
+    async fn remove_reaction(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        target: ReactionTarget,
+        emoji: String,
+    ) -> anyhow::Result<u64>;
+
+    /// Counts the reactions to each of the articles by emoji, in the order each emoji was first reacted with.
+    async fn get_article_reactions(
+        &self,
+        user_id: Option<i64>,
+        article_ids: Vec<i64>,
+    ) -> anyhow::Result<Vec<ReactionCountQuery>>;
+
+    /// Counts the reactions to each of the comments by emoji, in the order each emoji was first reacted with.
+    async fn get_comment_reactions(
+        &self,
+        user_id: Option<i64>,
+        comment_ids: Vec<i64>,
+    ) -> anyhow::Result<Vec<ReactionCountQuery>>;
+}
+
+#[derive(FromRow)]
+pub struct ReactionCountQuery {
+    /// The id of the article or comment reacted to.
+    pub target_id: i64,
+    pub emoji: String,
+    pub count: i64,
+    /// Whether the requesting user is one of the users that reacted with the emoji.
+    pub reacted: bool,
+}
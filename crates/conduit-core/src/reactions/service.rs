@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use conduit_domain::reactions::responses::ReactionsResponse;
+
+use crate::errors::ConduitResult;
+
+pub type DynReactionsService = Arc<dyn ReactionsService + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait ReactionsService {
+    /// Adds the user's reaction to the article, or removes it when the user already reacted with the emoji.
+    async fn toggle_article_reaction(
+        &self,
+        user_id: i64,
+        slug: String,
+        emoji: String,
+    ) -> ConduitResult<ReactionsResponse>;
+
+    /// Adds the user's reaction to the comment on the article, or removes it when the user already reacted with the
+    /// emoji.
+    async fn toggle_comment_reaction(
+        &self,
+        user_id: i64,
+        slug: String,
+        comment_id: i64,
+        emoji: String,
+    ) -> ConduitResult<ReactionsResponse>;
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+use sqlx::types::time::OffsetDateTime;
+use sqlx::FromRow;
+
+use crate::utils::unit_of_work::DynUnitOfWork;
+
+pub type DynSessionsRepository = Arc<dyn SessionsRepository + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait SessionsRepository {
+    async fn create_refresh_token(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        token_hash: String,
+        family: String,
+        expires_at: OffsetDateTime,
+    ) -> anyhow::Result<RefreshTokenEntity>;
+
+    async fn get_refresh_token(&self, token_hash: String) -> anyhow::Result<Option<RefreshTokenEntity>>;
+
+    /// Revokes an active refresh token, returning false if the token had already been revoked.
+    async fn revoke_refresh_token(&self, unit_of_work: &mut DynUnitOfWork, id: i64) -> anyhow::Result<bool>;
+
+    async fn revoke_refresh_token_family(&self, unit_of_work: &mut DynUnitOfWork, family: String)
+        -> anyhow::Result<()>;
+
+    /// Revokes every active refresh token for the user, ending all of their sessions.
+    async fn revoke_user_refresh_tokens(&self, unit_of_work: &mut DynUnitOfWork, user_id: i64) -> anyhow::Result<()>;
+
+    async fn revoke_access_token(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        token_id: String,
+        expires_at: OffsetDateTime,
+    ) -> anyhow::Result<()>;
+
+    /// Determines if the access token has been revoked, its user suspended or removed, or assigned another role than
+    /// the one the token was issued with.
+    async fn is_session_revoked(&self, token_id: String, user_id: i64, role: String) -> anyhow::Result<bool>;
+
+    async fn create_login_challenge(
+        &self,
+        user_id: i64,
+        token_hash: String,
+        expires_at: OffsetDateTime,
+    ) -> anyhow::Result<()>;
+
+    async fn get_login_challenge(&self, token_hash: String) -> anyhow::Result<Option<LoginChallengeEntity>>;
+
+    /// Marks the challenge as completed, returning false if the challenge had already been completed.
+    async fn use_login_challenge(&self, unit_of_work: &mut DynUnitOfWork, id: i64) -> anyhow::Result<bool>;
+
+    /// Records a failed attempt to complete the challenge, returning the number of failed attempts so far.
+    async fn record_login_challenge_attempt(&self, id: i64) -> anyhow::Result<i32>;
+
+    /// Retrieves the latest time any of the throttle keys are locked out until, if any are currently locked out.
+    async fn get_login_lockout(&self, keys: Vec<String>) -> anyhow::Result<Option<OffsetDateTime>>;
+
+    /// Records a failed login for the throttle key, returning the number of failures within the window. Failures
+    /// are counted from one again once the previous failure falls outside of the window.
+    async fn record_login_failure(&self, key: String, window_minutes: i64) -> anyhow::Result<i32>;
+
+    async fn lock_login(&self, key: String, locked_until: OffsetDateTime) -> anyhow::Result<()>;
+
+    async fn clear_login_failures(&self, key: String) -> anyhow::Result<()>;
+}
+
+#[derive(FromRow)]
+pub struct RefreshTokenEntity {
+    pub id: i64,
+    pub user_id: i64,
+    pub token_hash: String,
+    pub family: String,
+    pub expires_at: OffsetDateTime,
+    pub revoked_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(FromRow)]
+pub struct LoginChallengeEntity {
+    pub id: i64,
+    pub user_id: i64,
+    pub token_hash: String,
+    pub attempts: i32,
+    pub expires_at: OffsetDateTime,
+    pub used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use conduit_domain::users::UserDto;
+
+use crate::errors::ConduitResult;
+use crate::users::roles::Role;
+use crate::utils::token_service::TokenClaims;
+
+/// A reference counter for our sessions service, responsible for issuing and revoking the refresh tokens
+/// that allow users to renew their short-lived access tokens.
+pub type DynSessionsService = Arc<dyn SessionsService + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait SessionsService {
+    /// Starts a new session for the user, returning the raw refresh token to hand back to the client.
+    async fn new_refresh_token(&self, user_id: i64) -> ConduitResult<String>;
+
+    /// Rotates the refresh token, returning the user with a new access and refresh token.
+    async fn refresh_session(&self, refresh_token: String) -> ConduitResult<UserDto>;
+
+    /// Revokes the refresh token along with the access token used to make the request.
+    async fn revoke_session(&self, claims: TokenClaims, refresh_token: String) -> ConduitResult<()>;
+
+    /// Determines if the access token can no longer be used, either from being revoked, its user being suspended or
+    /// its user's role changing since it was issued.
+    async fn is_session_revoked(&self, token_id: String, user_id: i64, role: Role) -> ConduitResult<bool>;
+}
@@ -4,6 +4,8 @@ use async_trait::async_trait;
 use mockall::automock;
 use sqlx::types::time::OffsetDateTime;
 
+use crate::utils::unit_of_work::DynUnitOfWork;
+
 pub type DynTagsRepository = Arc<dyn TagsRepository + Send + Sync>;
 
 #[automock]
@@ -11,13 +13,27 @@ pub type DynTagsRepository = Arc<dyn TagsRepository + Send + Sync>;
 pub trait TagsRepository {
     async fn get_tags(&self, tags: Vec<String>) -> anyhow::Result<Vec<TagEntity>>;
 
-    async fn create_tags(&self, tags: Vec<String>) -> anyhow::Result<Vec<TagEntity>>;
+    async fn create_tags(&self, unit_of_work: &mut DynUnitOfWork, tags: Vec<String>) -> anyhow::Result<Vec<TagEntity>>;
 
     async fn get_article_tags_by_article_id(&self, article_id: i64) -> anyhow::Result<Vec<ArticleTagQuery>>;
 
     async fn get_article_tags_article_ids(&self, article_ids: Vec<i64>) -> anyhow::Result<Vec<ArticleTagQuery>>;
 
-    async fn create_article_tags(&self, tags: Vec<(i64, i64)>) -> anyhow::Result<()>;
+    async fn create_article_tags(&self, unit_of_work: &mut DynUnitOfWork, tags: Vec<(i64, i64)>) -> anyhow::Result<()>;
+
+    async fn delete_article_tags(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        article_id: i64,
+        tag_ids: Vec<i64>,
+    ) -> anyhow::Result<()>;
+
+    /// Removes the tags no longer referenced by any article, returning the number of tags removed.
+    async fn delete_unreferenced_tags(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        tag_ids: Vec<i64>,
+    ) -> anyhow::Result<u64>;
 }
 
 pub struct TagEntity {
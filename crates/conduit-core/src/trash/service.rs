@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use conduit_domain::trash::{TrashedArticleDto, TrashedCommentDto};
+
+use crate::errors::ConduitResult;
+
+/// A reference counter for our trash service, responsible for restoring deleted articles and comments within the
+/// retention window and permanently removing them once it has passed.
+pub type DynTrashService = Arc<dyn TrashService + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait TrashService {
+    /// Retrieves the articles and comments the user deleted that can still be restored.
+    async fn get_trash(&self, user_id: i64) -> ConduitResult<(Vec<TrashedArticleDto>, Vec<TrashedCommentDto>)>;
+
+    /// Restores an article the user deleted, as long as it has not yet been purged.
+    async fn restore_article(&self, user_id: i64, slug: String) -> ConduitResult<()>;
+
+    /// Restores a comment the user deleted, as long as it has not yet been purged.
+    async fn restore_comment(&self, user_id: i64, comment_id: i64) -> ConduitResult<()>;
+
+    /// Permanently removes every article and comment deleted before the retention window, returning the number of
+    /// items removed.
+    async fn purge_expired(&self) -> ConduitResult<u64>;
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use conduit_domain::two_factor::TwoFactorEnrollmentDto;
+use conduit_domain::users::UserDto;
+
+use crate::errors::ConduitResult;
+
+/// A reference counter for our two-factor service, responsible for enrolling users in TOTP based two-factor
+/// authentication and completing their logins once enrolled.
+pub type DynTwoFactorService = Arc<dyn TwoFactorService + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait TwoFactorService {
+    /// Generates a new secret for the user, which is not enforced until a first code is confirmed.
+    async fn begin_enrollment(&self, user_id: i64) -> ConduitResult<TwoFactorEnrollmentDto>;
+
+    /// Enables two-factor authentication once the user proves their authenticator is set up, returning the raw
+    /// recovery codes that are only ever shown here.
+    async fn confirm_enrollment(&self, user_id: i64, code: String) -> ConduitResult<Vec<String>>;
+
+    /// Disables two-factor authentication, requiring a current code or unused recovery code.
+    async fn disable_two_factor(&self, user_id: i64, code: String) -> ConduitResult<()>;
+
+    /// Exchanges the challenge issued on login, along with a current code or unused recovery code, for an access token.
+    async fn complete_login(&self, challenge_token: String, code: String) -> ConduitResult<UserDto>;
+}
@@ -1,2 +1,3 @@
 pub mod repository;
+pub mod roles;
 pub mod service;
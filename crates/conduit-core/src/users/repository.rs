@@ -1,3 +1,4 @@
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::SystemTime;
 
@@ -5,10 +6,15 @@ use async_trait::async_trait;
 use mockall::automock;
 use sqlx::types::time::OffsetDateTime;
 use sqlx::FromRow;
+use time::Format;
 
+use conduit_domain::admin::AdminUserDto;
 use conduit_domain::profiles::ProfileDto;
 use conduit_domain::users::UserDto;
 
+use crate::users::roles::Role;
+use crate::utils::unit_of_work::DynUnitOfWork;
+
 /// Similar to above, we want to keep a reference count across threads so we can manage our connection pool.
 pub type DynUsersRepository = Arc<dyn UsersRepository + Send + Sync>;
 
@@ -18,7 +24,13 @@ pub trait UsersRepository {
     async fn search_user_by_email_or_username(&self, email: &str, username: &str)
         -> anyhow::Result<Option<UserEntity>>;
 
-    async fn create_user(&self, email: &str, username: &str, hashed_password: &str) -> anyhow::Result<UserEntity>;
+    async fn create_user(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        email: &str,
+        username: &str,
+        hashed_password: &str,
+    ) -> anyhow::Result<UserEntity>;
 
     async fn get_user_by_email(&self, email: &str) -> anyhow::Result<Option<UserEntity>>;
 
@@ -26,8 +38,10 @@ pub trait UsersRepository {
 
     async fn get_user_by_id(&self, id: i64) -> anyhow::Result<UserEntity>;
 
+    #[allow(clippy::too_many_arguments)]
     async fn update_user(
         &self,
+        unit_of_work: &mut DynUnitOfWork,
         id: i64,
         email: String,
         username: String,
@@ -35,6 +49,110 @@ pub trait UsersRepository {
         bio: String,
         image: String,
     ) -> anyhow::Result<UserEntity>;
+
+    async fn update_user_password(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        id: i64,
+        hashed_password: String,
+    ) -> anyhow::Result<()>;
+
+    async fn create_password_reset_token(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        token_hash: String,
+        expires_at: OffsetDateTime,
+    ) -> anyhow::Result<()>;
+
+    async fn get_password_reset_token(&self, token_hash: String) -> anyhow::Result<Option<PasswordResetTokenEntity>>;
+
+    /// Marks a reset token as used, returning false if the token had already been used.
+    async fn use_password_reset_token(&self, unit_of_work: &mut DynUnitOfWork, id: i64) -> anyhow::Result<bool>;
+
+    /// Assigns the role to the user, returning false if no user exists with the username.
+    async fn update_user_role(&self, username: String, role: String) -> anyhow::Result<bool>;
+
+    /// Retrieves users with a username or email containing the search term, or all users when no term is given.
+    async fn get_users(&self, search: Option<String>, limit: i64, offset: i64) -> anyhow::Result<Vec<UserEntity>>;
+
+    async fn get_users_count(&self, search: Option<String>) -> anyhow::Result<i64>;
+
+    async fn update_user_suspension(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        id: i64,
+        suspended: bool,
+    ) -> anyhow::Result<UserEntity>;
+
+    async fn delete_user(&self, id: i64) -> anyhow::Result<()>;
+
+    async fn create_email_verification_token(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        email: String,
+        token_hash: String,
+        expires_at: OffsetDateTime,
+    ) -> anyhow::Result<()>;
+
+    async fn get_email_verification_token(
+        &self,
+        token_hash: String,
+    ) -> anyhow::Result<Option<EmailVerificationTokenEntity>>;
+
+    /// Marks a verification token as used, returning false if the token had already been used.
+    async fn use_email_verification_token(&self, unit_of_work: &mut DynUnitOfWork, id: i64) -> anyhow::Result<bool>;
+
+    /// Verifies the user's email, returning false if the user's email no longer matches the verified address.
+    async fn verify_user_email(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        email: String,
+    ) -> anyhow::Result<bool>;
+
+    /// Replaces the user's TOTP secret, leaving two-factor authentication disabled until a code is confirmed.
+    async fn update_user_totp_secret(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        id: i64,
+        totp_secret: Option<String>,
+    ) -> anyhow::Result<()>;
+
+    async fn enable_user_totp(&self, unit_of_work: &mut DynUnitOfWork, id: i64) -> anyhow::Result<()>;
+
+    /// Records the time step of a used code, returning false if a code for the same or a later step was already used.
+    async fn update_user_totp_last_used_step(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        id: i64,
+        step: i64,
+    ) -> anyhow::Result<bool>;
+
+    async fn create_recovery_codes(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        code_hashes: Vec<String>,
+    ) -> anyhow::Result<()>;
+
+    async fn delete_recovery_codes(&self, unit_of_work: &mut DynUnitOfWork, user_id: i64) -> anyhow::Result<()>;
+
+    /// Marks the user's recovery code as used, returning false if the code does not exist or was already used.
+    async fn use_recovery_code(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        code_hash: String,
+    ) -> anyhow::Result<bool>;
+
+    /// Marks all outstanding reset tokens for the user as used.
+    async fn invalidate_password_reset_tokens(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+    ) -> anyhow::Result<()>;
 }
 
 #[derive(FromRow)]
@@ -47,9 +165,41 @@ pub struct UserEntity {
     pub password: String,
     pub bio: String,
     pub image: String,
+    pub verified_at: Option<OffsetDateTime>,
+    pub role: String,
+    pub suspended_at: Option<OffsetDateTime>,
+    pub totp_secret: Option<String>,
+    pub totp_enabled_at: Option<OffsetDateTime>,
+    pub totp_last_used_step: Option<i64>,
+}
+
+#[derive(FromRow)]
+pub struct PasswordResetTokenEntity {
+    pub id: i64,
+    pub user_id: i64,
+    pub token_hash: String,
+    pub expires_at: OffsetDateTime,
+    pub used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(FromRow)]
+pub struct EmailVerificationTokenEntity {
+    pub id: i64,
+    pub user_id: i64,
+    pub email: String,
+    pub token_hash: String,
+    pub expires_at: OffsetDateTime,
+    pub used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
 }
 
 impl UserEntity {
+    pub fn role(&self) -> Role {
+        // roles are constrained in the database, so only fall back to the least privileged role defensively
+        Role::from_str(&self.role).unwrap_or_default()
+    }
+
     pub fn into_dto(self, token: String) -> UserDto {
         UserDto {
             id: self.id,
@@ -58,6 +208,21 @@ impl UserEntity {
             bio: Some(self.bio),
             image: Some(self.image),
             token,
+            verified: self.verified_at.is_some(),
+            two_factor_enabled: self.totp_enabled_at.is_some(),
+            refresh_token: None,
+        }
+    }
+
+    pub fn into_admin_dto(self) -> AdminUserDto {
+        AdminUserDto {
+            role: self.role().to_string(),
+            id: self.id,
+            username: self.username,
+            email: self.email,
+            verified: self.verified_at.is_some(),
+            suspended: self.suspended_at.is_some(),
+            created_at: self.created_at.lazy_format(Format::Rfc3339).to_string(),
         }
     }
 
@@ -82,6 +247,12 @@ impl Default for UserEntity {
             email: String::from("stub email"),
             password: String::from("hashed password"),
             image: String::from("stub image"),
+            verified_at: None,
+            role: String::from("user"),
+            suspended_at: None,
+            totp_secret: None,
+            totp_enabled_at: None,
+            totp_last_used_step: None,
         }
     }
 }
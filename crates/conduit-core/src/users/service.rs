@@ -4,9 +4,10 @@ use async_trait::async_trait;
 use mockall::automock;
 
 use conduit_domain::users::requests::{LoginUserDto, RegisterUserDto, UpdateUserDto};
-use conduit_domain::users::UserDto;
+use conduit_domain::users::{LoginResultDto, UserDto};
 
 use crate::errors::ConduitResult;
+use crate::users::roles::Role;
 
 /// A reference counter for our user service allows us safely pass instances user utils
 /// around which themselves depend on the user repostiory, and ultimately, our Posgres connection pool.
@@ -17,9 +18,29 @@ pub type DynUsersService = Arc<dyn UsersService + Send + Sync>;
 pub trait UsersService {
     async fn register_user(&self, request: RegisterUserDto) -> ConduitResult<UserDto>;
 
-    async fn login_user(&self, request: LoginUserDto) -> ConduitResult<UserDto>;
+    /// Verifies the user's password, issuing a challenge rather than an access token if two-factor is enabled. Failed
+    /// attempts are tracked against both the account and the client address, temporarily locking out either.
+    async fn login_user(&self, request: LoginUserDto, client_address: Option<String>) -> ConduitResult<LoginResultDto>;
 
     async fn get_current_user(&self, user_id: i64) -> ConduitResult<UserDto>;
 
     async fn updated_user(&self, user_id: i64, request: UpdateUserDto) -> ConduitResult<UserDto>;
+
+    /// Redeems a verification token, marking the address it was issued for as verified.
+    async fn verify_email(&self, token: String) -> ConduitResult<()>;
+
+    /// Mails a new verification token to the user's current address.
+    async fn resend_email_verification(&self, user_id: i64) -> ConduitResult<()>;
+
+    /// Ensures the user is allowed to author content, which requires a verified email when verification is enforced.
+    async fn ensure_verified(&self, user_id: i64) -> ConduitResult<()>;
+
+    /// Assigns a role to the user, taking effect the next time the user is issued an access token.
+    async fn update_user_role(&self, username: String, role: Role) -> ConduitResult<()>;
+
+    /// Mails a single use reset token to the user, succeeding regardless of the email belonging to a user.
+    async fn request_password_reset(&self, email: String) -> ConduitResult<()>;
+
+    /// Redeems a reset token, replacing the user's password and ending all of their existing sessions.
+    async fn confirm_password_reset(&self, token: String, password: String) -> ConduitResult<()>;
 }
@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::errors::ConduitResult;
+
+/// A mail service for delivering transactional emails, e.g. password resets.
+pub type DynMailService = Arc<dyn MailService + Send + Sync>;
+
+#[automock]
+#[async_trait]
+pub trait MailService {
+    async fn send_mail(&self, to: &str, subject: &str, body: &str) -> ConduitResult<()>;
+}
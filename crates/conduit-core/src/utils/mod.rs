@@ -1,2 +1,5 @@
+pub mod mail_service;
 pub mod security_service;
 pub mod token_service;
+pub mod totp_service;
+pub mod unit_of_work;
@@ -12,4 +12,8 @@ pub trait SecurityService {
     fn hash_password(&self, raw_password: &str) -> ConduitResult<String>;
 
     fn verify_password(&self, stored_password: &str, attempted_password: String) -> ConduitResult<bool>;
+
+    /// Determines if a stored hash was created with outdated parameters or the legacy global salt, and should be
+    /// replaced with a fresh hash the next time the raw password is available.
+    fn needs_rehash(&self, stored_password: &str) -> ConduitResult<bool>;
 }
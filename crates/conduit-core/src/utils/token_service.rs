@@ -2,13 +2,42 @@ use std::sync::Arc;
 
 use mockall::automock;
 
+use crate::access_tokens::scopes::Scope;
 use crate::errors::ConduitResult;
+use crate::users::roles::Role;
 
 /// A security service for handling JWT authentication.
 pub type DynTokenService = Arc<dyn TokenService + Send + Sync>;
 
 #[automock]
 pub trait TokenService {
-    fn new_token(&self, user_id: i64, email: &str) -> ConduitResult<String>;
-    fn get_user_id_from_token(&self, token: String) -> ConduitResult<i64>;
+    fn new_token(&self, user_id: i64, email: &str, role: Role) -> ConduitResult<String>;
+
+    fn get_claims_from_token(&self, token: String) -> ConduitResult<TokenClaims>;
+
+    /// Generates a random, opaque token for refresh tokens and the like - only its hash should ever be persisted.
+    fn new_opaque_token(&self) -> String;
+
+    fn hash_opaque_token(&self, token: &str) -> String;
+}
+
+/// The validated claims of an access token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenClaims {
+    pub user_id: i64,
+    pub token_id: String,
+    pub expires_at: i64,
+    pub role: Role,
+    /// The scopes of a personal access token, session tokens are not restricted to any scopes.
+    pub scopes: Option<Vec<Scope>>,
+}
+
+impl TokenClaims {
+    /// Determines if the token allows the scope, with every token allowing reads.
+    pub fn has_scope(&self, scope: Scope) -> bool {
+        match &self.scopes {
+            Some(scopes) => scope == Scope::Read || scopes.contains(&scope),
+            None => true,
+        }
+    }
 }
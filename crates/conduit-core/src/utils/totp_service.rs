@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use mockall::automock;
+
+use crate::errors::ConduitResult;
+
+/// A service for generating and verifying the time-based one-time passwords used for two-factor authentication.
+pub type DynTotpService = Arc<dyn TotpService + Send + Sync>;
+
+#[automock]
+pub trait TotpService {
+    /// Generates a new random, base32 encoded secret.
+    fn new_secret(&self) -> String;
+
+    /// Builds the `otpauth://` URI authenticator apps use to enroll the secret, typically rendered as a QR code.
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> String;
+
+    /// Verifies the code against the secret, tolerating a step of clock drift either way, and returns the time step
+    /// the code was issued for so that callers can reject codes that have already been used.
+    fn verify_code(&self, secret: &str, code: &str) -> ConduitResult<Option<i64>>;
+}
@@ -0,0 +1,28 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+/// A factory for opening units of work, allowing services to group several repository calls into a single transaction.
+pub type DynUnitOfWorkFactory = Arc<dyn UnitOfWorkFactory + Send + Sync>;
+
+/// The unit of work repositories accept, units of work opened by the factory are passed along with `as_mut()`.
+pub type DynUnitOfWork = dyn UnitOfWork;
+
+#[automock]
+#[async_trait]
+pub trait UnitOfWorkFactory {
+    async fn begin(&self) -> anyhow::Result<Box<dyn UnitOfWork>>;
+}
+
+/// An open transaction that repositories participate in when passed one. Dropping a unit of work
+/// without committing it rolls back any changes made within it, while the `Any` bound allows
+/// repository implementations to recover their concrete transaction type.
+#[automock]
+#[async_trait]
+pub trait UnitOfWork: Any + Send {
+    async fn commit(self: Box<Self>) -> anyhow::Result<()>;
+
+    async fn rollback(self: Box<Self>) -> anyhow::Result<()>;
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+pub mod requests;
+pub mod responses;
+
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct AccessTokenDto {
+    pub id: i64,
+    pub name: String,
+    pub scopes: Vec<String>,
+    /// The raw token, only included in the response to creating the token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: Option<String>,
+    #[serde(rename = "lastUsedAt")]
+    pub last_used_at: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+}
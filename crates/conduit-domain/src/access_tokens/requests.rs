@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct CreateAccessTokenRequest {
+    #[validate]
+    pub token: CreateAccessTokenDto,
+}
+
+#[derive(Serialize, Deserialize, Debug, Validate, Default)]
+pub struct CreateAccessTokenDto {
+    #[validate(required, length(min = 1, max = 100))]
+    pub name: Option<String>,
+    #[validate(required, length(min = 1))]
+    pub scopes: Option<Vec<String>>,
+    /// Tokens without an expiration remain valid until revoked.
+    #[serde(rename = "expiresInDays")]
+    #[validate(range(min = 1, max = 365))]
+    pub expires_in_days: Option<i64>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::access_tokens::AccessTokenDto;
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct AccessTokenResponse {
+    pub token: AccessTokenDto,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct AccessTokensResponse {
+    pub tokens: Vec<AccessTokenDto>,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+pub mod requests;
+pub mod responses;
+
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct AdminUserDto {
+    pub id: i64,
+    pub username: String,
+    pub email: String,
+    pub role: String,
+    pub verified: bool,
+    pub suspended: bool,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+}
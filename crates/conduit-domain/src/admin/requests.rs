@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::articles::requests::{LIMIT, OFFSET};
+
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct UpdateUserRoleRequest {
+    #[validate]
+    pub user: UpdateUserRoleDto,
+}
+
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct UpdateUserRoleDto {
+    #[validate(required, length(min = 1))]
+    pub role: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetUsersApiRequest {
+    pub q: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug)]
+pub struct GetUsersServiceRequest {
+    pub search: Option<String>,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl From<GetUsersApiRequest> for GetUsersServiceRequest {
+    fn from(request: GetUsersApiRequest) -> Self {
+        Self {
+            search: request.q.filter(|search| !search.is_empty()),
+            limit: request.limit.unwrap_or_else(|| LIMIT.abs()),
+            offset: request.offset.unwrap_or_else(|| OFFSET.abs()),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::admin::AdminUserDto;
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct AdminUsersResponse {
+    pub users: Vec<AdminUserDto>,
+    #[serde(rename = "usersCount")]
+    pub users_count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct AdminUserResponse {
+    pub user: AdminUserDto,
+}
@@ -1,14 +1,29 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::reactions::ReactionDto;
+
 #[derive(Debug, Clone, Deserialize, Serialize, Default, PartialEq)]
 pub struct ArticleDto {
     #[serde(skip_serializing, skip_deserializing)]
     pub id: i64,
     pub slug: String,
+    pub status: String,
+    #[serde(rename = "publishedAt")]
+    pub published_at: Option<String>,
+    #[serde(rename = "publishAt")]
+    pub publish_at: Option<String>,
     pub title: String,
     pub description: String,
     pub body: String,
+    /// The Markdown body rendered as sanitized HTML, present when retrieving a single article.
+    #[serde(rename = "bodyHtml", skip_serializing_if = "Option::is_none", default)]
+    pub body_html: Option<String>,
+    #[serde(rename = "wordCount")]
+    pub word_count: usize,
+    /// The estimated minutes it takes to read the body.
+    #[serde(rename = "readingTimeMinutes")]
+    pub reading_time_minutes: usize,
     #[serde(rename = "tagList")]
     pub tag_list: Vec<String>,
     #[serde(rename = "createdAt")]
@@ -18,7 +33,41 @@ pub struct ArticleDto {
     pub favorited: bool,
     #[serde(rename = "favoritesCount")]
     pub favorites_count: i64,
+    /// The reactions to the article, present when listing or retrieving articles.
+    #[serde(default)]
+    pub reactions: Vec<ReactionDto>,
+    /// The emojis the requesting user reacted to the article with.
+    #[serde(rename = "myReactions", default)]
+    pub my_reactions: Vec<String>,
+    /// The usernames of the users mentioned in the body, present when retrieving a single article.
+    #[serde(default)]
+    pub mentions: Vec<String>,
     pub author: AuthorDto,
+    /// An excerpt of the body around the terms of a search, with matches wrapped in `<mark>` tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct ArticleRevisionDto {
+    pub revision: i32,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    /// The username of the user that made the revision, if they still exist.
+    pub editor: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+}
+
+/// Unified diffs of each article field between two revisions, with unchanged fields left empty.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct ArticleRevisionDiffDto {
+    pub from: i32,
+    pub to: i32,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub body: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -39,11 +88,34 @@ pub struct CreateArticleDto {
     pub body: Option<String>,
     #[serde(rename = "tagList")]
     pub tag_list: Vec<String>,
+    /// One of draft, published or unlisted, publishing the article immediately when omitted.
+    pub status: Option<String>,
+    /// An RFC 3339 time in the future to publish the article at, keeping the article as a draft until then.
+    #[serde(rename = "publishAt")]
+    pub publish_at: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Default)]
 pub struct UpdateArticleDto {
     pub title: Option<String>,
     pub description: Option<String>,
     pub body: Option<String>,
+    /// Replaces the article's tags when present, leaving them untouched otherwise.
+    #[serde(rename = "tagList")]
+    pub tag_list: Option<Vec<String>>,
+    #[serde(rename = "publishAt")]
+    pub publish_at: Option<String>,
+}
+
+impl CreateArticleDto {
+    pub fn new_stub() -> Self {
+        Self {
+            title: Some(String::from("stub title")),
+            description: Some(String::from("stub description")),
+            body: Some(String::from("stub body")),
+            tag_list: vec![],
+            status: None,
+            publish_at: None,
+        }
+    }
 }
@@ -7,6 +7,7 @@ use crate::articles::models::{CreateArticleDto, UpdateArticleDto};
 lazy_static! {
     pub static ref LIMIT: i64 = 20;
     pub static ref OFFSET: i64 = 0;
+    pub static ref MAX_LIMIT: i64 = 100;
 }
 
 #[derive(Debug, Deserialize, Serialize, Validate)]
@@ -22,30 +23,44 @@ pub struct UpdateArticleRequest {
 
 #[derive(Debug, Deserialize)]
 pub struct GetArticlesApiRequest {
+    /// Searches the title, description and body of articles, ranking the results by relevance.
+    pub q: Option<String>,
     pub tag: Option<String>,
     pub author: Option<String>,
     pub favorited: Option<String>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
+    /// Continues the listing after the `nextCursor` of a previous page, taking precedence over the offset.
+    pub cursor: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Deserialize)]
+pub struct ArticleRevisionDiffQuery {
+    pub from: Option<i32>,
+}
+
+#[derive(Debug, Default, Clone)]
 pub struct GetArticlesServiceRequest {
+    pub q: Option<String>,
     pub tag: Option<String>,
     pub author: Option<String>,
     pub favorited: Option<String>,
     pub limit: i64,
     pub offset: i64,
+    pub cursor: Option<String>,
 }
 
 impl From<GetArticlesApiRequest> for GetArticlesServiceRequest {
     fn from(request: GetArticlesApiRequest) -> Self {
         Self {
+            q: request.q.filter(|q| !q.trim().is_empty()),
             tag: request.tag,
             author: request.author,
             favorited: request.favorited,
-            limit: request.limit.unwrap_or_else(|| LIMIT.abs()),
-            offset: request.offset.unwrap_or_else(|| OFFSET.abs()),
+            // page sizes are capped so a single request cannot pull the entire table
+            limit: request.limit.unwrap_or_else(|| LIMIT.abs()).clamp(1, *MAX_LIMIT),
+            offset: request.offset.unwrap_or_else(|| OFFSET.abs()).max(0),
+            cursor: request.cursor.filter(|cursor| !cursor.is_empty()),
         }
     }
 }
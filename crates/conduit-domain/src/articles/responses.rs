@@ -1,15 +1,39 @@
 use serde::{Deserialize, Serialize};
 
-use crate::articles::models::ArticleDto;
+use crate::articles::models::{ArticleDto, ArticleRevisionDiffDto, ArticleRevisionDto};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct ArticlesResponse {
     pub articles: Vec<ArticleDto>,
+    /// The total number of articles matching the request, across every page.
     #[serde(rename = "articlesCount")]
     pub articles_count: usize,
+    /// Retrieves the next page when passed as the `cursor`, present only while more articles remain.
+    #[serde(rename = "nextCursor", skip_serializing_if = "Option::is_none", default)]
+    pub next_cursor: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct ArticleResponse {
     pub article: ArticleDto,
+    /// The article's current slug, present when the article was requested by a slug it has since been renamed from.
+    #[serde(rename = "redirectTo", skip_serializing_if = "Option::is_none", default)]
+    pub redirect_to: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct ArticleRevisionsResponse {
+    pub revisions: Vec<ArticleRevisionDto>,
+    #[serde(rename = "revisionsCount")]
+    pub revisions_count: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct ArticleRevisionResponse {
+    pub revision: ArticleRevisionDto,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct ArticleRevisionDiffResponse {
+    pub diff: ArticleRevisionDiffDto,
 }
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use validator::Validate;
 
 use crate::articles::models::AuthorDto;
+use crate::reactions::ReactionDto;
 
 pub mod requests;
 pub mod responses;
@@ -14,11 +15,50 @@ pub struct CommentDto {
     #[serde(rename = "updatedAt")]
     pub updated_at: String,
     pub body: String,
+    /// The Markdown body rendered as sanitized HTML, present when listing an article's comments.
+    #[serde(rename = "bodyHtml", skip_serializing_if = "Option::is_none", default)]
+    pub body_html: Option<String>,
     pub author: AuthorDto,
+    /// The comment replied to, absent for comments made directly on the article.
+    #[serde(rename = "parentId", skip_serializing_if = "Option::is_none", default)]
+    pub parent_id: Option<i64>,
+    /// Whether the comment was deleted, leaving a placeholder in place of its body and author so its replies remain.
+    #[serde(default)]
+    pub deleted: bool,
+    /// Whether the comment was edited after it was posted.
+    #[serde(default)]
+    pub edited: bool,
+    #[serde(default)]
+    pub reactions: Vec<ReactionDto>,
+    /// The emojis the requesting user reacted to the comment with.
+    #[serde(rename = "myReactions", default)]
+    pub my_reactions: Vec<String>,
+    /// The usernames of the users mentioned in the body.
+    #[serde(default)]
+    pub mentions: Vec<String>,
+    #[serde(default)]
+    pub replies: Vec<CommentDto>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Default, Validate)]
 pub struct CreateCommentDto {
     #[validate(required)]
     pub body: Option<String>,
+    #[serde(rename = "parentId")]
+    pub parent_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Validate)]
+pub struct UpdateCommentDto {
+    #[validate(required)]
+    pub body: Option<String>,
+}
+
+/// A previous version of an edited comment.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct CommentRevisionDto {
+    pub body: String,
+    /// The time the version was written, either when the comment was posted or when it was last edited.
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
 }
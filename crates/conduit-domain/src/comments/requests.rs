@@ -1,10 +1,64 @@
 use serde::Deserialize;
 use validator::Validate;
 
-use crate::comments::CreateCommentDto;
+use crate::articles::requests::{LIMIT, MAX_LIMIT};
+use crate::comments::{CreateCommentDto, UpdateCommentDto};
 
 #[derive(Debug, Deserialize, Validate)]
 pub struct CreateCommentRequest {
     #[validate]
     pub comment: CreateCommentDto,
 }
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateCommentRequest {
+    #[validate]
+    pub comment: UpdateCommentDto,
+}
+
+/// The order top-level comments are listed in, replies are always listed oldest first beneath their comment.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum CommentSort {
+    #[default]
+    Oldest,
+    Newest,
+    /// The comments with the most reactions first, then oldest first.
+    MostReacted,
+}
+
+impl CommentSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommentSort::Oldest => "oldest",
+            CommentSort::Newest => "newest",
+            CommentSort::MostReacted => "most-reacted",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetCommentsApiRequest {
+    pub limit: Option<i64>,
+    /// Continues the listing after the `nextCursor` of a previous page.
+    pub cursor: Option<String>,
+    pub sort: Option<CommentSort>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetCommentsServiceRequest {
+    /// The number of top-level comments in the page, each listed along with all of its replies.
+    pub limit: i64,
+    pub cursor: Option<String>,
+    pub sort: CommentSort,
+}
+
+impl From<GetCommentsApiRequest> for GetCommentsServiceRequest {
+    fn from(request: GetCommentsApiRequest) -> Self {
+        Self {
+            limit: request.limit.unwrap_or_else(|| LIMIT.abs()).clamp(1, *MAX_LIMIT),
+            cursor: request.cursor.filter(|cursor| !cursor.is_empty()),
+            sort: request.sort.unwrap_or_default(),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::comments::CommentDto;
+use crate::comments::{CommentDto, CommentRevisionDto};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct CommentResponse {
@@ -10,4 +10,17 @@ pub struct CommentResponse {
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct CommentsResponse {
     pub comments: Vec<CommentDto>,
+    /// The total number of comments on the article, including replies, across every page.
+    #[serde(rename = "commentsCount", default)]
+    pub comments_count: usize,
+    /// Retrieves the next page when passed as the `cursor`, present only while more comments remain.
+    #[serde(rename = "nextCursor", skip_serializing_if = "Option::is_none", default)]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct CommentRevisionsResponse {
+    pub revisions: Vec<CommentRevisionDto>,
+    #[serde(rename = "revisionsCount")]
+    pub revisions_count: usize,
 }
@@ -2,10 +2,16 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+pub mod access_tokens;
+pub mod admin;
 pub mod articles;
 pub mod comments;
+pub mod notifications;
 pub mod profiles;
+pub mod reactions;
 pub mod tags;
+pub mod trash;
+pub mod two_factor;
 pub mod users;
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+pub mod requests;
+pub mod responses;
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct NotificationDto {
+    pub id: i64,
+    /// What the notification is about, currently always `mention`.
+    pub kind: String,
+    /// The username of the user whose article or comment the notification is for.
+    pub actor: String,
+    #[serde(rename = "articleSlug")]
+    pub article_slug: String,
+    #[serde(rename = "articleTitle")]
+    pub article_title: String,
+    /// The comment the user was mentioned in, absent for mentions in the article itself.
+    #[serde(rename = "commentId", skip_serializing_if = "Option::is_none", default)]
+    pub comment_id: Option<i64>,
+    pub read: bool,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+use crate::articles::requests::{LIMIT, MAX_LIMIT, OFFSET};
+
+#[derive(Debug, Deserialize)]
+pub struct GetNotificationsApiRequest {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GetNotificationsServiceRequest {
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl From<GetNotificationsApiRequest> for GetNotificationsServiceRequest {
+    fn from(request: GetNotificationsApiRequest) -> Self {
+        Self {
+            limit: request.limit.unwrap_or_else(|| LIMIT.abs()).clamp(1, *MAX_LIMIT),
+            offset: request.offset.unwrap_or_else(|| OFFSET.abs()).max(0),
+        }
+    }
+}
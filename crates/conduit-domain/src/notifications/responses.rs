@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::notifications::NotificationDto;
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct NotificationsResponse {
+    /// The user's notifications, newest first.
+    pub notifications: Vec<NotificationDto>,
+    #[serde(rename = "unreadCount")]
+    pub unread_count: i64,
+}
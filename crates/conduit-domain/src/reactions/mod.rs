@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+pub mod requests;
+pub mod responses;
+
+/// The number of users that reacted to an article or comment with the emoji.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct ReactionDto {
+    pub emoji: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, Validate)]
+pub struct ToggleReactionDto {
+    #[validate(required)]
+    pub emoji: Option<String>,
+}
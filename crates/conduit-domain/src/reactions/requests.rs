@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::reactions::ToggleReactionDto;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ToggleReactionRequest {
+    #[validate]
+    pub reaction: ToggleReactionDto,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::reactions::ReactionDto;
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct ReactionsResponse {
+    pub reactions: Vec<ReactionDto>,
+    /// The emojis the requesting user reacted with.
+    #[serde(rename = "myReactions")]
+    pub my_reactions: Vec<String>,
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+pub mod responses;
+
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct TrashedArticleDto {
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    #[serde(rename = "deletedAt")]
+    pub deleted_at: String,
+    /// The time the article is permanently removed, after which it can no longer be restored.
+    #[serde(rename = "purgeAt")]
+    pub purge_at: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct TrashedCommentDto {
+    pub id: i64,
+    pub body: String,
+    #[serde(rename = "articleSlug")]
+    pub article_slug: String,
+    #[serde(rename = "deletedAt")]
+    pub deleted_at: String,
+    /// The time the comment is permanently removed, after which it can no longer be restored.
+    #[serde(rename = "purgeAt")]
+    pub purge_at: String,
+}
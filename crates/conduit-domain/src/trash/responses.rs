@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::trash::{TrashedArticleDto, TrashedCommentDto};
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct TrashResponse {
+    pub articles: Vec<TrashedArticleDto>,
+    pub comments: Vec<TrashedCommentDto>,
+}
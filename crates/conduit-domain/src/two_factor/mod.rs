@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+pub mod requests;
+pub mod responses;
+
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct TwoFactorEnrollmentDto {
+    pub secret: String,
+    #[serde(rename = "provisioningUri")]
+    pub provisioning_uri: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct TwoFactorChallengeDto {
+    #[serde(rename = "challengeToken")]
+    pub challenge_token: String,
+    /// The number of seconds remaining to complete the challenge.
+    #[serde(rename = "expiresIn")]
+    pub expires_in: u64,
+}
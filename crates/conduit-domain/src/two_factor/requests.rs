@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct TwoFactorCodeRequest {
+    #[serde(rename = "twoFactor")]
+    #[validate]
+    pub two_factor: TwoFactorCodeDto,
+}
+
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct TwoFactorCodeDto {
+    #[validate(required, length(min = 1))]
+    pub code: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct TwoFactorLoginRequest {
+    #[serde(rename = "twoFactor")]
+    #[validate]
+    pub two_factor: TwoFactorLoginDto,
+}
+
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct TwoFactorLoginDto {
+    #[serde(rename = "challengeToken")]
+    #[validate(required, length(min = 1))]
+    pub challenge_token: Option<String>,
+    #[validate(required, length(min = 1))]
+    pub code: Option<String>,
+}
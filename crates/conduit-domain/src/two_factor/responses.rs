@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::two_factor::{TwoFactorChallengeDto, TwoFactorEnrollmentDto};
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct TwoFactorEnrollmentResponse {
+    #[serde(rename = "twoFactor")]
+    pub two_factor: TwoFactorEnrollmentDto,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct TwoFactorChallengeResponse {
+    #[serde(rename = "twoFactor")]
+    pub two_factor: TwoFactorChallengeDto,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct RecoveryCodesResponse {
+    #[serde(rename = "recoveryCodes")]
+    pub recovery_codes: Vec<String>,
+}
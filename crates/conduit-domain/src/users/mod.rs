@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::two_factor::TwoFactorChallengeDto;
+
 pub mod requests;
 pub mod responses;
 
@@ -12,4 +14,17 @@ pub struct UserDto {
     pub bio: Option<String>,
     pub image: Option<String>,
     pub token: String,
+    #[serde(default)]
+    pub verified: bool,
+    #[serde(rename = "twoFactorEnabled", default)]
+    pub two_factor_enabled: bool,
+    #[serde(rename = "refreshToken", skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+}
+
+/// The result of checking a user's password, which only authenticates users without two-factor authentication.
+#[derive(Debug)]
+pub enum LoginResultDto {
+    Authenticated(UserDto),
+    TwoFactorRequired(TwoFactorChallengeDto),
 }
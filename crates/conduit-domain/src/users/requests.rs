@@ -18,6 +18,29 @@ pub struct UpdateUserRequest {
     pub user: UpdateUserDto,
 }
 
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct RefreshTokenRequest {
+    #[validate]
+    pub user: RefreshTokenDto,
+}
+
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct PasswordResetRequest {
+    #[validate]
+    pub user: PasswordResetDto,
+}
+
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct ConfirmPasswordResetRequest {
+    #[validate]
+    pub user: ConfirmPasswordResetDto,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VerifyEmailQuery {
+    pub token: String,
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug, Validate, Default)]
 pub struct RegisterUserDto {
     #[validate(required, length(min = 1))]
@@ -45,6 +68,27 @@ pub struct UpdateUserDto {
     pub image: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct RefreshTokenDto {
+    #[serde(rename = "refreshToken")]
+    #[validate(required, length(min = 1))]
+    pub refresh_token: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct PasswordResetDto {
+    #[validate(required, length(min = 1), email(message = "email is invalid"))]
+    pub email: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Validate)]
+pub struct ConfirmPasswordResetDto {
+    #[validate(required, length(min = 1))]
+    pub token: Option<String>,
+    #[validate(required, length(min = 1))]
+    pub password: Option<String>,
+}
+
 impl RegisterUserDto {
     pub fn new_stub() -> Self {
         Self {
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::two_factor::responses::TwoFactorChallengeResponse;
 use crate::users::UserDto;
 
 #[derive(Serialize, Deserialize, Default, Debug)]
@@ -27,7 +28,18 @@ impl UserAuthenicationResponse {
                 bio,
                 image,
                 token,
+                verified: false,
+                two_factor_enabled: false,
+                refresh_token: None,
             },
         }
     }
 }
+
+/// Logging in responds with the authenticated user, or the challenge to complete for users with two-factor enabled.
+#[derive(Serialize, Debug)]
+#[serde(untagged)]
+pub enum LoginUserResponse {
+    Authenticated(UserAuthenicationResponse),
+    TwoFactorRequired(TwoFactorChallengeResponse),
+}
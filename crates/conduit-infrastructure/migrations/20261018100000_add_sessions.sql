@@ -0,0 +1,32 @@
+-- refresh tokens are rotated on every use, with each rotation belonging to the family of its originating login
+create table if not exists refresh_tokens
+(
+    id         bigint generated by default as identity,
+    user_id    bigint      not null references users (id) on delete cascade,
+    token_hash varchar     not null,
+    family     varchar     not null,
+    expires_at timestamptz not null,
+    revoked_at timestamptz,
+    created_at timestamptz not null default current_timestamp
+);
+
+alter table refresh_tokens
+    add constraint refresh_tokens_id_pk primary key (id);
+
+create unique index if not exists refresh_tokens_token_hash_idx on refresh_tokens (token_hash);
+
+create index if not exists refresh_tokens_family_idx on refresh_tokens (family);
+
+-- access tokens revoked before their expiration, e.g. on logout
+create table if not exists revoked_access_tokens
+(
+    id         bigint generated by default as identity,
+    token_id   varchar     not null,
+    expires_at timestamptz not null,
+    created_at timestamptz not null default current_timestamp
+);
+
+alter table revoked_access_tokens
+    add constraint revoked_access_tokens_id_pk primary key (id);
+
+create index if not exists revoked_access_tokens_token_id_idx on revoked_access_tokens (token_id);
@@ -0,0 +1,17 @@
+-- reset tokens are single use, and are invalidated whenever a newer token is requested for the same user
+create table if not exists password_reset_tokens
+(
+    id         bigint generated by default as identity,
+    user_id    bigint      not null references users (id) on delete cascade,
+    token_hash varchar     not null,
+    expires_at timestamptz not null,
+    used_at    timestamptz,
+    created_at timestamptz not null default current_timestamp
+);
+
+alter table password_reset_tokens
+    add constraint password_reset_tokens_id_pk primary key (id);
+
+create unique index if not exists password_reset_tokens_token_hash_idx on password_reset_tokens (token_hash);
+
+create index if not exists password_reset_tokens_user_id_idx on password_reset_tokens (user_id);
@@ -0,0 +1,24 @@
+alter table users
+    add column if not exists verified_at timestamptz;
+
+-- accounts created before verification was introduced have no way to have verified, so consider them verified
+update users
+set verified_at = created_at
+where verified_at is null;
+
+-- tokens are issued for a specific address, so changing the email again will leave outstanding tokens unredeemable
+create table if not exists email_verification_tokens
+(
+    id         bigint generated by default as identity,
+    user_id    bigint      not null references users (id) on delete cascade,
+    email      varchar     not null,
+    token_hash varchar     not null,
+    expires_at timestamptz not null,
+    used_at    timestamptz,
+    created_at timestamptz not null default current_timestamp
+);
+
+alter table email_verification_tokens
+    add constraint email_verification_tokens_id_pk primary key (id);
+
+create unique index if not exists email_verification_tokens_token_hash_idx on email_verification_tokens (token_hash);
@@ -0,0 +1,5 @@
+alter table users
+    add column if not exists role varchar not null default 'user';
+
+alter table users
+    add constraint users_role_check check (role in ('user', 'moderator', 'admin'));
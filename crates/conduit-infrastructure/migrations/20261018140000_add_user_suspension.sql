@@ -0,0 +1,3 @@
+-- suspended users are unable to authenticate, and are hidden from profiles and article listings
+alter table users
+    add column if not exists suspended_at timestamptz;
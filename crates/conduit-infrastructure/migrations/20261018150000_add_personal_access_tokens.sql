@@ -0,0 +1,19 @@
+-- long-lived tokens for scripts and integrations, limited to the scopes chosen when the token was created
+create table if not exists personal_access_tokens
+(
+    id           bigint generated by default as identity,
+    user_id      bigint      not null references users (id) on delete cascade,
+    name         varchar     not null,
+    token_hash   varchar     not null,
+    scopes       varchar[]   not null,
+    expires_at   timestamptz,
+    last_used_at timestamptz,
+    created_at   timestamptz not null default current_timestamp
+);
+
+alter table personal_access_tokens
+    add constraint personal_access_tokens_id_pk primary key (id);
+
+create unique index if not exists personal_access_tokens_token_hash_idx on personal_access_tokens (token_hash);
+
+create index if not exists personal_access_tokens_user_id_idx on personal_access_tokens (user_id);
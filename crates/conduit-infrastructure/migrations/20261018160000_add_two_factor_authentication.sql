@@ -0,0 +1,36 @@
+-- the TOTP secret is set on enrollment, but only enforced on login once the user confirms a first code
+alter table users
+    add column if not exists totp_secret         varchar,
+    add column if not exists totp_enabled_at     timestamptz,
+    add column if not exists totp_last_used_step bigint;
+
+create table if not exists recovery_codes
+(
+    id         bigint generated by default as identity,
+    user_id    bigint      not null references users (id) on delete cascade,
+    code_hash  varchar     not null,
+    used_at    timestamptz,
+    created_at timestamptz not null default current_timestamp
+);
+
+alter table recovery_codes
+    add constraint recovery_codes_id_pk primary key (id);
+
+create index if not exists recovery_codes_user_id_idx on recovery_codes (user_id);
+
+-- issued after a successful password check for users with two-factor authentication enabled
+create table if not exists login_challenges
+(
+    id         bigint generated by default as identity,
+    user_id    bigint      not null references users (id) on delete cascade,
+    token_hash varchar     not null,
+    attempts   integer     not null default 0,
+    expires_at timestamptz not null,
+    used_at    timestamptz,
+    created_at timestamptz not null default current_timestamp
+);
+
+alter table login_challenges
+    add constraint login_challenges_id_pk primary key (id);
+
+create unique index if not exists login_challenges_token_hash_idx on login_challenges (token_hash);
@@ -0,0 +1,11 @@
+-- failed logins are tracked per attempted account and per client address, keyed e.g. 'account:<email>' or 'ip:<address>'
+create table if not exists login_throttles
+(
+    key             varchar     not null,
+    failures        integer     not null default 0,
+    last_failure_at timestamptz not null default current_timestamp,
+    locked_until    timestamptz
+);
+
+alter table login_throttles
+    add constraint login_throttles_key_pk primary key (key);
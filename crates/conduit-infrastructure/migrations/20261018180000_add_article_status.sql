@@ -0,0 +1,16 @@
+-- drafts are only visible to their author, while unlisted articles are reachable by slug but hidden from listings
+alter table articles
+    add column if not exists status varchar not null default 'published';
+
+alter table articles
+    add constraint articles_status_check check (status in ('draft', 'published', 'unlisted'));
+
+alter table articles
+    add column if not exists published_at timestamptz;
+
+-- existing articles were published as soon as they were created
+update articles
+set published_at = created_at
+where published_at is null;
+
+create index if not exists articles_status_published_at_idx on articles (status, published_at desc);
@@ -0,0 +1,5 @@
+-- scheduled articles remain unpublished until their publish time has passed, when the scheduler publishes them
+alter table articles
+    add column if not exists publish_at timestamptz;
+
+create index if not exists articles_publish_at_idx on articles (publish_at) where publish_at is not null;
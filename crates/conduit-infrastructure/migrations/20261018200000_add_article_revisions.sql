@@ -0,0 +1,23 @@
+-- every version of an article is kept as an immutable revision, numbered from one for each article
+create table if not exists article_revisions
+(
+    id          bigint generated by default as identity,
+    article_id  bigint      not null references articles (id) on delete cascade,
+    revision    integer     not null,
+    title       varchar     not null,
+    description varchar     not null,
+    body        varchar     not null,
+    editor_id   bigint      references users (id) on delete set null,
+    created_at  timestamptz not null default current_timestamp
+);
+
+alter table article_revisions
+    add constraint article_revisions_id_pk primary key (id);
+
+alter table article_revisions
+    add constraint article_revisions_article_id_revision_key unique (article_id, revision);
+
+-- existing articles start their history from their current content
+insert into article_revisions (article_id, revision, title, description, body, editor_id, created_at)
+select id, 1, title, description, body, user_id, updated_at
+from articles;
@@ -0,0 +1,12 @@
+-- deleted articles and comments are kept in the trash until the scheduler purges them after the retention window
+alter table articles
+    add column if not exists deleted_at timestamptz,
+    add column if not exists deleted_by bigint references users (id) on delete set null;
+
+alter table comments
+    add column if not exists deleted_at timestamptz,
+    add column if not exists deleted_by bigint references users (id) on delete set null;
+
+create index if not exists articles_deleted_at_idx on articles (deleted_at) where deleted_at is not null;
+
+create index if not exists comments_deleted_at_idx on comments (deleted_at) where deleted_at is not null;
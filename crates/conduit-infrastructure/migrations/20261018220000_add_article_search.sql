@@ -0,0 +1,9 @@
+-- articles are searchable by a weighted document over their content, favoring matches in the title over the body
+alter table articles
+    add column if not exists search_vector tsvector generated always as (
+                setweight(to_tsvector('english', title), 'A') ||
+                setweight(to_tsvector('english', description), 'B') ||
+                setweight(to_tsvector('english', body), 'C')
+        ) stored;
+
+create index if not exists articles_search_vector_idx on articles using gin (search_vector);
@@ -0,0 +1,3 @@
+-- article listings page through published articles by creation time, using the id to break ties
+create index if not exists articles_listing_idx on articles (created_at desc, id desc)
+    where deleted_at is null and status = 'published';
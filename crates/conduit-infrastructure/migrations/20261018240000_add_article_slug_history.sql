@@ -0,0 +1,16 @@
+-- renamed articles keep their former slugs, so links shared before the rename continue to resolve
+create table if not exists article_slug_history
+(
+    id         bigint generated by default as identity,
+    article_id bigint      not null references articles (id) on delete cascade,
+    slug       varchar     not null,
+    created_at timestamptz not null default current_timestamp
+);
+
+alter table article_slug_history
+    add constraint article_slug_history_id_pk primary key (id);
+
+alter table article_slug_history
+    add constraint article_slug_history_slug_key unique (slug);
+
+create index if not exists article_slug_history_article_id_idx on article_slug_history (article_id);
@@ -0,0 +1,5 @@
+-- comments may reply to another comment on the same article, deleted comments remain as placeholders for their replies
+alter table comments
+    add column if not exists parent_id bigint references comments (id) on delete set null;
+
+create index if not exists comments_parent_id_idx on comments (parent_id) where parent_id is not null;
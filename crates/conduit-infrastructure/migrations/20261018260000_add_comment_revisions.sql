@@ -0,0 +1,13 @@
+-- edited comments keep each previous version of their body, visible to moderators
+create table if not exists comment_revisions
+(
+    id         bigint generated by default as identity,
+    comment_id bigint      not null references comments (id) on delete cascade,
+    body       varchar     not null,
+    created_at timestamptz not null
+);
+
+alter table comment_revisions
+    add constraint comment_revisions_id_pk primary key (id);
+
+create index if not exists comment_revisions_comment_id_idx on comment_revisions (comment_id);
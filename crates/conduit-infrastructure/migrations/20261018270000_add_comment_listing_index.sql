@@ -0,0 +1,2 @@
+-- backs paging through an article's comments in creation order
+create index if not exists comments_article_id_created_at_idx on comments (article_id, created_at);
@@ -0,0 +1,26 @@
+-- users react to articles and comments with emojis from a fixed set, at most once per emoji on each
+create table if not exists reactions
+(
+    id         bigint generated by default as identity,
+    user_id    bigint      not null references users (id) on delete cascade,
+    article_id bigint      references articles (id) on delete cascade,
+    comment_id bigint      references comments (id) on delete cascade,
+    emoji      varchar     not null,
+    created_at timestamptz not null default current_timestamp
+);
+
+alter table reactions
+    add constraint reactions_id_pk primary key (id);
+
+alter table reactions
+    add constraint reactions_target_check check ((article_id is null) <> (comment_id is null));
+
+create unique index if not exists reactions_user_id_article_id_emoji_key on reactions (user_id, article_id, emoji)
+    where article_id is not null;
+
+create unique index if not exists reactions_user_id_comment_id_emoji_key on reactions (user_id, comment_id, emoji)
+    where comment_id is not null;
+
+create index if not exists reactions_article_id_idx on reactions (article_id) where article_id is not null;
+
+create index if not exists reactions_comment_id_idx on reactions (comment_id) where comment_id is not null;
@@ -0,0 +1,36 @@
+-- users mentioned in an article's body, or in the body of one of its comments
+create table if not exists mentions
+(
+    id         bigint generated by default as identity,
+    user_id    bigint      not null references users (id) on delete cascade,
+    article_id bigint      not null references articles (id) on delete cascade,
+    comment_id bigint      references comments (id) on delete cascade,
+    created_at timestamptz not null default current_timestamp
+);
+
+alter table mentions
+    add constraint mentions_id_pk primary key (id);
+
+create unique index if not exists mentions_article_id_user_id_key on mentions (article_id, user_id)
+    where comment_id is null;
+
+create unique index if not exists mentions_comment_id_user_id_key on mentions (comment_id, user_id)
+    where comment_id is not null;
+
+-- notifications let users know where they were mentioned, and are kept until their article or comment is removed
+create table if not exists notifications
+(
+    id         bigint generated by default as identity,
+    user_id    bigint      not null references users (id) on delete cascade,
+    actor_id   bigint      not null references users (id) on delete cascade,
+    kind       varchar     not null,
+    article_id bigint      not null references articles (id) on delete cascade,
+    comment_id bigint      references comments (id) on delete cascade,
+    read_at    timestamptz,
+    created_at timestamptz not null default current_timestamp
+);
+
+alter table notifications
+    add constraint notifications_id_pk primary key (id);
+
+create index if not exists notifications_user_id_created_at_idx on notifications (user_id, created_at desc);
@@ -5,6 +5,9 @@ select a.id                                                          as "id!",
        a.body                                                        as "body!",
        a.description                                                 as "description!",
        a.slug                                                        as "slug!",
+       a.status                                                      as "status!",
+       a.published_at                                                as "published_at",
+       a.publish_at                                                  as "publish_at",
        u.id                                                          as "user_id!",
        exists(
                select 1 from user_favorites af where af.user_id = $1::bigint and af.article_id = a.id
@@ -16,7 +19,10 @@ select a.id                                                          as "id!",
                 and follower_id = $1::bigint)                           "following_author!",
        u.username                                                    as "author_username!",
        u.bio                                                         as "author_bio!",
-       u.image                                                       as "author_image!"
+       u.image                                                       as "author_image!",
+       null::varchar                                                 as "snippet?"
 from articles a
          join users u on u.id = a.user_id
-where a.slug = $2::varchar;
+where a.slug = $2::varchar
+  and a.deleted_at is null
+  and (a.status <> 'draft' or a.user_id = $1::bigint);
@@ -5,6 +5,9 @@ select a.id
        a.body                                                                                         as "body!",
        a.description                                                                                  as "description!",
        a.slug                                                                                         as "slug!",
+       a.status                                                                                       as "status!",
+       a.published_at                                                                                 as "published_at",
+       a.publish_at                                                                                   as "publish_at",
        u.id                                                                                           as "user_id!",
        exists(select 1 from user_favorites af where af.user_id = $1::bigint and af.article_id = a.id) as "favorited!",
        (select count(*) from user_favorites where article_id = a.id)                                  as "favorites!",
@@ -14,10 +17,16 @@ select a.id
                 and follower_id = $1::bigint)                                                            "following_author!",
        u.username                                                                                     as "author_username!",
        u.bio                                                                                          as "author_bio!",
-       u.image                                                                                        as "author_image!"
+       u.image                                                                                        as "author_image!",
+       ts_headline('english', a.body, search_query, 'StartSel=<mark>, StopSel=</mark>, MaxFragments=2') as "snippet?"
 from articles a
          join users u on u.id = a.user_id
-where ($2::varchar is null or $2::varchar = u.username)
+         cross join websearch_to_tsquery('english', $7::varchar) search_query
+where u.suspended_at is null
+  and a.deleted_at is null
+  and a.status = 'published'
+  and (a.publish_at is null or a.publish_at <= current_timestamp)
+  and ($2::varchar is null or $2::varchar = u.username)
   and ($3::varchar is null or exists(
         select 1
         from tags t
@@ -28,7 +37,12 @@ where ($2::varchar is null or $2::varchar = u.username)
         select 1
         from users favoriting_user
                  join user_favorites f on favoriting_user.id = f.user_id
-        where favoriting_user.username = $4::varchar)
+        where favoriting_user.username = $4::varchar
+          and f.article_id = a.id)
     )
-order by a.created_at desc
+  and ($7::varchar is null or a.search_vector @@ search_query)
+  and ($8::timestamptz is null or (a.created_at, a.id) < ($8::timestamptz, $9::bigint))
+order by ts_rank(a.search_vector, search_query) desc nulls last,
+         a.created_at desc,
+         a.id desc
 limit $5::integer offset $6::integer;
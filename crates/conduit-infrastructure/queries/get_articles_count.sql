@@ -0,0 +1,22 @@
+select count(*) as "count!"
+from articles a
+         join users u on u.id = a.user_id
+where u.suspended_at is null
+  and a.deleted_at is null
+  and a.status = 'published'
+  and (a.publish_at is null or a.publish_at <= current_timestamp)
+  and ($1::varchar is null or $1::varchar = u.username)
+  and ($2::varchar is null or exists(
+        select 1
+        from tags t
+                 join article_tags at on (t.id, a.id) = (at.tag_id, at.article_id)
+        where tag = $2::varchar
+    ))
+  and ($3::varchar is null or exists(
+        select 1
+        from users favoriting_user
+                 join user_favorites f on favoriting_user.id = f.user_id
+        where favoriting_user.username = $3::varchar
+          and f.article_id = a.id)
+    )
+  and ($4::varchar is null or a.search_vector @@ websearch_to_tsquery('english', $4::varchar));
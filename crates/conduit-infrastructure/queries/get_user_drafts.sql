@@ -0,0 +1,27 @@
+select a.id                                                                                           as "id!",
+       a.created_at                                                                                   as "created_at!",
+       a.updated_at                                                                                   as "updated_at!",
+       a.title                                                                                        as "title!",
+       a.body                                                                                         as "body!",
+       a.description                                                                                  as "description!",
+       a.slug                                                                                         as "slug!",
+       a.status                                                                                       as "status!",
+       a.published_at                                                                                 as "published_at",
+       a.publish_at                                                                                   as "publish_at",
+       u.id                                                                                           as "user_id!",
+       exists(select 1 from user_favorites af where af.user_id = $1::bigint and af.article_id = a.id) as "favorited!",
+       (select count(*) from user_favorites where article_id = a.id)                                  as "favorites!",
+       false                                                                                          as "following_author!",
+       u.username                                                                                     as "author_username!",
+       u.bio                                                                                          as "author_bio!",
+       u.image                                                                                        as "author_image!",
+       null::varchar                                                                                  as "snippet?"
+from articles a
+         join users u on u.id = a.user_id
+where a.user_id = $1::bigint
+  and a.status = 'draft'
+  and a.deleted_at is null
+  and ($4::timestamptz is null or (a.updated_at, a.id) < ($4::timestamptz, $5::bigint))
+order by a.updated_at desc,
+         a.id desc
+limit $2::integer offset $3::integer;
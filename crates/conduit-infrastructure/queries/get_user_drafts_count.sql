@@ -0,0 +1,5 @@
+select count(*) as "count!"
+from articles a
+where a.user_id = $1::bigint
+  and a.status = 'draft'
+  and a.deleted_at is null;
@@ -1,6 +1,8 @@
 with inserted_article_cte as (
-    insert into articles (created_at, updated_at, title, body, slug, description, user_id)
-        values (current_timestamp, current_timestamp, $1::varchar, $2::varchar, $3::varchar, $4::varchar, $5::bigint)
+    insert into articles (created_at, updated_at, title, body, slug, description, user_id, status, published_at,
+                          publish_at)
+        values (current_timestamp, current_timestamp, $1::varchar, $2::varchar, $3::varchar, $4::varchar, $5::bigint,
+                $6::varchar, case when $6::varchar = 'published' then current_timestamp end, $7::timestamptz)
         returning id as "id",
             created_at as "created_at",
             updated_at as "updated_at",
@@ -8,6 +10,9 @@ with inserted_article_cte as (
             body as "body",
             slug as "slug",
             description as "description",
+            status as "status",
+            published_at as "published_at",
+            publish_at as "publish_at",
             user_id as "user_id")
 select a.id          as "id!",
        a.created_at  as "created_at!",
@@ -16,6 +21,9 @@ select a.id          as "id!",
        a.body        as "body!",
        a.slug        as "slug!",
        a.description as "description!",
+       a.status      as "status!",
+       a.published_at,
+       a.publish_at,
        u.username    as "author_username!",
        u.bio         as "author_bio!",
        u.image       as "author_image!"
@@ -13,6 +13,9 @@ with updated_article_cte as (
             body as "body",
             slug as "slug",
             description as "description",
+            status as "status",
+            published_at as "published_at",
+            publish_at as "publish_at",
             user_id as "user_id")
 select a.id          as "id!",
        a.created_at  as "created_at!",
@@ -21,6 +24,9 @@ select a.id          as "id!",
        a.body        as "body!",
        a.slug        as "slug!",
        a.description as "description!",
+       a.status      as "status!",
+       a.published_at,
+       a.publish_at,
        u.username    as "author_username!",
        u.bio         as "author_bio!",
        u.image       as "author_image!"
@@ -3,3 +3,4 @@ pub mod mocks;
 pub mod repositories;
 pub mod service_register;
 pub mod services;
+pub mod unit_of_work;
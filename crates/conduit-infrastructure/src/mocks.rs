@@ -1,12 +1,49 @@
+use std::ops::Add;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use conduit_core::access_tokens::repository::AccessTokenEntity;
+use conduit_core::access_tokens::repository::MockAccessTokensRepository;
+use conduit_core::articles::repository::ArticleRevisionEntity;
+use conduit_core::articles::repository::DeletedArticleEntity;
+use conduit_core::articles::repository::GetArticleQuery;
+use conduit_core::articles::repository::MockArticlesRepository;
+use conduit_core::articles::repository::UpsertArticleQuery;
+use conduit_core::comments::repository::CommentEntity;
+use conduit_core::comments::repository::CommentQuery;
+use conduit_core::comments::repository::MockCommentsRepository;
+use conduit_core::config::AppConfig;
+use conduit_core::mentions::repository::MockMentionsRepository;
+use conduit_core::notifications::repository::MockNotificationsRepository;
 use conduit_core::profiles::repository::MockProfilesRepository;
+use conduit_core::reactions::repository::MockReactionsRepository;
+use conduit_core::reactions::repository::ReactionCountQuery;
+use conduit_core::sessions::repository::LoginChallengeEntity;
+use conduit_core::sessions::repository::MockSessionsRepository;
+use conduit_core::sessions::repository::RefreshTokenEntity;
+use conduit_core::tags::repository::MockTagsRepository;
+use conduit_core::users::repository::EmailVerificationTokenEntity;
 use conduit_core::users::repository::MockUsersRepository;
+use conduit_core::users::repository::PasswordResetTokenEntity;
+use conduit_core::users::service::MockUsersService;
+use conduit_core::utils::mail_service::MockMailService;
 use conduit_core::utils::security_service::MockSecurityService;
 use conduit_core::utils::token_service::MockTokenService;
+use conduit_core::utils::totp_service::MockTotpService;
+use conduit_core::utils::unit_of_work::MockUnitOfWorkFactory;
+use mockall::predicate::eq;
+use sqlx::types::time::OffsetDateTime;
+
+use crate::services::users_service::DUMMY_PASSWORD;
 
 pub struct UsersServiceTestFixture {
     pub mock_repository: MockUsersRepository,
+    pub mock_sessions_repository: MockSessionsRepository,
     pub mock_token_service: MockTokenService,
     pub mock_security_service: MockSecurityService,
+    pub mock_mail_service: MockMailService,
+    pub mock_unit_of_work_factory: MockUnitOfWorkFactory,
+    pub config: Arc<AppConfig>,
 }
 
 pub struct ProfilesServiceTestFixture {
@@ -14,6 +51,302 @@ pub struct ProfilesServiceTestFixture {
     pub mock_users_repository: MockUsersRepository,
 }
 
+pub struct ArticlesServiceTestFixture {
+    pub mock_articles_repository: MockArticlesRepository,
+    pub mock_tags_repository: MockTagsRepository,
+    pub mock_reactions_repository: MockReactionsRepository,
+    pub mock_users_repository: MockUsersRepository,
+    pub mock_mentions_repository: MockMentionsRepository,
+    pub mock_notifications_repository: MockNotificationsRepository,
+    pub mock_unit_of_work_factory: MockUnitOfWorkFactory,
+}
+
+pub struct SessionsServiceTestFixture {
+    pub mock_sessions_repository: MockSessionsRepository,
+    pub mock_users_repository: MockUsersRepository,
+    pub mock_token_service: MockTokenService,
+    pub mock_unit_of_work_factory: MockUnitOfWorkFactory,
+    pub config: Arc<AppConfig>,
+}
+
+pub struct AdminServiceTestFixture {
+    pub mock_users_repository: MockUsersRepository,
+    pub mock_sessions_repository: MockSessionsRepository,
+    pub mock_users_service: MockUsersService,
+    pub mock_security_service: MockSecurityService,
+    pub mock_token_service: MockTokenService,
+    pub mock_unit_of_work_factory: MockUnitOfWorkFactory,
+}
+
+pub struct AccessTokensServiceTestFixture {
+    pub mock_repository: MockAccessTokensRepository,
+    pub mock_users_repository: MockUsersRepository,
+    pub mock_token_service: MockTokenService,
+}
+
+pub struct TwoFactorServiceTestFixture {
+    pub mock_users_repository: MockUsersRepository,
+    pub mock_sessions_repository: MockSessionsRepository,
+    pub mock_totp_service: MockTotpService,
+    pub mock_token_service: MockTokenService,
+    pub mock_unit_of_work_factory: MockUnitOfWorkFactory,
+}
+
+pub struct TrashServiceTestFixture {
+    pub mock_articles_repository: MockArticlesRepository,
+    pub mock_comments_repository: MockCommentsRepository,
+    pub config: Arc<AppConfig>,
+}
+
+pub struct CommentsServiceTestFixture {
+    pub mock_comments_repository: MockCommentsRepository,
+    pub mock_articles_repository: MockArticlesRepository,
+    pub mock_reactions_repository: MockReactionsRepository,
+    pub mock_users_repository: MockUsersRepository,
+    pub mock_mentions_repository: MockMentionsRepository,
+    pub mock_notifications_repository: MockNotificationsRepository,
+    pub mock_unit_of_work_factory: MockUnitOfWorkFactory,
+    pub config: Arc<AppConfig>,
+}
+
+pub struct ReactionsServiceTestFixture {
+    pub mock_reactions_repository: MockReactionsRepository,
+    pub mock_articles_repository: MockArticlesRepository,
+    pub mock_comments_repository: MockCommentsRepository,
+    pub mock_unit_of_work_factory: MockUnitOfWorkFactory,
+    pub config: Arc<AppConfig>,
+}
+
+pub struct NotificationsServiceTestFixture {
+    pub mock_notifications_repository: MockNotificationsRepository,
+}
+
+pub struct FeedsServiceTestFixture {
+    pub mock_articles_repository: MockArticlesRepository,
+    pub mock_tags_repository: MockTagsRepository,
+    pub config: Arc<AppConfig>,
+}
+
+/// Builds a configuration with stub values for services that depend on application settings.
+pub fn stub_config() -> AppConfig {
+    AppConfig {
+        database_url: String::from("stub database url"),
+        rust_log: String::from("info"),
+        argon_salt: String::from("stub salt"),
+        argon_variant: String::from("argon2id"),
+        argon_memory_cost_kib: 19456,
+        argon_iterations: 2,
+        token_secret: String::from("stub secret"),
+        access_token_lifetime_seconds: 3600,
+        refresh_token_lifetime_days: 30,
+        password_reset_token_lifetime_minutes: 60,
+        email_verification_token_lifetime_hours: 24,
+        require_email_verification: false,
+        two_factor_issuer: String::from("Conduit"),
+        two_factor_challenge_lifetime_minutes: 5,
+        login_max_failed_attempts: 5,
+        login_max_failed_attempts_per_ip: 20,
+        login_failure_window_minutes: 15,
+        login_lockout_seconds: 30,
+        login_max_lockout_seconds: 900,
+        trust_proxy_headers: false,
+        scheduler_interval_seconds: 60,
+        trash_retention_days: 30,
+        comment_max_depth: 5,
+        comment_edit_window_minutes: 15,
+        reaction_emojis: String::from("👍,❤️"),
+        port: 8080,
+        run_migrations: false,
+        seed: false,
+        admin_username: None,
+        cors_origin: String::from("http://localhost:3000"),
+        api_url: String::from("http://localhost:8080/api"),
+        site_url: String::from("http://localhost:3000"),
+        mail_transport: String::from("log"),
+        mail_from: String::from("noreply@conduit.local"),
+        mail_directory: None,
+        smtp_host: None,
+        smtp_port: 587,
+        smtp_username: None,
+        smtp_password: None,
+    }
+}
+
+/// Builds a published article row as returned by article writes, with stub values.
+pub fn stub_upsert_article_query() -> UpsertArticleQuery {
+    UpsertArticleQuery {
+        id: 1,
+        created_at: OffsetDateTime::from(SystemTime::now()),
+        updated_at: OffsetDateTime::from(SystemTime::now()),
+        title: String::from("stub title"),
+        body: String::from("stub body"),
+        description: String::from("stub description"),
+        slug: String::from("stub-title"),
+        status: String::from("published"),
+        published_at: Some(OffsetDateTime::from(SystemTime::now())),
+        publish_at: None,
+        author_username: String::from("stub username"),
+        author_image: String::from("stub image"),
+        author_bio: String::from("stub bio"),
+    }
+}
+
+/// Builds a published article row as returned by article reads, with stub values.
+pub fn stub_get_article_query() -> GetArticleQuery {
+    GetArticleQuery {
+        id: 1,
+        created_at: OffsetDateTime::from(SystemTime::now()),
+        updated_at: OffsetDateTime::from(SystemTime::now()),
+        title: String::from("stub title"),
+        body: String::from("stub body"),
+        description: String::from("stub description"),
+        slug: String::from("stub-title"),
+        status: String::from("published"),
+        published_at: Some(OffsetDateTime::from(SystemTime::now())),
+        publish_at: None,
+        user_id: 1,
+        favorites: 0,
+        favorited: false,
+        following_author: false,
+        author_username: String::from("stub username"),
+        author_image: String::from("stub image"),
+        author_bio: String::from("stub bio"),
+        snippet: None,
+    }
+}
+
+/// Builds the first revision of an article with stub values.
+pub fn stub_article_revision_entity() -> ArticleRevisionEntity {
+    ArticleRevisionEntity {
+        id: 1,
+        article_id: 1,
+        revision: 1,
+        title: String::from("stub title"),
+        description: String::from("stub description"),
+        body: String::from("stub body"),
+        editor_username: Some(String::from("stub username")),
+        created_at: OffsetDateTime::from(SystemTime::now()),
+    }
+}
+
+/// Builds a soft deleted article with stub values.
+pub fn stub_deleted_article_entity() -> DeletedArticleEntity {
+    DeletedArticleEntity {
+        id: 1,
+        slug: String::from("stub-title"),
+        title: String::from("stub title"),
+        description: String::from("stub description"),
+        deleted_by: Some(1),
+        deleted_at: OffsetDateTime::from(SystemTime::now()),
+    }
+}
+
+/// Builds a top level comment row with stub values.
+pub fn stub_comment_entity() -> CommentEntity {
+    CommentEntity {
+        id: 1,
+        body: String::from("stub body"),
+        user_id: 1,
+        article_id: 1,
+        parent_id: None,
+        created_at: OffsetDateTime::from(SystemTime::now()),
+        updated_at: OffsetDateTime::from(SystemTime::now()),
+    }
+}
+
+/// Builds a top level comment as returned by comment reads, with stub values.
+pub fn stub_comment_query() -> CommentQuery {
+    CommentQuery {
+        id: 1,
+        created_at: OffsetDateTime::from(SystemTime::now()),
+        updated_at: OffsetDateTime::from(SystemTime::now()),
+        body: String::from("stub body"),
+        author_username: String::from("stub username"),
+        author_bio: String::from("stub bio"),
+        author_image: String::from("stub image"),
+        following_author: false,
+        parent_id: None,
+        deleted: false,
+        edited: false,
+        reactions_count: 0,
+    }
+}
+
+/// Builds a read scoped personal access token with stub values.
+pub fn stub_access_token_entity() -> AccessTokenEntity {
+    AccessTokenEntity {
+        id: 1,
+        user_id: 1,
+        name: String::from("stub name"),
+        token_hash: String::from("stub token hash"),
+        scopes: vec![String::from("read")],
+        expires_at: None,
+        last_used_at: None,
+        created_at: OffsetDateTime::from(SystemTime::now()),
+    }
+}
+
+/// Builds an active refresh token expiring in an hour, with stub values.
+pub fn stub_refresh_token_entity() -> RefreshTokenEntity {
+    RefreshTokenEntity {
+        id: 1,
+        user_id: 1,
+        token_hash: String::from("stub token hash"),
+        family: String::from("stub family"),
+        expires_at: OffsetDateTime::from(SystemTime::now().add(Duration::from_secs(3600))),
+        revoked_at: None,
+        created_at: OffsetDateTime::from(SystemTime::now()),
+    }
+}
+
+/// Builds an unused two-factor login challenge expiring in five minutes, with stub values.
+pub fn stub_login_challenge_entity() -> LoginChallengeEntity {
+    LoginChallengeEntity {
+        id: 1,
+        user_id: 1,
+        token_hash: String::from("stub token hash"),
+        attempts: 0,
+        expires_at: OffsetDateTime::from(SystemTime::now().add(Duration::from_secs(300))),
+        used_at: None,
+        created_at: OffsetDateTime::from(SystemTime::now()),
+    }
+}
+
+/// Builds a single reaction count with stub values.
+pub fn stub_reaction_count_query() -> ReactionCountQuery {
+    ReactionCountQuery {
+        target_id: 1,
+        emoji: String::from("👍"),
+        count: 1,
+        reacted: false,
+    }
+}
+
+/// Builds an unused password reset token expiring in an hour, with stub values.
+pub fn stub_password_reset_token_entity() -> PasswordResetTokenEntity {
+    PasswordResetTokenEntity {
+        id: 1,
+        user_id: 1,
+        token_hash: String::from("stub token hash"),
+        expires_at: OffsetDateTime::from(SystemTime::now().add(Duration::from_secs(3600))),
+        used_at: None,
+        created_at: OffsetDateTime::from(SystemTime::now()),
+    }
+}
+
+/// Builds an unused email verification token expiring in an hour, with stub values.
+pub fn stub_email_verification_token_entity() -> EmailVerificationTokenEntity {
+    EmailVerificationTokenEntity {
+        id: 1,
+        user_id: 1,
+        email: String::from("stub email"),
+        token_hash: String::from("stub token hash"),
+        expires_at: OffsetDateTime::from(SystemTime::now().add(Duration::from_secs(3600))),
+        used_at: None,
+        created_at: OffsetDateTime::from(SystemTime::now()),
+    }
+}
+
 impl Default for UsersServiceTestFixture {
     fn default() -> Self {
         UsersServiceTestFixture::new()
@@ -22,10 +355,21 @@ impl Default for UsersServiceTestFixture {
 
 impl UsersServiceTestFixture {
     pub fn new() -> Self {
+        let mut mock_security_service = MockSecurityService::new();
+
+        mock_security_service
+            .expect_hash_password()
+            .with(eq(DUMMY_PASSWORD))
+            .returning(|_| Ok(String::from("stub dummy password hash")));
+
         Self {
             mock_repository: MockUsersRepository::new(),
+            mock_sessions_repository: MockSessionsRepository::new(),
             mock_token_service: MockTokenService::new(),
-            mock_security_service: MockSecurityService::new(),
+            mock_security_service,
+            mock_mail_service: MockMailService::new(),
+            mock_unit_of_work_factory: MockUnitOfWorkFactory::new(),
+            config: Arc::new(stub_config()),
         }
     }
 }
@@ -44,3 +388,179 @@ impl Default for ProfilesServiceTestFixture {
         ProfilesServiceTestFixture::new()
     }
 }
+
+impl ArticlesServiceTestFixture {
+    pub fn new() -> Self {
+        Self {
+            mock_articles_repository: MockArticlesRepository::new(),
+            mock_tags_repository: MockTagsRepository::new(),
+            mock_reactions_repository: MockReactionsRepository::new(),
+            mock_users_repository: MockUsersRepository::new(),
+            mock_mentions_repository: MockMentionsRepository::new(),
+            mock_notifications_repository: MockNotificationsRepository::new(),
+            mock_unit_of_work_factory: MockUnitOfWorkFactory::new(),
+        }
+    }
+}
+
+impl Default for ArticlesServiceTestFixture {
+    fn default() -> Self {
+        ArticlesServiceTestFixture::new()
+    }
+}
+
+impl SessionsServiceTestFixture {
+    pub fn new() -> Self {
+        Self {
+            mock_sessions_repository: MockSessionsRepository::new(),
+            mock_users_repository: MockUsersRepository::new(),
+            mock_token_service: MockTokenService::new(),
+            mock_unit_of_work_factory: MockUnitOfWorkFactory::new(),
+            config: Arc::new(stub_config()),
+        }
+    }
+}
+
+impl Default for SessionsServiceTestFixture {
+    fn default() -> Self {
+        SessionsServiceTestFixture::new()
+    }
+}
+
+impl AdminServiceTestFixture {
+    pub fn new() -> Self {
+        Self {
+            mock_users_repository: MockUsersRepository::new(),
+            mock_sessions_repository: MockSessionsRepository::new(),
+            mock_users_service: MockUsersService::new(),
+            mock_security_service: MockSecurityService::new(),
+            mock_token_service: MockTokenService::new(),
+            mock_unit_of_work_factory: MockUnitOfWorkFactory::new(),
+        }
+    }
+}
+
+impl Default for AdminServiceTestFixture {
+    fn default() -> Self {
+        AdminServiceTestFixture::new()
+    }
+}
+
+impl AccessTokensServiceTestFixture {
+    pub fn new() -> Self {
+        Self {
+            mock_repository: MockAccessTokensRepository::new(),
+            mock_users_repository: MockUsersRepository::new(),
+            mock_token_service: MockTokenService::new(),
+        }
+    }
+}
+
+impl Default for AccessTokensServiceTestFixture {
+    fn default() -> Self {
+        AccessTokensServiceTestFixture::new()
+    }
+}
+
+impl TwoFactorServiceTestFixture {
+    pub fn new() -> Self {
+        Self {
+            mock_users_repository: MockUsersRepository::new(),
+            mock_sessions_repository: MockSessionsRepository::new(),
+            mock_totp_service: MockTotpService::new(),
+            mock_token_service: MockTokenService::new(),
+            mock_unit_of_work_factory: MockUnitOfWorkFactory::new(),
+        }
+    }
+}
+
+impl Default for TwoFactorServiceTestFixture {
+    fn default() -> Self {
+        TwoFactorServiceTestFixture::new()
+    }
+}
+
+impl TrashServiceTestFixture {
+    pub fn new() -> Self {
+        Self {
+            mock_articles_repository: MockArticlesRepository::new(),
+            mock_comments_repository: MockCommentsRepository::new(),
+            config: Arc::new(stub_config()),
+        }
+    }
+}
+
+impl Default for TrashServiceTestFixture {
+    fn default() -> Self {
+        TrashServiceTestFixture::new()
+    }
+}
+
+impl CommentsServiceTestFixture {
+    pub fn new() -> Self {
+        Self {
+            mock_comments_repository: MockCommentsRepository::new(),
+            mock_articles_repository: MockArticlesRepository::new(),
+            mock_reactions_repository: MockReactionsRepository::new(),
+            mock_users_repository: MockUsersRepository::new(),
+            mock_mentions_repository: MockMentionsRepository::new(),
+            mock_notifications_repository: MockNotificationsRepository::new(),
+            mock_unit_of_work_factory: MockUnitOfWorkFactory::new(),
+            config: Arc::new(stub_config()),
+        }
+    }
+}
+
+impl Default for CommentsServiceTestFixture {
+    fn default() -> Self {
+        CommentsServiceTestFixture::new()
+    }
+}
+
+impl ReactionsServiceTestFixture {
+    pub fn new() -> Self {
+        Self {
+            mock_reactions_repository: MockReactionsRepository::new(),
+            mock_articles_repository: MockArticlesRepository::new(),
+            mock_comments_repository: MockCommentsRepository::new(),
+            mock_unit_of_work_factory: MockUnitOfWorkFactory::new(),
+            config: Arc::new(stub_config()),
+        }
+    }
+}
+
+impl Default for ReactionsServiceTestFixture {
+    fn default() -> Self {
+        ReactionsServiceTestFixture::new()
+    }
+}
+
+impl NotificationsServiceTestFixture {
+    pub fn new() -> Self {
+        Self {
+            mock_notifications_repository: MockNotificationsRepository::new(),
+        }
+    }
+}
+
+impl Default for NotificationsServiceTestFixture {
+    fn default() -> Self {
+        NotificationsServiceTestFixture::new()
+    }
+}
+
+impl FeedsServiceTestFixture {
+    pub fn new() -> Self {
+        Self {
+            mock_articles_repository: MockArticlesRepository::new(),
+            mock_tags_repository: MockTagsRepository::new(),
+            config: Arc::new(stub_config()),
+        }
+    }
+}
+
+impl Default for FeedsServiceTestFixture {
+    fn default() -> Self {
+        FeedsServiceTestFixture::new()
+    }
+}
@@ -0,0 +1,114 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
+use sqlx::{query, query_as};
+
+use conduit_core::access_tokens::repository::{AccessTokenEntity, AccessTokensRepository};
+
+use crate::connection_pool::ConduitConnectionPool;
+
+#[derive(Clone)]
+pub struct PostgresAccessTokensRepository {
+    pool: ConduitConnectionPool,
+}
+
+impl PostgresAccessTokensRepository {
+    pub fn new(pool: ConduitConnectionPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AccessTokensRepository for PostgresAccessTokensRepository {
+    async fn create_access_token(
+        &self,
+        user_id: i64,
+        name: String,
+        token_hash: String,
+        scopes: Vec<String>,
+        expires_at: Option<OffsetDateTime>,
+    ) -> anyhow::Result<AccessTokenEntity> {
+        query_as!(
+            AccessTokenEntity,
+            r#"
+        insert into personal_access_tokens (user_id, name, token_hash, scopes, expires_at, created_at)
+        values ($1::bigint, $2::varchar, $3::varchar, $4::varchar[], $5::timestamptz, current_timestamp)
+        returning *
+            "#,
+            user_id,
+            name,
+            token_hash,
+            &scopes,
+            expires_at
+        )
+        .fetch_one(&self.pool)
+        .await
+        .context("could not create the personal access token")
+    }
+
+    async fn get_user_access_tokens(&self, user_id: i64) -> anyhow::Result<Vec<AccessTokenEntity>> {
+        query_as!(
+            AccessTokenEntity,
+            r#"
+        select *
+        from personal_access_tokens
+        where user_id = $1
+        order by created_at desc, id desc
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("could not retrieve the user's personal access tokens")
+    }
+
+    async fn get_access_token(&self, token_hash: String) -> anyhow::Result<Option<AccessTokenEntity>> {
+        query_as!(
+            AccessTokenEntity,
+            r#"
+        select *
+        from personal_access_tokens
+        where token_hash = $1::varchar
+            "#,
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("could not retrieve the personal access token")
+    }
+
+    async fn delete_access_token(&self, user_id: i64, id: i64) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+        delete from personal_access_tokens
+        where id = $1
+        and user_id = $2
+            "#,
+            id,
+            user_id
+        )
+        .execute(&self.pool)
+        .await
+        .context("could not delete the personal access token")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn update_access_token_last_used(&self, id: i64) -> anyhow::Result<()> {
+        // tokens used by busy integrations only need their last use recorded roughly, so avoid a write per request
+        query!(
+            r#"
+        update personal_access_tokens
+        set last_used_at = current_timestamp
+        where id = $1
+        and (last_used_at is null or last_used_at < current_timestamp - interval '1 minute')
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .context("could not update the personal access token")?;
+
+        Ok(())
+    }
+}
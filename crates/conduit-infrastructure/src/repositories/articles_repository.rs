@@ -1,12 +1,18 @@
 use anyhow::Context;
 use async_trait::async_trait;
-use sqlx::{query, query_as, query_file_as};
+use sqlx::types::time::OffsetDateTime;
+use sqlx::{query, query_as, query_file, query_file_as};
 
+use conduit_core::articles::cursor::ArticleCursor;
 use conduit_core::articles::repository::{
-    ArticlesRepository, GetArticleFavoritesQuery, GetArticleQuery, UpsertArticleQuery,
+    ArticleRevisionEntity, ArticlesRepository, DeletedArticleEntity, GetArticleFavoritesQuery, GetArticleQuery,
+    NewArticle, PublishedArticleEntity, UpsertArticleQuery,
 };
+use conduit_core::utils::unit_of_work::DynUnitOfWork;
+use conduit_domain::articles::requests::GetArticlesServiceRequest;
 
 use crate::connection_pool::ConduitConnectionPool;
+use crate::unit_of_work::PostgresUnitOfWork;
 
 #[derive(Clone)]
 pub struct PostgresArticlesRepository {
@@ -23,28 +29,28 @@ impl PostgresArticlesRepository {
 impl ArticlesRepository for PostgresArticlesRepository {
     async fn create_article(
         &self,
-        user_id: i64,
-        title: String,
-        slug: String,
-        description: String,
-        body: String,
+        unit_of_work: &mut DynUnitOfWork,
+        article: NewArticle,
     ) -> anyhow::Result<UpsertArticleQuery> {
         query_file_as!(
             UpsertArticleQuery,
             "queries/insert_article.sql",
-            title,
-            body,
-            slug,
-            description,
-            user_id
+            article.title,
+            article.body,
+            article.slug,
+            article.description,
+            article.user_id,
+            article.status.as_str(),
+            article.publish_at
         )
-        .fetch_one(&self.pool)
+        .fetch_one(PostgresUnitOfWork::transaction(unit_of_work)?)
         .await
         .context("an unexpected error occured creating article")
     }
 
     async fn update_article(
         &self,
+        unit_of_work: &mut DynUnitOfWork,
         id: i64,
         title: String,
         slug: String,
@@ -60,7 +66,7 @@ impl ArticlesRepository for PostgresArticlesRepository {
             body,
             id
         )
-        .fetch_one(&self.pool)
+        .fetch_one(PostgresUnitOfWork::transaction(unit_of_work)?)
         .await
         .context("an unexpected error occured creating article")
     }
@@ -68,27 +74,46 @@ impl ArticlesRepository for PostgresArticlesRepository {
     async fn get_articles(
         &self,
         user_id: Option<i64>,
-        tag: Option<String>,
-        author: Option<String>,
-        favorited: Option<String>,
-        limit: i64,
-        offset: i64,
+        request: GetArticlesServiceRequest,
+        cursor: Option<ArticleCursor>,
     ) -> anyhow::Result<Vec<GetArticleQuery>> {
+        // the cursor already marks where the page starts, so the offset no longer applies
+        let offset = if cursor.is_some() { 0 } else { request.offset };
+
         query_file_as!(
             GetArticleQuery,
             "queries/get_articles.sql",
             user_id,
-            author,
-            tag,
-            favorited,
-            limit as i32,
-            offset as i32
+            request.author,
+            request.tag,
+            request.favorited,
+            request.limit as i32,
+            offset as i32,
+            request.q,
+            cursor.map(|cursor| cursor.timestamp),
+            cursor.map(|cursor| cursor.id)
         )
         .fetch_all(&self.pool)
         .await
         .context("an unexpected error occured retrieving articles")
     }
 
+    async fn get_articles_count(&self, request: GetArticlesServiceRequest) -> anyhow::Result<i64> {
+        let count = query_file!(
+            "queries/get_articles_count.sql",
+            request.author,
+            request.tag,
+            request.favorited,
+            request.q
+        )
+        .fetch_one(&self.pool)
+        .await
+        .context("an unexpected error occured counting articles")?
+        .count;
+
+        Ok(count)
+    }
+
     async fn get_article_by_slug(&self, user_id: Option<i64>, slug: String) -> anyhow::Result<Option<GetArticleQuery>> {
         query_file_as!(GetArticleQuery, "queries/get_article_by_slug.sql", user_id, slug)
             .fetch_optional(&self.pool)
@@ -96,21 +121,414 @@ impl ArticlesRepository for PostgresArticlesRepository {
             .context("an unexpected error occured retrieving articles")
     }
 
-    async fn delete_article(&self, id: i64) -> anyhow::Result<()> {
+    async fn get_taken_slugs(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        slug: String,
+        article_id: Option<i64>,
+    ) -> anyhow::Result<Vec<String>> {
+        // concurrent writes would otherwise both find the same slug free, the lock is held until the transaction ends
         query!(
             r#"
-    delete from articles
-    where id = $1
-        "#,
+        select true as "locked!"
+        from (select pg_advisory_xact_lock(hashtext('article_slugs'))) slug_lock
+            "#
+        )
+        .fetch_one(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occured locking the article slugs")?;
+
+        let taken_slugs = query!(
+            r#"
+        select a.slug as "slug!"
+        from articles a
+        where (a.slug = $1::varchar or a.slug like $1::varchar || '-%')
+          and ($2::bigint is null or a.id <> $2::bigint)
+        union
+        select h.slug as "slug!"
+        from article_slug_history h
+        where (h.slug = $1::varchar or h.slug like $1::varchar || '-%')
+          and ($2::bigint is null or h.article_id <> $2::bigint)
+            "#,
+            slug,
+            article_id
+        )
+        .fetch_all(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occured searching for the article slug")?
+        .into_iter()
+        .map(|taken_slug| taken_slug.slug)
+        .collect();
+
+        Ok(taken_slugs)
+    }
+
+    async fn record_slug_change(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        article_id: i64,
+        previous_slug: String,
+        slug: String,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        delete from article_slug_history
+        where article_id = $1::bigint
+          and slug = $2::varchar
+            "#,
+            article_id,
+            slug
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occured releasing the article slug")?;
+
+        query!(
+            r#"
+        insert into article_slug_history (article_id, slug)
+        values ($1::bigint, $2::varchar)
+        on conflict (slug) do nothing
+            "#,
+            article_id,
+            previous_slug
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occured recording the article slug")?;
+
+        Ok(())
+    }
+
+    async fn get_renamed_article_slug(&self, previous_slug: String) -> anyhow::Result<Option<String>> {
+        let renamed_article = query!(
+            r#"
+        select a.slug
+        from article_slug_history h
+                 join articles a on a.id = h.article_id
+        where h.slug = $1::varchar
+          and a.deleted_at is null
+            "#,
+            previous_slug
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("an unexpected error occured retrieving the renamed article")?;
+
+        Ok(renamed_article.map(|article| article.slug))
+    }
+
+    async fn get_user_drafts(
+        &self,
+        user_id: i64,
+        limit: i64,
+        offset: i64,
+        cursor: Option<ArticleCursor>,
+    ) -> anyhow::Result<Vec<GetArticleQuery>> {
+        // the cursor already marks where the page starts, so the offset no longer applies
+        let offset = if cursor.is_some() { 0 } else { offset };
+
+        query_file_as!(
+            GetArticleQuery,
+            "queries/get_user_drafts.sql",
+            user_id,
+            limit as i32,
+            offset as i32,
+            cursor.map(|cursor| cursor.timestamp),
+            cursor.map(|cursor| cursor.id)
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occured retrieving user drafts")
+    }
+
+    async fn get_user_drafts_count(&self, user_id: i64) -> anyhow::Result<i64> {
+        let count = query_file!("queries/get_user_drafts_count.sql", user_id)
+            .fetch_one(&self.pool)
+            .await
+            .context("an unexpected error occured counting user drafts")?
+            .count;
+
+        Ok(count)
+    }
+
+    async fn publish_article(&self, unit_of_work: &mut DynUnitOfWork, id: i64) -> anyhow::Result<UpsertArticleQuery> {
+        query_as!(
+            UpsertArticleQuery,
+            r#"
+        with published_article_cte as (
+            update articles
+            set status = 'published',
+                published_at = coalesce(published_at, current_timestamp),
+                publish_at = null,
+                updated_at = current_timestamp
+            where id = $1::bigint
+            returning *
+        ) select a.id as "id!",
+                 a.created_at as "created_at!",
+                 a.updated_at as "updated_at!",
+                 a.title as "title!",
+                 a.body as "body!",
+                 a.description as "description!",
+                 a.slug as "slug!",
+                 a.status as "status!",
+                 a.published_at,
+                 a.publish_at,
+                 u.username as "author_username!",
+                 u.bio as "author_bio!",
+                 u.image as "author_image!"
+        from published_article_cte a
+        join users u on u.id = a.user_id
+            "#,
             id
         )
-        .execute(&self.pool)
+        .fetch_one(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred publishing the article")
+    }
+
+    async fn update_article_publish_at(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        id: i64,
+        publish_at: OffsetDateTime,
+    ) -> anyhow::Result<UpsertArticleQuery> {
+        query_as!(
+            UpsertArticleQuery,
+            r#"
+        with scheduled_article_cte as (
+            update articles
+            set publish_at = $1::timestamptz,
+                updated_at = current_timestamp
+            where id = $2::bigint
+            returning *
+        ) select a.id as "id!",
+                 a.created_at as "created_at!",
+                 a.updated_at as "updated_at!",
+                 a.title as "title!",
+                 a.body as "body!",
+                 a.description as "description!",
+                 a.slug as "slug!",
+                 a.status as "status!",
+                 a.published_at,
+                 a.publish_at,
+                 u.username as "author_username!",
+                 u.bio as "author_bio!",
+                 u.image as "author_image!"
+        from scheduled_article_cte a
+        join users u on u.id = a.user_id
+            "#,
+            publish_at,
+            id
+        )
+        .fetch_one(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred scheduling the article")
+    }
+
+    async fn publish_due_articles(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+    ) -> anyhow::Result<Vec<PublishedArticleEntity>> {
+        query_as!(
+            PublishedArticleEntity,
+            r#"
+        update articles
+        set status = 'published',
+            published_at = coalesce(published_at, publish_at),
+            publish_at = null,
+            updated_at = current_timestamp
+        where publish_at <= current_timestamp
+          and status <> 'published'
+          and deleted_at is null
+        returning id, user_id
+            "#
+        )
+        .fetch_all(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred publishing scheduled articles")
+    }
+
+    async fn create_article_revision(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        article_id: i64,
+        editor_id: i64,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        insert into article_revisions (article_id, revision, title, description, body, editor_id)
+        select a.id,
+               coalesce((select max(revision) from article_revisions where article_id = a.id), 0) + 1,
+               a.title,
+               a.description,
+               a.body,
+               $2::bigint
+        from articles a
+        where a.id = $1::bigint
+            "#,
+            article_id,
+            editor_id
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred recording the article revision")?;
+
+        Ok(())
+    }
+
+    async fn get_article_revisions(&self, article_id: i64) -> anyhow::Result<Vec<ArticleRevisionEntity>> {
+        query_as!(
+            ArticleRevisionEntity,
+            r#"
+        select r.id,
+               r.article_id,
+               r.revision,
+               r.title,
+               r.description,
+               r.body,
+               u.username as "editor_username?",
+               r.created_at
+        from article_revisions r
+        left join users u on u.id = r.editor_id
+        where r.article_id = $1::bigint
+        order by r.revision desc
+            "#,
+            article_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occured retrieving article revisions")
+    }
+
+    async fn get_article_revision(
+        &self,
+        article_id: i64,
+        revision: i32,
+    ) -> anyhow::Result<Option<ArticleRevisionEntity>> {
+        query_as!(
+            ArticleRevisionEntity,
+            r#"
+        select r.id,
+               r.article_id,
+               r.revision,
+               r.title,
+               r.description,
+               r.body,
+               u.username as "editor_username?",
+               r.created_at
+        from article_revisions r
+        left join users u on u.id = r.editor_id
+        where r.article_id = $1::bigint
+          and r.revision = $2::integer
+            "#,
+            article_id,
+            revision
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("an unexpected error occured retrieving the article revision")
+    }
+
+    async fn delete_article(&self, unit_of_work: &mut DynUnitOfWork, id: i64, deleted_by: i64) -> anyhow::Result<()> {
+        query!(
+            r#"
+        update articles
+        set deleted_at = current_timestamp,
+            deleted_by = $2::bigint
+        where id = $1::bigint
+          and deleted_at is null
+            "#,
+            id,
+            deleted_by
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
         .await
         .context("an unexpected error occurred deleting article")?;
 
         Ok(())
     }
 
+    async fn get_deleted_articles(
+        &self,
+        deleted_by: i64,
+        deleted_after: OffsetDateTime,
+    ) -> anyhow::Result<Vec<DeletedArticleEntity>> {
+        query_as!(
+            DeletedArticleEntity,
+            r#"
+        select id,
+               slug,
+               title,
+               description,
+               deleted_by,
+               deleted_at as "deleted_at!"
+        from articles
+        where deleted_by = $1::bigint
+          and deleted_at > $2::timestamptz
+        order by deleted_at desc
+            "#,
+            deleted_by,
+            deleted_after
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occurred retrieving deleted articles")
+    }
+
+    async fn get_deleted_article(&self, slug: String) -> anyhow::Result<Option<DeletedArticleEntity>> {
+        query_as!(
+            DeletedArticleEntity,
+            r#"
+        select id,
+               slug,
+               title,
+               description,
+               deleted_by,
+               deleted_at as "deleted_at!"
+        from articles
+        where slug = $1::varchar
+          and deleted_at is not null
+            "#,
+            slug
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("an unexpected error occurred retrieving the deleted article")
+    }
+
+    async fn restore_article(&self, id: i64) -> anyhow::Result<()> {
+        query!(
+            r#"
+        update articles
+        set deleted_at = null,
+            deleted_by = null
+        where id = $1::bigint
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .context("an unexpected error occurred restoring article")?;
+
+        Ok(())
+    }
+
+    async fn purge_deleted_articles(&self, deleted_before: OffsetDateTime) -> anyhow::Result<u64> {
+        let purged = query!(
+            r#"
+        delete from articles
+        where deleted_at <= $1::timestamptz
+            "#,
+            deleted_before
+        )
+        .execute(&self.pool)
+        .await
+        .context("an unexpected error occurred purging deleted articles")?
+        .rows_affected();
+
+        Ok(purged)
+    }
+
     async fn favorite_article(&self, article_id: i64, user_id: i64) -> anyhow::Result<GetArticleQuery> {
         query_as!(
             GetArticleQuery,
@@ -126,13 +544,17 @@ impl ArticlesRepository for PostgresArticlesRepository {
                  a.body as "body!",
                  a.description as "description!",
                  a.slug as "slug!",
+                 a.status as "status!",
+                 a.published_at,
+                 a.publish_at,
                  u.id as "user_id!",
                  true as "favorited!",
                  (select count(*) + 1 from user_favorites where article_id = a.id) as "favorites!",
                  exists(select 1 from user_follows where followee_id = a.user_id and follower_id = $1) "following_author!",
                  u.username as "author_username!",
                  u.bio as "author_bio!",
-                 u.image as "author_image!"
+                 u.image as "author_image!",
+                 null::varchar as "snippet?"
         from favorited_article_cte fa
         join users u on fa.user_id = u.id
         join articles a on fa.article_id = a.id
@@ -160,13 +582,17 @@ impl ArticlesRepository for PostgresArticlesRepository {
                  a.body as "body!",
                  a.description as "description!",
                  a.slug as "slug!",
+                 a.status as "status!",
+                 a.published_at,
+                 a.publish_at,
                  u.id as "user_id!",
                  false as "favorited!",
                  (select count(*) - 1 from user_favorites where article_id = a.id) as "favorites!",
                  exists(select 1 from user_follows where followee_id = a.user_id and follower_id = $1) "following_author!",
                  u.username as "author_username!",
                  u.bio as "author_bio!",
-                 u.image as "author_image!"
+                 u.image as "author_image!",
+                 null::varchar as "snippet?"
         from articles a
         join users u on a.user_id = u.id
         where a.id = $2
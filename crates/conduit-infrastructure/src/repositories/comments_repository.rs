@@ -1,10 +1,17 @@
 use anyhow::Context;
 use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
 use sqlx::{query, query_as};
 
-use conduit_core::comments::repository::{CommentEntity, CommentQuery, CommentsRepository};
+use conduit_core::comments::cursor::CommentCursor;
+use conduit_core::comments::repository::{
+    CommentEntity, CommentQuery, CommentRevisionEntity, CommentsRepository, DeletedCommentEntity,
+};
+use conduit_core::utils::unit_of_work::DynUnitOfWork;
+use conduit_domain::comments::requests::CommentSort;
 
 use crate::connection_pool::ConduitConnectionPool;
+use crate::unit_of_work::PostgresUnitOfWork;
 
 pub struct PostgresCommentsRepository {
     pool: ConduitConnectionPool,
@@ -18,7 +25,14 @@ impl PostgresCommentsRepository {
 
 #[async_trait]
 impl CommentsRepository for PostgresCommentsRepository {
-    async fn get_comments(&self, user_id: Option<i64>, article_id: i64) -> anyhow::Result<Vec<CommentQuery>> {
+    async fn get_comments(
+        &self,
+        user_id: Option<i64>,
+        article_id: i64,
+        sort: CommentSort,
+        limit: i64,
+        cursor: Option<CommentCursor>,
+    ) -> anyhow::Result<Vec<CommentQuery>> {
         query_as!(
             CommentQuery,
             r#"
@@ -29,28 +43,121 @@ impl CommentsRepository for PostgresCommentsRepository {
                u.username as "author_username!",
                u.bio as "author_bio!",
                u.image as "author_image!",
-               exists(select 1 from user_follows uf where (
-                   $1::bigint is null or (uf.followee_id, uf.follower_id) = ($1::bigint, c.user_id))
-               ) as "following_author!"
+               exists(select 1
+                      from user_follows uf
+                      where uf.follower_id = $1::bigint
+                        and uf.followee_id = c.user_id) as "following_author!",
+               c.parent_id,
+               c.deleted_at is not null as "deleted!",
+               c.updated_at > c.created_at as "edited!",
+               rc.reactions_count as "reactions_count!"
         from comments c
         join users u on c.user_id = u.id
+        cross join lateral (select count(*) as reactions_count from reactions re where re.comment_id = c.id) rc
         where c.article_id = $2
+          and c.parent_id is null
+          and (c.deleted_at is null or exists(select 1 from comments r where r.parent_id = c.id))
+          and ($5::timestamptz is null
+              or ($3::varchar = 'newest' and (c.created_at, c.id) < ($5::timestamptz, $6::bigint))
+              or ($3::varchar = 'oldest' and (c.created_at, c.id) > ($5::timestamptz, $6::bigint))
+              or ($3::varchar = 'most-reacted'
+                  and (-rc.reactions_count, c.created_at, c.id) > (-$7::bigint, $5::timestamptz, $6::bigint)))
+        order by case when $3::varchar = 'most-reacted' then rc.reactions_count end desc,
+                 case when $3::varchar = 'newest' then c.created_at end desc,
+                 case when $3::varchar = 'newest' then c.id end desc,
+                 c.created_at,
+                 c.id
+        limit $4
             "#,
             user_id,
-            article_id
+            article_id,
+            sort.as_str(),
+            limit,
+            cursor.map(|cursor| cursor.created_at),
+            cursor.map(|cursor| cursor.id),
+            cursor.map(|cursor| cursor.reactions_count)
         )
         .fetch_all(&self.pool)
         .await
         .context("an unexpected error occurred while retrieving comments")
     }
 
+    async fn get_comment_replies(
+        &self,
+        user_id: Option<i64>,
+        comment_ids: Vec<i64>,
+    ) -> anyhow::Result<Vec<CommentQuery>> {
+        query_as!(
+            CommentQuery,
+            r#"
+        with recursive replies_cte as (
+            select r.id
+            from comments r
+            where r.parent_id = any($2::bigint[])
+            union all
+            select r.id
+            from comments r
+            join replies_cte p on r.parent_id = p.id
+        ) select c.id as "id!",
+                 c.body as "body!",
+                 c.created_at as "created_at!",
+                 c.updated_at as "updated_at!",
+                 u.username as "author_username!",
+                 u.bio as "author_bio!",
+                 u.image as "author_image!",
+                 exists(select 1
+                        from user_follows uf
+                        where uf.follower_id = $1::bigint
+                          and uf.followee_id = c.user_id) as "following_author!",
+                 c.parent_id,
+                 c.deleted_at is not null as "deleted!",
+                 c.updated_at > c.created_at as "edited!",
+                 (select count(*) from reactions re where re.comment_id = c.id) as "reactions_count!"
+        from comments c
+        join users u on c.user_id = u.id
+        where c.id in (select id from replies_cte)
+        order by c.created_at, c.id
+            "#,
+            user_id,
+            &comment_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occurred while retrieving comment replies")
+    }
+
+    async fn get_comments_count(&self, article_id: i64) -> anyhow::Result<i64> {
+        let count = query!(
+            r#"
+        select count(*) as "count!"
+        from comments
+        where article_id = $1::bigint
+          and deleted_at is null
+            "#,
+            article_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .context("an unexpected error occurred while counting comments")?
+        .count;
+
+        Ok(count)
+    }
+
     async fn get_comment(&self, comment_id: i64) -> anyhow::Result<Option<CommentEntity>> {
         query_as!(
             CommentEntity,
             r#"
-        select *
+        select id,
+               body,
+               user_id,
+               article_id,
+               parent_id,
+               created_at,
+               updated_at
         from comments
         where id = $1::bigint
+          and deleted_at is null
             "#,
             comment_id
         )
@@ -59,13 +166,20 @@ impl CommentsRepository for PostgresCommentsRepository {
         .context("an unexpected error occurred while creating comment")
     }
 
-    async fn create_comment(&self, article_id: i64, user_id: i64, body: String) -> anyhow::Result<CommentQuery> {
+    async fn create_comment(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        article_id: i64,
+        user_id: i64,
+        parent_id: Option<i64>,
+        body: String,
+    ) -> anyhow::Result<CommentQuery> {
         query_as!(
             CommentQuery,
             r#"
         with insert_comment_cte as (
-            insert into comments (body, user_id, article_id, created_at, updated_at)
-            values ($1::varchar, $2::bigint, $3::bigint, current_timestamp, current_timestamp)
+            insert into comments (body, user_id, article_id, parent_id, created_at, updated_at)
+            values ($1::varchar, $2::bigint, $3::bigint, $4::bigint, current_timestamp, current_timestamp)
             returning *
         ) select c.id as "id!",
                  c.body as "body!",
@@ -74,32 +188,206 @@ impl CommentsRepository for PostgresCommentsRepository {
                  u.username as "author_username!",
                  u.bio as "author_bio!",
                  u.image as "author_image!",
-                 false as "following_author!"
+                 false as "following_author!",
+                 c.parent_id,
+                 false as "deleted!",
+                 false as "edited!",
+                 0::bigint as "reactions_count!"
         from insert_comment_cte c
         join users u on c.user_id = u.id
         where c.article_id = $3::bigint
             "#,
             body,
             user_id,
-            article_id
+            article_id,
+            parent_id
         )
-        .fetch_one(&self.pool)
+        .fetch_one(PostgresUnitOfWork::transaction(unit_of_work)?)
         .await
         .context("an unexpected error occurred while creating comment")
     }
 
-    async fn delete_comment(&self, comment_id: i64) -> anyhow::Result<()> {
+    async fn update_comment(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        comment_id: i64,
+        body: String,
+    ) -> anyhow::Result<CommentQuery> {
+        query_as!(
+            CommentQuery,
+            r#"
+        with update_comment_cte as (
+            update comments
+            set body = $2::varchar,
+                updated_at = current_timestamp
+            where id = $1::bigint
+            returning *
+        ) select c.id as "id!",
+                 c.body as "body!",
+                 c.created_at as "created_at!",
+                 c.updated_at as "updated_at!",
+                 u.username as "author_username!",
+                 u.bio as "author_bio!",
+                 u.image as "author_image!",
+                 false as "following_author!",
+                 c.parent_id,
+                 false as "deleted!",
+                 true as "edited!",
+                 (select count(*) from reactions re where re.comment_id = c.id) as "reactions_count!"
+        from update_comment_cte c
+        join users u on c.user_id = u.id
+            "#,
+            comment_id,
+            body
+        )
+        .fetch_one(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while updating comment")
+    }
+
+    async fn create_comment_revision(&self, unit_of_work: &mut DynUnitOfWork, comment_id: i64) -> anyhow::Result<()> {
         query!(
             r#"
-       delete from comments
-       where id = $1
+        insert into comment_revisions (comment_id, body, created_at)
+        select id, body, updated_at
+        from comments
+        where id = $1::bigint
             "#,
             comment_id
         )
-        .execute(&self.pool)
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while recording comment revision")?;
+
+        Ok(())
+    }
+
+    async fn get_comment_revisions(&self, comment_id: i64) -> anyhow::Result<Vec<CommentRevisionEntity>> {
+        query_as!(
+            CommentRevisionEntity,
+            r#"
+        select id,
+               comment_id,
+               body,
+               created_at
+        from comment_revisions
+        where comment_id = $1::bigint
+        order by created_at, id
+            "#,
+            comment_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occurred while retrieving comment revisions")
+    }
+
+    async fn delete_comment(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        comment_id: i64,
+        deleted_by: i64,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        update comments
+        set deleted_at = current_timestamp,
+            deleted_by = $2::bigint
+        where id = $1::bigint
+          and deleted_at is null
+            "#,
+            comment_id,
+            deleted_by
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
         .await
         .context("an unexpected error occurred while deleting comment")?;
 
         Ok(())
     }
+
+    async fn get_deleted_comments(
+        &self,
+        deleted_by: i64,
+        deleted_after: OffsetDateTime,
+    ) -> anyhow::Result<Vec<DeletedCommentEntity>> {
+        query_as!(
+            DeletedCommentEntity,
+            r#"
+        select c.id,
+               c.body,
+               a.slug as article_slug,
+               c.deleted_by,
+               c.deleted_at as "deleted_at!"
+        from comments c
+        join articles a on a.id = c.article_id
+        where c.deleted_by = $1::bigint
+          and c.deleted_at > $2::timestamptz
+        order by c.deleted_at desc
+            "#,
+            deleted_by,
+            deleted_after
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occurred while retrieving deleted comments")
+    }
+
+    async fn get_deleted_comment(&self, comment_id: i64) -> anyhow::Result<Option<DeletedCommentEntity>> {
+        query_as!(
+            DeletedCommentEntity,
+            r#"
+        select c.id,
+               c.body,
+               a.slug as article_slug,
+               c.deleted_by,
+               c.deleted_at as "deleted_at!"
+        from comments c
+        join articles a on a.id = c.article_id
+        where c.id = $1::bigint
+          and c.deleted_at is not null
+            "#,
+            comment_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("an unexpected error occurred while retrieving the deleted comment")
+    }
+
+    async fn restore_comment(&self, comment_id: i64) -> anyhow::Result<()> {
+        query!(
+            r#"
+        update comments
+        set deleted_at = null,
+            deleted_by = null
+        where id = $1::bigint
+            "#,
+            comment_id
+        )
+        .execute(&self.pool)
+        .await
+        .context("an unexpected error occurred while restoring comment")?;
+
+        Ok(())
+    }
+
+    async fn purge_deleted_comments(&self, deleted_before: OffsetDateTime) -> anyhow::Result<u64> {
+        let purged = query!(
+            r#"
+        delete from comments c
+        where c.deleted_at <= $1::timestamptz
+          -- deleted comments stay as placeholders until none of their replies remain
+          and not exists(select 1
+                         from comments r
+                         where r.parent_id = c.id
+                           and (r.deleted_at is null or r.deleted_at > $1::timestamptz))
+            "#,
+            deleted_before
+        )
+        .execute(&self.pool)
+        .await
+        .context("an unexpected error occurred while purging deleted comments")?
+        .rows_affected();
+
+        Ok(purged)
+    }
 }
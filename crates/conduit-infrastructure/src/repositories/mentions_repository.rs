@@ -0,0 +1,127 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use sqlx::{query, query_as};
+
+use conduit_core::mentions::repository::{CommentMentionQuery, MentionsRepository};
+use conduit_core::utils::unit_of_work::DynUnitOfWork;
+
+use crate::connection_pool::ConduitConnectionPool;
+use crate::unit_of_work::PostgresUnitOfWork;
+
+pub struct PostgresMentionsRepository {
+    pool: ConduitConnectionPool,
+}
+
+impl PostgresMentionsRepository {
+    pub fn new(pool: ConduitConnectionPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MentionsRepository for PostgresMentionsRepository {
+    async fn replace_mentions(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        article_id: i64,
+        comment_id: Option<i64>,
+        user_ids: Vec<i64>,
+    ) -> anyhow::Result<Vec<i64>> {
+        query!(
+            r#"
+        delete from mentions
+        where article_id = $1::bigint
+          and comment_id is not distinct from $2::bigint
+          and user_id <> all($3::bigint[])
+            "#,
+            article_id,
+            comment_id,
+            &user_ids
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while removing mentions")?;
+
+        // users already mentioned conflict with their existing mention, so only the newly mentioned users are returned
+        let mentioned_user_ids = query!(
+            r#"
+        insert into mentions (user_id, article_id, comment_id)
+        select unnest($3::bigint[]), $1::bigint, $2::bigint
+        on conflict do nothing
+        returning user_id
+            "#,
+            article_id,
+            comment_id,
+            &user_ids
+        )
+        .fetch_all(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while creating mentions")?
+        .into_iter()
+        .map(|mention| mention.user_id)
+        .collect();
+
+        Ok(mentioned_user_ids)
+    }
+
+    async fn get_article_mentions(&self, article_id: i64) -> anyhow::Result<Vec<String>> {
+        let usernames = query!(
+            r#"
+        select u.username
+        from mentions m
+        join users u on m.user_id = u.id
+        where m.article_id = $1::bigint
+          and m.comment_id is null
+        order by m.id
+            "#,
+            article_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occurred while retrieving article mentions")?
+        .into_iter()
+        .map(|mention| mention.username)
+        .collect();
+
+        Ok(usernames)
+    }
+
+    async fn get_article_mentioned_user_ids(&self, article_id: i64) -> anyhow::Result<Vec<i64>> {
+        let user_ids = query!(
+            r#"
+        select m.user_id
+        from mentions m
+        where m.article_id = $1::bigint
+          and m.comment_id is null
+        order by m.id
+            "#,
+            article_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occurred while retrieving article mentions")?
+        .into_iter()
+        .map(|mention| mention.user_id)
+        .collect();
+
+        Ok(user_ids)
+    }
+
+    async fn get_comment_mentions(&self, comment_ids: Vec<i64>) -> anyhow::Result<Vec<CommentMentionQuery>> {
+        query_as!(
+            CommentMentionQuery,
+            r#"
+        select m.comment_id as "comment_id!",
+               u.username
+        from mentions m
+        join users u on m.user_id = u.id
+        where m.comment_id = any($1::bigint[])
+        order by m.id
+            "#,
+            &comment_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occurred while retrieving comment mentions")
+    }
+}
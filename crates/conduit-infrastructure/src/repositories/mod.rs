@@ -1,5 +1,10 @@
+pub mod access_tokens_repository;
 pub mod articles_repository;
 pub mod comments_repository;
+pub mod mentions_repository;
+pub mod notifications_repository;
 pub mod profiles_repository;
+pub mod reactions_repository;
+pub mod sessions_repository;
 pub mod tags_repository;
 pub mod users_repository;
@@ -0,0 +1,120 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use sqlx::{query, query_as};
+
+use conduit_core::notifications::repository::{NotificationQuery, NotificationsRepository};
+use conduit_core::utils::unit_of_work::DynUnitOfWork;
+
+use crate::connection_pool::ConduitConnectionPool;
+use crate::unit_of_work::PostgresUnitOfWork;
+
+pub struct PostgresNotificationsRepository {
+    pool: ConduitConnectionPool,
+}
+
+impl PostgresNotificationsRepository {
+    pub fn new(pool: ConduitConnectionPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationsRepository for PostgresNotificationsRepository {
+    async fn create_mention_notifications(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        actor_id: i64,
+        article_id: i64,
+        comment_id: Option<i64>,
+        user_ids: Vec<i64>,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        insert into notifications (user_id, actor_id, kind, article_id, comment_id)
+        select unnest($4::bigint[]), $1::bigint, 'mention', $2::bigint, $3::bigint
+            "#,
+            actor_id,
+            article_id,
+            comment_id,
+            &user_ids
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while creating mention notifications")?;
+
+        Ok(())
+    }
+
+    async fn get_notifications(&self, user_id: i64, limit: i64, offset: i64) -> anyhow::Result<Vec<NotificationQuery>> {
+        query_as!(
+            NotificationQuery,
+            r#"
+        select n.id,
+               n.kind,
+               u.username as "actor_username",
+               a.slug as "article_slug",
+               a.title as "article_title",
+               n.comment_id,
+               n.read_at,
+               n.created_at
+        from notifications n
+        join users u on n.actor_id = u.id
+        join articles a on n.article_id = a.id
+        left join comments c on n.comment_id = c.id
+        where n.user_id = $1::bigint
+          and a.deleted_at is null
+          and c.deleted_at is null
+        order by n.created_at desc, n.id desc
+        limit $2::bigint
+        offset $3::bigint
+            "#,
+            user_id,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occurred while retrieving notifications")
+    }
+
+    async fn get_unread_notifications_count(&self, user_id: i64) -> anyhow::Result<i64> {
+        let count = query!(
+            r#"
+        select count(*) as "count!"
+        from notifications n
+        join articles a on n.article_id = a.id
+        left join comments c on n.comment_id = c.id
+        where n.user_id = $1::bigint
+          and n.read_at is null
+          and a.deleted_at is null
+          and c.deleted_at is null
+            "#,
+            user_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .context("an unexpected error occurred while counting unread notifications")?
+        .count;
+
+        Ok(count)
+    }
+
+    async fn mark_notifications_read(&self, user_id: i64, notification_id: Option<i64>) -> anyhow::Result<u64> {
+        let marked = query!(
+            r#"
+        update notifications
+        set read_at = coalesce(read_at, current_timestamp)
+        where user_id = $1::bigint
+          and ($2::bigint is null or id = $2::bigint)
+            "#,
+            user_id,
+            notification_id
+        )
+        .execute(&self.pool)
+        .await
+        .context("an unexpected error occurred while marking notifications read")?
+        .rows_affected();
+
+        Ok(marked)
+    }
+}
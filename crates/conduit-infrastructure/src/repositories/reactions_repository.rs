@@ -0,0 +1,138 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use sqlx::{query, query_as};
+
+use conduit_core::reactions::repository::{ReactionCountQuery, ReactionsRepository};
+use conduit_core::reactions::ReactionTarget;
+use conduit_core::utils::unit_of_work::DynUnitOfWork;
+
+use crate::connection_pool::ConduitConnectionPool;
+use crate::unit_of_work::PostgresUnitOfWork;
+
+pub struct PostgresReactionsRepository {
+    pool: ConduitConnectionPool,
+}
+
+impl PostgresReactionsRepository {
+    pub fn new(pool: ConduitConnectionPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Splits the target into the article and comment columns, exactly one of which is set on each reaction.
+fn target_columns(target: ReactionTarget) -> (Option<i64>, Option<i64>) {
+    match target {
+        ReactionTarget::Article(article_id) => (Some(article_id), None),
+        ReactionTarget::Comment(comment_id) => (None, Some(comment_id)),
+    }
+}
+
+#[async_trait]
+impl ReactionsRepository for PostgresReactionsRepository {
+    async fn add_reaction(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        target: ReactionTarget,
+        emoji: String,
+    ) -> anyhow::Result<()> {
+        let (article_id, comment_id) = target_columns(target);
+
+        query!(
+            r#"
+        insert into reactions (user_id, article_id, comment_id, emoji)
+        values ($1::bigint, $2::bigint, $3::bigint, $4::varchar)
+        on conflict do nothing
+            "#,
+            user_id,
+            article_id,
+            comment_id,
+            emoji
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while adding reaction")?;
+
+        Ok(())
+    }
+
+    async fn remove_reaction(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        target: ReactionTarget,
+        emoji: String,
+    ) -> anyhow::Result<u64> {
+        let (article_id, comment_id) = target_columns(target);
+
+        let removed = query!(
+            r#"
+        delete from reactions
+        where user_id = $1::bigint
+          and article_id is not distinct from $2::bigint
+          and comment_id is not distinct from $3::bigint
+          and emoji = $4::varchar
+            "#,
+            user_id,
+            article_id,
+            comment_id,
+            emoji
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while removing reaction")?
+        .rows_affected();
+
+        Ok(removed)
+    }
+
+    async fn get_article_reactions(
+        &self,
+        user_id: Option<i64>,
+        article_ids: Vec<i64>,
+    ) -> anyhow::Result<Vec<ReactionCountQuery>> {
+        query_as!(
+            ReactionCountQuery,
+            r#"
+        select r.article_id as "target_id!",
+               r.emoji,
+               count(*) as "count!",
+               coalesce(bool_or(r.user_id = $1::bigint), false) as "reacted!"
+        from reactions r
+        where r.article_id = any($2::bigint[])
+        group by r.article_id, r.emoji
+        order by r.article_id, min(r.id)
+            "#,
+            user_id,
+            &article_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occurred while retrieving article reactions")
+    }
+
+    async fn get_comment_reactions(
+        &self,
+        user_id: Option<i64>,
+        comment_ids: Vec<i64>,
+    ) -> anyhow::Result<Vec<ReactionCountQuery>> {
+        query_as!(
+            ReactionCountQuery,
+            r#"
+        select r.comment_id as "target_id!",
+               r.emoji,
+               count(*) as "count!",
+               coalesce(bool_or(r.user_id = $1::bigint), false) as "reacted!"
+        from reactions r
+        where r.comment_id = any($2::bigint[])
+        group by r.comment_id, r.emoji
+        order by r.comment_id, min(r.id)
+            "#,
+            user_id,
+            &comment_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occurred while retrieving comment reactions")
+    }
+}
@@ -0,0 +1,327 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
+use sqlx::{query, query_as};
+
+use conduit_core::sessions::repository::{LoginChallengeEntity, RefreshTokenEntity, SessionsRepository};
+use conduit_core::utils::unit_of_work::DynUnitOfWork;
+
+use crate::connection_pool::ConduitConnectionPool;
+use crate::unit_of_work::PostgresUnitOfWork;
+
+#[derive(Clone)]
+pub struct PostgresSessionsRepository {
+    pool: ConduitConnectionPool,
+}
+
+impl PostgresSessionsRepository {
+    pub fn new(pool: ConduitConnectionPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SessionsRepository for PostgresSessionsRepository {
+    async fn create_refresh_token(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        token_hash: String,
+        family: String,
+        expires_at: OffsetDateTime,
+    ) -> anyhow::Result<RefreshTokenEntity> {
+        query_as!(
+            RefreshTokenEntity,
+            r#"
+        insert into refresh_tokens (user_id, token_hash, family, expires_at, created_at)
+        values ($1::bigint, $2::varchar, $3::varchar, $4::timestamptz, current_timestamp)
+        returning *
+            "#,
+            user_id,
+            token_hash,
+            family,
+            expires_at
+        )
+        .fetch_one(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while creating refresh token")
+    }
+
+    async fn get_refresh_token(&self, token_hash: String) -> anyhow::Result<Option<RefreshTokenEntity>> {
+        query_as!(
+            RefreshTokenEntity,
+            r#"
+        select *
+        from refresh_tokens
+        where token_hash = $1::varchar
+            "#,
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("an unexpected error occurred while retrieving refresh token")
+    }
+
+    async fn revoke_refresh_token(&self, unit_of_work: &mut DynUnitOfWork, id: i64) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+        update refresh_tokens
+        set revoked_at = current_timestamp
+        where id = $1
+        and revoked_at is null
+            "#,
+            id
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while revoking refresh token")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn revoke_refresh_token_family(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        family: String,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        update refresh_tokens
+        set revoked_at = current_timestamp
+        where family = $1::varchar
+        and revoked_at is null
+            "#,
+            family
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while revoking refresh token family")?;
+
+        Ok(())
+    }
+
+    async fn revoke_user_refresh_tokens(&self, unit_of_work: &mut DynUnitOfWork, user_id: i64) -> anyhow::Result<()> {
+        query!(
+            r#"
+        update refresh_tokens
+        set revoked_at = current_timestamp
+        where user_id = $1
+        and revoked_at is null
+            "#,
+            user_id
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while revoking user refresh tokens")?;
+
+        Ok(())
+    }
+
+    async fn revoke_access_token(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        token_id: String,
+        expires_at: OffsetDateTime,
+    ) -> anyhow::Result<()> {
+        let transaction = PostgresUnitOfWork::transaction(unit_of_work)?;
+
+        // revoked tokens only need to be tracked until they would have expired anyway, so clean up while we're here
+        query!(
+            r#"
+        delete from revoked_access_tokens
+        where expires_at < current_timestamp
+            "#
+        )
+        .execute(&mut *transaction)
+        .await
+        .context("an unexpected error occurred while removing expired revoked access tokens")?;
+
+        query!(
+            r#"
+        insert into revoked_access_tokens (token_id, expires_at, created_at)
+        values ($1::varchar, $2::timestamptz, current_timestamp)
+            "#,
+            token_id,
+            expires_at
+        )
+        .execute(&mut *transaction)
+        .await
+        .context("an unexpected error occurred while revoking access token")?;
+
+        Ok(())
+    }
+
+    async fn is_session_revoked(&self, token_id: String, user_id: i64, role: String) -> anyhow::Result<bool> {
+        let revoked = query!(
+            r#"
+        select exists(
+            select 1
+            from revoked_access_tokens
+            where token_id = $1::varchar
+        ) or not exists(
+            select 1
+            from users
+            where id = $2
+            and suspended_at is null
+            and role = $3::varchar
+        ) as "revoked!"
+            "#,
+            token_id,
+            user_id,
+            role
+        )
+        .fetch_one(&self.pool)
+        .await
+        .context("an unexpected error occurred while checking for revoked access token")?
+        .revoked;
+
+        Ok(revoked)
+    }
+
+    async fn create_login_challenge(
+        &self,
+        user_id: i64,
+        token_hash: String,
+        expires_at: OffsetDateTime,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        insert into login_challenges (user_id, token_hash, expires_at, created_at)
+        values ($1::bigint, $2::varchar, $3::timestamptz, current_timestamp)
+            "#,
+            user_id,
+            token_hash,
+            expires_at
+        )
+        .execute(&self.pool)
+        .await
+        .context("could not create the login challenge")?;
+
+        Ok(())
+    }
+
+    async fn get_login_challenge(&self, token_hash: String) -> anyhow::Result<Option<LoginChallengeEntity>> {
+        query_as!(
+            LoginChallengeEntity,
+            r#"
+        select *
+        from login_challenges
+        where token_hash = $1::varchar
+            "#,
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("could not retrieve the login challenge")
+    }
+
+    async fn use_login_challenge(&self, unit_of_work: &mut DynUnitOfWork, id: i64) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+        update login_challenges
+        set used_at = current_timestamp
+        where id = $1
+        and used_at is null
+            "#,
+            id
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not complete the login challenge")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn record_login_challenge_attempt(&self, id: i64) -> anyhow::Result<i32> {
+        let attempts = query!(
+            r#"
+        update login_challenges
+        set attempts = attempts + 1
+        where id = $1
+        returning attempts
+            "#,
+            id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .context("could not record the login challenge attempt")?
+        .attempts;
+
+        Ok(attempts)
+    }
+
+    async fn get_login_lockout(&self, keys: Vec<String>) -> anyhow::Result<Option<OffsetDateTime>> {
+        let locked_until = query!(
+            r#"
+        select max(locked_until) as locked_until
+        from login_throttles
+        where key = any($1::varchar[])
+        and locked_until > current_timestamp
+            "#,
+            &keys
+        )
+        .fetch_one(&self.pool)
+        .await
+        .context("could not retrieve login lockouts")?
+        .locked_until;
+
+        Ok(locked_until)
+    }
+
+    async fn record_login_failure(&self, key: String, window_minutes: i64) -> anyhow::Result<i32> {
+        let failures = query!(
+            r#"
+        insert into login_throttles (key, failures, last_failure_at)
+        values ($1::varchar, 1, current_timestamp)
+        on conflict (key) do update
+        set
+            failures = case
+                when login_throttles.last_failure_at < current_timestamp - make_interval(mins => $2::integer) then 1
+                else login_throttles.failures + 1
+            end,
+            last_failure_at = current_timestamp
+        returning failures
+            "#,
+            key,
+            window_minutes as i32
+        )
+        .fetch_one(&self.pool)
+        .await
+        .context("could not record the failed login")?
+        .failures;
+
+        Ok(failures)
+    }
+
+    async fn lock_login(&self, key: String, locked_until: OffsetDateTime) -> anyhow::Result<()> {
+        query!(
+            r#"
+        update login_throttles
+        set locked_until = $1::timestamptz
+        where key = $2::varchar
+            "#,
+            locked_until,
+            key
+        )
+        .execute(&self.pool)
+        .await
+        .context("could not lock the login")?;
+
+        Ok(())
+    }
+
+    async fn clear_login_failures(&self, key: String) -> anyhow::Result<()> {
+        query!(
+            r#"
+        delete from login_throttles
+        where key = $1::varchar
+            "#,
+            key
+        )
+        .execute(&self.pool)
+        .await
+        .context("could not clear the failed logins")?;
+
+        Ok(())
+    }
+}
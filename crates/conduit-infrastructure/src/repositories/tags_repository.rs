@@ -2,11 +2,13 @@ use anyhow::Context;
 use async_trait::async_trait;
 use lazy_static::lazy_static;
 use sqlx::postgres::PgRow;
-use sqlx::{query_as, QueryBuilder, Row};
+use sqlx::{query, query_as, QueryBuilder, Row};
 
 use conduit_core::tags::repository::{ArticleTagQuery, TagEntity, TagsRepository};
+use conduit_core::utils::unit_of_work::DynUnitOfWork;
 
 use crate::connection_pool::ConduitConnectionPool;
+use crate::unit_of_work::PostgresUnitOfWork;
 
 lazy_static! {
     static ref PG_CURRENT_TIMESTAMP: &'static str = "current_timestamp";
@@ -45,7 +47,7 @@ impl TagsRepository for PostgresTagsRepository {
             .context("an unexpected error occurred while retrieving tags")
     }
 
-    async fn create_tags(&self, tags: Vec<String>) -> anyhow::Result<Vec<TagEntity>> {
+    async fn create_tags(&self, unit_of_work: &mut DynUnitOfWork, tags: Vec<String>) -> anyhow::Result<Vec<TagEntity>> {
         let mut query_builder = QueryBuilder::new("insert into tags (tag, created_at) ");
 
         query_builder.push_values(tags, |mut builder, tag| {
@@ -60,7 +62,7 @@ impl TagsRepository for PostgresTagsRepository {
                 tag: row.get(1),
                 created_at: row.get(2),
             })
-            .fetch_all(&self.pool)
+            .fetch_all(PostgresUnitOfWork::transaction(unit_of_work)?)
             .await
             .context("an unexpected error occurred while creating article tags")
     }
@@ -74,7 +76,7 @@ impl TagsRepository for PostgresTagsRepository {
                at.tag_id,
                t.tag
         from article_tags at
-        join tags t on t.id = at.article_id
+        join tags t on t.id = at.tag_id
         where article_id = $1
         order by t.tag
             "#,
@@ -105,7 +107,7 @@ impl TagsRepository for PostgresTagsRepository {
         .context("an unexpected error occurred while retrieving tags")
     }
 
-    async fn create_article_tags(&self, tags: Vec<(i64, i64)>) -> anyhow::Result<()> {
+    async fn create_article_tags(&self, unit_of_work: &mut DynUnitOfWork, tags: Vec<(i64, i64)>) -> anyhow::Result<()> {
         let mut query_builder = QueryBuilder::new("insert into article_tags (tag_id, article_id, created_at) ");
 
         query_builder.push_values(tags, |mut builder, (tag_id, article_id)| {
@@ -117,10 +119,53 @@ impl TagsRepository for PostgresTagsRepository {
 
         query_builder
             .build()
-            .execute(&self.pool)
+            .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
             .await
             .context("an unexpected error occurred while creating article tags")?;
 
         Ok(())
     }
+
+    async fn delete_article_tags(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        article_id: i64,
+        tag_ids: Vec<i64>,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        delete from article_tags
+        where article_id = $1
+          and tag_id = any($2)
+            "#,
+            article_id,
+            tag_ids.as_slice()
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while removing article tags")?;
+
+        Ok(())
+    }
+
+    async fn delete_unreferenced_tags(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        tag_ids: Vec<i64>,
+    ) -> anyhow::Result<u64> {
+        let deleted_tags = query!(
+            r#"
+        delete from tags t
+        where t.id = any($1)
+          and not exists(select 1 from article_tags at where at.tag_id = t.id)
+            "#,
+            tag_ids.as_slice()
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("an unexpected error occurred while removing unreferenced tags")?
+        .rows_affected();
+
+        Ok(deleted_tags)
+    }
 }
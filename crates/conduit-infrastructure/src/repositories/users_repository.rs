@@ -1,10 +1,13 @@
 use anyhow::Context;
 use async_trait::async_trait;
-use sqlx::query_as;
+use sqlx::types::time::OffsetDateTime;
+use sqlx::{query, query_as};
 
-use conduit_core::users::repository::{UserEntity, UsersRepository};
+use conduit_core::users::repository::{EmailVerificationTokenEntity, PasswordResetTokenEntity, UserEntity, UsersRepository};
+use conduit_core::utils::unit_of_work::DynUnitOfWork;
 
 use crate::connection_pool::ConduitConnectionPool;
+use crate::unit_of_work::PostgresUnitOfWork;
 
 #[derive(Clone)]
 pub struct PostgresUsersRepository {
@@ -34,7 +37,13 @@ impl UsersRepository for PostgresUsersRepository {
                email,
                password,
                bio,
-               image
+               image,
+               verified_at,
+               role,
+               suspended_at,
+               totp_secret,
+               totp_enabled_at,
+               totp_last_used_step
         from users
         where email = $1::varchar
         or username = $2::varchar"#,
@@ -46,7 +55,13 @@ impl UsersRepository for PostgresUsersRepository {
         .context("an unexpected error occured while search for users")
     }
 
-    async fn create_user(&self, email: &str, username: &str, hashed_password: &str) -> anyhow::Result<UserEntity> {
+    async fn create_user(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        email: &str,
+        username: &str,
+        hashed_password: &str,
+    ) -> anyhow::Result<UserEntity> {
         query_as!(
             UserEntity,
             r#"
@@ -58,7 +73,7 @@ impl UsersRepository for PostgresUsersRepository {
             email,
             hashed_password
         )
-        .fetch_one(&self.pool)
+        .fetch_one(PostgresUnitOfWork::transaction(unit_of_work)?)
         .await
         .context("an unexpected error occured while creating the user")
     }
@@ -110,6 +125,7 @@ impl UsersRepository for PostgresUsersRepository {
 
     async fn update_user(
         &self,
+        unit_of_work: &mut DynUnitOfWork,
         id: i64,
         email: String,
         username: String,
@@ -127,6 +143,7 @@ impl UsersRepository for PostgresUsersRepository {
             password = $3::varchar,
             bio = $4::varchar,
             image = $5::varchar,
+            verified_at = case when email = $2::varchar then verified_at end,
             updated_at = current_timestamp
         where id = $6
         returning *
@@ -138,8 +155,420 @@ impl UsersRepository for PostgresUsersRepository {
             image,
             id
         )
-        .fetch_one(&self.pool)
+        .fetch_one(PostgresUnitOfWork::transaction(unit_of_work)?)
         .await
         .context("could not update the user")
     }
+
+    async fn update_user_password(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        id: i64,
+        hashed_password: String,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        update users
+        set
+            password = $1::varchar,
+            updated_at = current_timestamp
+        where id = $2
+            "#,
+            hashed_password,
+            id
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not update the user password")?;
+
+        Ok(())
+    }
+
+    async fn create_password_reset_token(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        token_hash: String,
+        expires_at: OffsetDateTime,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        insert into password_reset_tokens (user_id, token_hash, expires_at, created_at)
+        values ($1::bigint, $2::varchar, $3::timestamptz, current_timestamp)
+            "#,
+            user_id,
+            token_hash,
+            expires_at
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not create the password reset token")?;
+
+        Ok(())
+    }
+
+    async fn get_password_reset_token(&self, token_hash: String) -> anyhow::Result<Option<PasswordResetTokenEntity>> {
+        query_as!(
+            PasswordResetTokenEntity,
+            r#"
+        select *
+        from password_reset_tokens
+        where token_hash = $1::varchar
+            "#,
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("could not retrieve the password reset token")
+    }
+
+    async fn use_password_reset_token(&self, unit_of_work: &mut DynUnitOfWork, id: i64) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+        update password_reset_tokens
+        set used_at = current_timestamp
+        where id = $1
+        and used_at is null
+            "#,
+            id
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not use the password reset token")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn update_user_role(&self, username: String, role: String) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+        update users
+        set
+            role = $1::varchar,
+            updated_at = current_timestamp
+        where username = $2::varchar
+            "#,
+            role,
+            username
+        )
+        .execute(&self.pool)
+        .await
+        .context("could not update the user role")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn get_users(&self, search: Option<String>, limit: i64, offset: i64) -> anyhow::Result<Vec<UserEntity>> {
+        query_as!(
+            UserEntity,
+            r#"
+        select *
+        from users
+        where $1::varchar is null
+        or username ilike '%' || $1::varchar || '%'
+        or email ilike '%' || $1::varchar || '%'
+        order by created_at desc, id desc
+        limit $2::integer offset $3::integer
+            "#,
+            search,
+            limit as i32,
+            offset as i32
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("an unexpected error occured while retrieving users")
+    }
+
+    async fn get_users_count(&self, search: Option<String>) -> anyhow::Result<i64> {
+        let users_count = query!(
+            r#"
+        select count(*) as "users_count!"
+        from users
+        where $1::varchar is null
+        or username ilike '%' || $1::varchar || '%'
+        or email ilike '%' || $1::varchar || '%'
+            "#,
+            search
+        )
+        .fetch_one(&self.pool)
+        .await
+        .context("an unexpected error occured while counting users")?
+        .users_count;
+
+        Ok(users_count)
+    }
+
+    async fn update_user_suspension(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        id: i64,
+        suspended: bool,
+    ) -> anyhow::Result<UserEntity> {
+        query_as!(
+            UserEntity,
+            r#"
+        update users
+        set
+            suspended_at = case when $1::boolean then coalesce(suspended_at, current_timestamp) end,
+            updated_at = current_timestamp
+        where id = $2
+        returning *
+            "#,
+            suspended,
+            id
+        )
+        .fetch_one(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not update the user suspension")
+    }
+
+    async fn delete_user(&self, id: i64) -> anyhow::Result<()> {
+        query!(
+            r#"
+        delete from users
+        where id = $1
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .context("could not delete the user")?;
+
+        Ok(())
+    }
+
+    async fn create_email_verification_token(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        email: String,
+        token_hash: String,
+        expires_at: OffsetDateTime,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        insert into email_verification_tokens (user_id, email, token_hash, expires_at, created_at)
+        values ($1::bigint, $2::varchar, $3::varchar, $4::timestamptz, current_timestamp)
+            "#,
+            user_id,
+            email,
+            token_hash,
+            expires_at
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not create the email verification token")?;
+
+        Ok(())
+    }
+
+    async fn get_email_verification_token(
+        &self,
+        token_hash: String,
+    ) -> anyhow::Result<Option<EmailVerificationTokenEntity>> {
+        query_as!(
+            EmailVerificationTokenEntity,
+            r#"
+        select *
+        from email_verification_tokens
+        where token_hash = $1::varchar
+            "#,
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("could not retrieve the email verification token")
+    }
+
+    async fn use_email_verification_token(&self, unit_of_work: &mut DynUnitOfWork, id: i64) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+        update email_verification_tokens
+        set used_at = current_timestamp
+        where id = $1
+        and used_at is null
+            "#,
+            id
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not use the email verification token")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn verify_user_email(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        email: String,
+    ) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+        update users
+        set
+            verified_at = coalesce(verified_at, current_timestamp),
+            updated_at = current_timestamp
+        where id = $1
+        and email = $2::varchar
+            "#,
+            user_id,
+            email
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not verify the user email")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn update_user_totp_secret(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        id: i64,
+        totp_secret: Option<String>,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        update users
+        set
+            totp_secret = $1::varchar,
+            totp_enabled_at = null,
+            totp_last_used_step = null,
+            updated_at = current_timestamp
+        where id = $2
+            "#,
+            totp_secret,
+            id
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not update the user TOTP secret")?;
+
+        Ok(())
+    }
+
+    async fn enable_user_totp(&self, unit_of_work: &mut DynUnitOfWork, id: i64) -> anyhow::Result<()> {
+        query!(
+            r#"
+        update users
+        set
+            totp_enabled_at = current_timestamp,
+            updated_at = current_timestamp
+        where id = $1
+        and totp_secret is not null
+            "#,
+            id
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not enable TOTP for the user")?;
+
+        Ok(())
+    }
+
+    async fn update_user_totp_last_used_step(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        id: i64,
+        step: i64,
+    ) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+        update users
+        set totp_last_used_step = $1::bigint
+        where id = $2
+        and (totp_last_used_step is null or totp_last_used_step < $1::bigint)
+            "#,
+            step,
+            id
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not record the used TOTP step")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn create_recovery_codes(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        code_hashes: Vec<String>,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        insert into recovery_codes (user_id, code_hash, created_at)
+        select $1::bigint, code_hash, current_timestamp
+        from unnest($2::varchar[]) as code_hash
+            "#,
+            user_id,
+            &code_hashes
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not create the recovery codes")?;
+
+        Ok(())
+    }
+
+    async fn delete_recovery_codes(&self, unit_of_work: &mut DynUnitOfWork, user_id: i64) -> anyhow::Result<()> {
+        query!(
+            r#"
+        delete from recovery_codes
+        where user_id = $1
+            "#,
+            user_id
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not delete the recovery codes")?;
+
+        Ok(())
+    }
+
+    async fn use_recovery_code(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        code_hash: String,
+    ) -> anyhow::Result<bool> {
+        let result = query!(
+            r#"
+        update recovery_codes
+        set used_at = current_timestamp
+        where user_id = $1
+        and code_hash = $2::varchar
+        and used_at is null
+            "#,
+            user_id,
+            code_hash
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not use the recovery code")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn invalidate_password_reset_tokens(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+    ) -> anyhow::Result<()> {
+        query!(
+            r#"
+        update password_reset_tokens
+        set used_at = current_timestamp
+        where user_id = $1
+        and used_at is null
+            "#,
+            user_id
+        )
+        .execute(PostgresUnitOfWork::transaction(unit_of_work)?)
+        .await
+        .context("could not invalidate the password reset tokens")?;
+
+        Ok(())
+    }
 }
@@ -2,42 +2,85 @@ use std::sync::Arc;
 
 use tracing::info;
 
+use conduit_core::access_tokens::repository::DynAccessTokensRepository;
+use conduit_core::access_tokens::service::DynAccessTokensService;
+use conduit_core::admin::service::DynAdminService;
 use conduit_core::articles::repository::DynArticlesRepository;
 use conduit_core::articles::service::DynArticlesService;
 use conduit_core::comments::repository::DynCommentsRepository;
 use conduit_core::comments::service::DynCommentsService;
 use conduit_core::config::AppConfig;
+use conduit_core::feeds::service::DynFeedsService;
+use conduit_core::mentions::repository::DynMentionsRepository;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::notifications::service::DynNotificationsService;
 use conduit_core::profiles::repository::DynProfilesRepository;
 use conduit_core::profiles::service::DynProfilesService;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::reactions::service::DynReactionsService;
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::sessions::service::DynSessionsService;
 use conduit_core::tags::repository::DynTagsRepository;
 use conduit_core::tags::service::DynTagsService;
+use conduit_core::trash::service::DynTrashService;
+use conduit_core::two_factor::service::DynTwoFactorService;
 use conduit_core::users::repository::DynUsersRepository;
 use conduit_core::users::service::DynUsersService;
+use conduit_core::utils::mail_service::DynMailService;
 use conduit_core::utils::security_service::DynSecurityService;
 use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::totp_service::DynTotpService;
+use conduit_core::utils::unit_of_work::DynUnitOfWorkFactory;
 
 use crate::connection_pool::ConduitConnectionPool;
+use crate::repositories::access_tokens_repository::PostgresAccessTokensRepository;
 use crate::repositories::articles_repository::PostgresArticlesRepository;
 use crate::repositories::comments_repository::PostgresCommentsRepository;
+use crate::repositories::mentions_repository::PostgresMentionsRepository;
+use crate::repositories::notifications_repository::PostgresNotificationsRepository;
 use crate::repositories::profiles_repository::PostgresProfilesRepository;
+use crate::repositories::reactions_repository::PostgresReactionsRepository;
+use crate::repositories::sessions_repository::PostgresSessionsRepository;
 use crate::repositories::tags_repository::PostgresTagsRepository;
 use crate::repositories::users_repository::PostgresUsersRepository;
+use crate::services::access_tokens_service::ConduitAccessTokensService;
+use crate::services::admin_service::ConduitAdminService;
 use crate::services::articles_service::ConduitArticlesService;
 use crate::services::comments_service::ConduitCommentsService;
+use crate::services::feeds_service::ConduitFeedsService;
+use crate::services::notifications_service::ConduitNotificationsService;
 use crate::services::profiles_service::ConduitProfilesService;
+use crate::services::reactions_service::ConduitReactionsService;
+use crate::services::sessions_service::ConduitSessionsService;
 use crate::services::tags_service::ConduitTagsService;
+use crate::services::trash_service::ConduitTrashService;
+use crate::services::two_factor_service::ConduitTwoFactorService;
 use crate::services::users_service::ConduitUsersService;
 use crate::services::utils::argon_security_service::ArgonSecurityService;
+use crate::services::utils::file_mail_service::FileMailService;
+use crate::services::utils::hmac_totp_service::HmacTotpService;
 use crate::services::utils::jwt_service::JwtService;
+use crate::services::utils::mentions_tracker::MentionsTracker;
+use crate::services::utils::smtp_mail_service::SmtpMailService;
+use crate::unit_of_work::PostgresUnitOfWorkFactory;
 
 #[derive(Clone)]
 pub struct ServiceRegister {
+    pub config: Arc<AppConfig>,
     pub users_service: DynUsersService,
     pub token_service: DynTokenService,
+    pub sessions_service: DynSessionsService,
     pub profiles_service: DynProfilesService,
     pub articles_service: DynArticlesService,
     pub comments_service: DynCommentsService,
     pub tags_service: DynTagsService,
+    pub admin_service: DynAdminService,
+    pub access_tokens_service: DynAccessTokensService,
+    pub two_factor_service: DynTwoFactorService,
+    pub trash_service: DynTrashService,
+    pub feeds_service: DynFeedsService,
+    pub reactions_service: DynReactionsService,
+    pub notifications_service: DynNotificationsService,
 }
 
 /// A simple service container responsible for managing the various services our API endpoints will pull from through axum extensions.
@@ -45,16 +88,63 @@ impl ServiceRegister {
     pub fn new(pool: ConduitConnectionPool, config: Arc<AppConfig>) -> Self {
         info!("initializing utility services...");
         let security_service = Arc::new(ArgonSecurityService::new(config.clone())) as DynSecurityService;
-        let token_service = Arc::new(JwtService::new(config)) as DynTokenService;
+        let token_service = Arc::new(JwtService::new(config.clone())) as DynTokenService;
+        let totp_service = Arc::new(HmacTotpService::new(config.clone())) as DynTotpService;
+        let unit_of_work_factory = Arc::new(PostgresUnitOfWorkFactory::new(pool.clone())) as DynUnitOfWorkFactory;
+        let mail_service = match config.mail_transport.as_str() {
+            "smtp" => {
+                Arc::new(SmtpMailService::new(config.clone()).expect("could not initialize the SMTP mail service"))
+                    as DynMailService
+            },
+            _ => Arc::new(FileMailService::new(config.clone())) as DynMailService,
+        };
 
         info!("utility services initialized, building feature services...");
         let users_repository = Arc::new(PostgresUsersRepository::new(pool.clone())) as DynUsersRepository;
+        let sessions_repository = Arc::new(PostgresSessionsRepository::new(pool.clone())) as DynSessionsRepository;
         let users_service = Arc::new(ConduitUsersService::new(
             users_repository.clone(),
-            security_service,
+            sessions_repository.clone(),
+            security_service.clone(),
             token_service.clone(),
+            mail_service,
+            unit_of_work_factory.clone(),
+            config.clone(),
         )) as DynUsersService;
 
+        let admin_service = Arc::new(ConduitAdminService::new(
+            users_repository.clone(),
+            sessions_repository.clone(),
+            users_service.clone(),
+            security_service,
+            token_service.clone(),
+            unit_of_work_factory.clone(),
+        )) as DynAdminService;
+
+        let two_factor_service = Arc::new(ConduitTwoFactorService::new(
+            users_repository.clone(),
+            sessions_repository.clone(),
+            totp_service,
+            token_service.clone(),
+            unit_of_work_factory.clone(),
+        )) as DynTwoFactorService;
+
+        let sessions_service = Arc::new(ConduitSessionsService::new(
+            sessions_repository,
+            users_repository.clone(),
+            token_service.clone(),
+            unit_of_work_factory.clone(),
+            config.clone(),
+        )) as DynSessionsService;
+
+        let access_tokens_repository =
+            Arc::new(PostgresAccessTokensRepository::new(pool.clone())) as DynAccessTokensRepository;
+        let access_tokens_service = Arc::new(ConduitAccessTokensService::new(
+            access_tokens_repository,
+            users_repository.clone(),
+            token_service.clone(),
+        )) as DynAccessTokensService;
+
         let profiles_repository = Arc::new(PostgresProfilesRepository::new(pool.clone())) as DynProfilesRepository;
         let profiles_service = Arc::new(ConduitProfilesService::new(
             users_repository.clone(),
@@ -64,25 +154,75 @@ impl ServiceRegister {
         let tags_repository = Arc::new(PostgresTagsRepository::new(pool.clone())) as DynTagsRepository;
         let tags_service = Arc::new(ConduitTagsService::new(tags_repository.clone())) as DynTagsService;
 
+        let reactions_repository = Arc::new(PostgresReactionsRepository::new(pool.clone())) as DynReactionsRepository;
+        let mentions_repository = Arc::new(PostgresMentionsRepository::new(pool.clone())) as DynMentionsRepository;
+        let notifications_repository =
+            Arc::new(PostgresNotificationsRepository::new(pool.clone())) as DynNotificationsRepository;
+
         let articles_repository = Arc::new(PostgresArticlesRepository::new(pool.clone())) as DynArticlesRepository;
         let articles_service = Arc::new(ConduitArticlesService::new(
             articles_repository.clone(),
-            tags_repository,
+            tags_repository.clone(),
+            reactions_repository.clone(),
+            MentionsTracker::new(
+                users_repository.clone(),
+                mentions_repository.clone(),
+                notifications_repository.clone(),
+            ),
+            unit_of_work_factory.clone(),
         )) as DynArticlesService;
 
+        let feeds_service = Arc::new(ConduitFeedsService::new(
+            articles_repository.clone(),
+            tags_repository,
+            config.clone(),
+        )) as DynFeedsService;
+
         let comments_repository = Arc::new(PostgresCommentsRepository::new(pool)) as DynCommentsRepository;
-        let comments_service =
-            Arc::new(ConduitCommentsService::new(comments_repository, articles_repository)) as DynCommentsService;
+        let comments_service = Arc::new(ConduitCommentsService::new(
+            comments_repository.clone(),
+            articles_repository.clone(),
+            reactions_repository.clone(),
+            MentionsTracker::new(users_repository, mentions_repository, notifications_repository.clone()),
+            unit_of_work_factory.clone(),
+            config.clone(),
+        )) as DynCommentsService;
+
+        let reactions_service = Arc::new(ConduitReactionsService::new(
+            reactions_repository,
+            articles_repository.clone(),
+            comments_repository.clone(),
+            unit_of_work_factory,
+            config.clone(),
+        )) as DynReactionsService;
+
+        let trash_service = Arc::new(ConduitTrashService::new(
+            articles_repository,
+            comments_repository,
+            config.clone(),
+        )) as DynTrashService;
+
+        let notifications_service =
+            Arc::new(ConduitNotificationsService::new(notifications_repository)) as DynNotificationsService;
 
         info!("feature services successfully initialized!");
 
         ServiceRegister {
+            config,
             users_service,
             token_service,
+            sessions_service,
             profiles_service,
             articles_service,
             comments_service,
             tags_service,
+            admin_service,
+            access_tokens_service,
+            two_factor_service,
+            trash_service,
+            feeds_service,
+            reactions_service,
+            notifications_service,
         }
     }
 }
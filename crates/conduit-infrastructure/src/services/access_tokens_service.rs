@@ -0,0 +1,146 @@
+use std::ops::Add;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
+use tracing::{error, info};
+
+use conduit_core::access_tokens::repository::DynAccessTokensRepository;
+use conduit_core::access_tokens::scopes::Scope;
+use conduit_core::access_tokens::service::{AccessTokensService, ACCESS_TOKEN_PREFIX};
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::utils::token_service::{DynTokenService, TokenClaims};
+use conduit_domain::access_tokens::requests::CreateAccessTokenDto;
+use conduit_domain::access_tokens::AccessTokenDto;
+
+#[derive(Clone)]
+pub struct ConduitAccessTokensService {
+    repository: DynAccessTokensRepository,
+    users_repository: DynUsersRepository,
+    token_service: DynTokenService,
+}
+
+impl ConduitAccessTokensService {
+    pub fn new(
+        repository: DynAccessTokensRepository,
+        users_repository: DynUsersRepository,
+        token_service: DynTokenService,
+    ) -> Self {
+        Self {
+            repository,
+            users_repository,
+            token_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AccessTokensService for ConduitAccessTokensService {
+    async fn create_access_token(&self, user_id: i64, request: CreateAccessTokenDto) -> ConduitResult<AccessTokenDto> {
+        let name = request.name.unwrap();
+        let mut scopes = request
+            .scopes
+            .unwrap()
+            .iter()
+            .map(|scope| Scope::from_str(scope).map(|scope| scope.to_string()))
+            .collect::<Result<Vec<String>, String>>()
+            .map_err(ConduitError::BadRequest)?;
+
+        scopes.sort();
+        scopes.dedup();
+
+        let expires_at = request
+            .expires_in_days
+            .map(|days| OffsetDateTime::from(SystemTime::now().add(Duration::from_secs(days as u64 * 24 * 60 * 60))));
+
+        let token = format!("{}{}", ACCESS_TOKEN_PREFIX, self.token_service.new_opaque_token());
+
+        info!("creating personal access token {:?} for user {:?}", name, user_id);
+        let created_token = self
+            .repository
+            .create_access_token(
+                user_id,
+                name,
+                self.token_service.hash_opaque_token(&token),
+                scopes,
+                expires_at,
+            )
+            .await?;
+
+        Ok(created_token.into_dto(Some(token)))
+    }
+
+    async fn get_access_tokens(&self, user_id: i64) -> ConduitResult<Vec<AccessTokenDto>> {
+        info!("retrieving personal access tokens for user {:?}", user_id);
+        let tokens = self
+            .repository
+            .get_user_access_tokens(user_id)
+            .await?
+            .into_iter()
+            .map(|token| token.into_dto(None))
+            .collect();
+
+        Ok(tokens)
+    }
+
+    async fn revoke_access_token(&self, user_id: i64, id: i64) -> ConduitResult<()> {
+        info!("revoking personal access token {:?} for user {:?}", id, user_id);
+        let deleted = self.repository.delete_access_token(user_id, id).await?;
+
+        if !deleted {
+            return Err(ConduitError::NotFound(String::from(
+                "personal access token was not found",
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn get_claims_from_access_token(&self, token: String) -> ConduitResult<TokenClaims> {
+        let token_hash = self.token_service.hash_opaque_token(&token);
+        let existing_token = self.repository.get_access_token(token_hash).await?;
+
+        if existing_token.is_none() {
+            error!("personal access token was not found");
+            return Err(ConduitError::Unauthorized);
+        }
+
+        let access_token = existing_token.unwrap();
+
+        if matches!(access_token.expires_at, Some(expires_at) if expires_at < OffsetDateTime::now_utc()) {
+            error!("personal access token {:?} has expired", access_token.id);
+            return Err(ConduitError::Unauthorized);
+        }
+
+        let user = self.users_repository.get_user_by_id(access_token.user_id).await?;
+
+        if user.suspended_at.is_some() {
+            error!(
+                "personal access token {:?} belongs to a suspended user",
+                access_token.id
+            );
+            return Err(ConduitError::Unauthorized);
+        }
+
+        self.repository.update_access_token_last_used(access_token.id).await?;
+
+        Ok(TokenClaims {
+            user_id: user.id,
+            token_id: format!("{}{}", ACCESS_TOKEN_PREFIX, access_token.id),
+            expires_at: access_token
+                .expires_at
+                .map(|expires_at| expires_at.unix_timestamp())
+                .unwrap_or(i64::MAX),
+            role: user.role(),
+            scopes: Some(
+                access_token
+                    .scopes
+                    .iter()
+                    .filter_map(|scope| Scope::from_str(scope).ok())
+                    .collect(),
+            ),
+        })
+    }
+}
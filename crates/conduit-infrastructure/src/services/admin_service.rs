@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use tracing::{error, info};
+
+use conduit_core::admin::service::AdminService;
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::users::roles::Role;
+use conduit_core::users::service::DynUsersService;
+use conduit_core::utils::security_service::DynSecurityService;
+use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::unit_of_work::DynUnitOfWorkFactory;
+use conduit_domain::admin::requests::GetUsersServiceRequest;
+use conduit_domain::admin::AdminUserDto;
+
+#[derive(Clone)]
+pub struct ConduitAdminService {
+    users_repository: DynUsersRepository,
+    sessions_repository: DynSessionsRepository,
+    users_service: DynUsersService,
+    security_service: DynSecurityService,
+    token_service: DynTokenService,
+    unit_of_work_factory: DynUnitOfWorkFactory,
+}
+
+impl ConduitAdminService {
+    pub fn new(
+        users_repository: DynUsersRepository,
+        sessions_repository: DynSessionsRepository,
+        users_service: DynUsersService,
+        security_service: DynSecurityService,
+        token_service: DynTokenService,
+        unit_of_work_factory: DynUnitOfWorkFactory,
+    ) -> Self {
+        Self {
+            users_repository,
+            sessions_repository,
+            users_service,
+            security_service,
+            token_service,
+            unit_of_work_factory,
+        }
+    }
+
+    async fn get_user(&self, username: &str) -> ConduitResult<UserEntity> {
+        self.users_repository
+            .get_user_by_username(username)
+            .await?
+            .ok_or_else(|| ConduitError::NotFound(String::from("user was not found")))
+    }
+
+    async fn get_other_user(&self, admin_id: i64, username: &str) -> ConduitResult<UserEntity> {
+        let user = self.get_user(username).await?;
+
+        // admins locking themselves out would leave no one able to undo it
+        if user.id == admin_id {
+            error!("admin {:?} attempted to manage their own account", admin_id);
+            return Err(ConduitError::BadRequest(String::from(
+                "administrators cannot perform this action on their own account",
+            )));
+        }
+
+        Ok(user)
+    }
+
+    async fn update_user_suspension(&self, user: UserEntity, suspended: bool) -> ConduitResult<AdminUserDto> {
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let updated_user = self
+            .users_repository
+            .update_user_suspension(unit_of_work.as_mut(), user.id, suspended)
+            .await?;
+
+        if suspended {
+            self.sessions_repository
+                .revoke_user_refresh_tokens(unit_of_work.as_mut(), user.id)
+                .await?;
+        }
+
+        unit_of_work.commit().await?;
+
+        Ok(updated_user.into_admin_dto())
+    }
+}
+
+#[async_trait]
+impl AdminService for ConduitAdminService {
+    async fn get_users(&self, request: GetUsersServiceRequest) -> ConduitResult<(Vec<AdminUserDto>, i64)> {
+        info!("retrieving users for search {:?}", request.search);
+        let users = self
+            .users_repository
+            .get_users(request.search.clone(), request.limit, request.offset)
+            .await?;
+
+        let users_count = self.users_repository.get_users_count(request.search).await?;
+
+        info!("found {:?} of {:?} users", users.len(), users_count);
+        let mapped_users = users.into_iter().map(|user| user.into_admin_dto()).collect();
+
+        Ok((mapped_users, users_count))
+    }
+
+    async fn suspend_user(&self, admin_id: i64, username: String) -> ConduitResult<AdminUserDto> {
+        let user = self.get_other_user(admin_id, &username).await?;
+
+        info!("admin {:?} suspending user {:?}", admin_id, user.id);
+        self.update_user_suspension(user, true).await
+    }
+
+    async fn unsuspend_user(&self, username: String) -> ConduitResult<AdminUserDto> {
+        let user = self.get_user(&username).await?;
+
+        info!("lifting suspension for user {:?}", user.id);
+        self.update_user_suspension(user, false).await
+    }
+
+    async fn update_user_role(&self, admin_id: i64, username: String, role: Role) -> ConduitResult<()> {
+        let user = self.get_other_user(admin_id, &username).await?;
+
+        info!("admin {:?} assigning role {:?} to user {:?}", admin_id, role, user.id);
+        self.users_service.update_user_role(user.username, role).await
+    }
+
+    async fn force_password_reset(&self, admin_id: i64, username: String) -> ConduitResult<()> {
+        let user = self.get_other_user(admin_id, &username).await?;
+
+        // nobody knows the opaque token, so the existing password stops working until the user completes the reset
+        info!("admin {:?} forcing a password reset for user {:?}", admin_id, user.id);
+        let scrambled_password = self
+            .security_service
+            .hash_password(&self.token_service.new_opaque_token())?;
+
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        self.users_repository
+            .update_user_password(unit_of_work.as_mut(), user.id, scrambled_password)
+            .await?;
+
+        self.sessions_repository
+            .revoke_user_refresh_tokens(unit_of_work.as_mut(), user.id)
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        self.users_service.request_password_reset(user.email).await
+    }
+
+    async fn delete_user(&self, admin_id: i64, username: String) -> ConduitResult<()> {
+        let user = self.get_other_user(admin_id, &username).await?;
+
+        info!("admin {:?} deleting user {:?}", admin_id, user.id);
+        self.users_repository.delete_user(user.id).await?;
+
+        Ok(())
+    }
+}
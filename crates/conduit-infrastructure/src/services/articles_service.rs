@@ -1,136 +1,153 @@
+use std::str::FromStr;
+
 use async_trait::async_trait;
 use itertools::Itertools;
+use similar::TextDiff;
 use slug::slugify;
+use sqlx::types::time::OffsetDateTime;
 use tracing::info;
 
-use conduit_core::articles::repository::{DynArticlesRepository, GetArticleQuery};
+use conduit_core::articles::cursor::ArticleCursor;
+use conduit_core::articles::repository::{ArticleRevisionEntity, DynArticlesRepository, GetArticleQuery, NewArticle};
 use conduit_core::articles::service::ArticlesService;
+use conduit_core::articles::slug::next_available_slug;
+use conduit_core::articles::status::{parse_publish_at, ArticleStatus};
 use conduit_core::errors::{ConduitError, ConduitResult};
-use conduit_core::tags::repository::DynTagsRepository;
-use conduit_domain::articles::models::ArticleDto;
+use conduit_core::reactions::collect_reactions;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::tags::repository::{DynTagsRepository, TagEntity};
+use conduit_core::users::roles::{Permission, Role};
+use conduit_core::utils::unit_of_work::{DynUnitOfWork, DynUnitOfWorkFactory};
+use conduit_domain::articles::models::{
+    ArticleDto, ArticleRevisionDiffDto, ArticleRevisionDto, CreateArticleDto, UpdateArticleDto,
+};
+use conduit_domain::articles::requests::GetArticlesServiceRequest;
+use conduit_domain::articles::responses::ArticlesResponse;
+
+use crate::services::utils::markdown::{render_markdown, render_snippet};
+use crate::services::utils::mentions_tracker::MentionsTracker;
 
 pub struct ConduitArticlesService {
     articles_repository: DynArticlesRepository,
     tags_repository: DynTagsRepository,
+    reactions_repository: DynReactionsRepository,
+    mentions_tracker: MentionsTracker,
+    unit_of_work_factory: DynUnitOfWorkFactory,
 }
 
 impl ConduitArticlesService {
-    pub fn new(articles_repository: DynArticlesRepository, tags_repository: DynTagsRepository) -> Self {
+    pub fn new(
+        articles_repository: DynArticlesRepository,
+        tags_repository: DynTagsRepository,
+        reactions_repository: DynReactionsRepository,
+        mentions_tracker: MentionsTracker,
+        unit_of_work_factory: DynUnitOfWorkFactory,
+    ) -> Self {
         Self {
             articles_repository,
             tags_repository,
+            reactions_repository,
+            mentions_tracker,
+            unit_of_work_factory,
         }
     }
 }
 
 #[async_trait]
 impl ArticlesService for ConduitArticlesService {
-    /// TODO: this flow should _really_ be handled within a transaction
-    /// this is a lot harder to do than expected while hiding transaction details within
-    /// the repositories themselves so our services can still maintain ease of testability
-    /// see other other branches for their attempts, but I would _like_ to surround the repositories
-    /// with a context/unit of work orchestrator but it seems much more complicated than anticipated
-    async fn create_article(
-        &self,
-        user_id: i64,
-        title: String,
-        description: String,
-        body: String,
-        tag_list: Vec<String>,
-    ) -> ConduitResult<ArticleDto> {
-        let slug = slugify(&title);
+    async fn create_article(&self, user_id: i64, request: CreateArticleDto) -> ConduitResult<ArticleDto> {
+        let title = request.title.unwrap();
+        let description = request.description.unwrap();
+        let body = request.body.unwrap();
 
-        // TODO: remove this, just create the article and slap a GUID or something on the slug regardless of it it exists or not
-        // verify an existing article does not exist with the request title
-        let article_title_exists = self
-            .articles_repository
-            .get_article_by_slug(None, slug.clone())
-            .await?
-            .is_some();
+        let mut status = match request.status {
+            Some(status) => ArticleStatus::from_str(&status).map_err(ConduitError::BadRequest)?,
+            None => ArticleStatus::Published,
+        };
 
-        if article_title_exists {
-            return Err(ConduitError::ObjectConflict(String::from(
-                "article with that title exists",
-            )));
+        let publish_at = request.publish_at.as_deref().map(validate_publish_at).transpose()?;
+
+        // scheduled articles are held back as drafts until the scheduler publishes them
+        if publish_at.is_some() && status == ArticleStatus::Published {
+            status = ArticleStatus::Draft;
         }
 
         // collect a unique list of the article tags to create
-        let deduped_tag_list = tag_list.into_iter().unique().collect_vec();
+        let deduped_tag_list = request.tag_list.into_iter().unique().collect_vec();
 
-        // search for existing tags, as we want to create a new tag if the request contains a tag that doesn't exist in the database
-        let existing_tags = self
-            .tags_repository
-            .get_tags(deduped_tag_list.clone())
-            .await?
-            .into_iter()
-            .map(|tag| tag.tag)
-            .collect_vec();
+        // the article, its new tags and the related article tags are all created within the same transaction,
+        // so a failure at any step rolls back the entire unit of work when it's dropped
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
 
-        let mut tags_to_create: Vec<String> = Vec::new();
-
-        // roll through the tags on request, adding any that do not yet exist in the tags table
-        for tag in deduped_tag_list.clone() {
-            if !existing_tags.contains(&tag) {
-                tags_to_create.push(tag);
-            }
-        }
+        // titles need not be unique, so articles sharing a title are told apart by a numeric suffix on their slug
+        let slug = self.generate_unique_slug(unit_of_work.as_mut(), &title, None).await?;
 
         // create the article so we can reference the created article tags
         let created_article = self
             .articles_repository
-            .create_article(user_id, title, slug, description, body)
+            .create_article(
+                unit_of_work.as_mut(),
+                NewArticle {
+                    user_id,
+                    title,
+                    slug,
+                    description,
+                    body,
+                    status,
+                    publish_at,
+                },
+            )
             .await?;
 
-        // if we detect new tags, create them
-        if !tags_to_create.is_empty() {
-            self.tags_repository.create_tags(tags_to_create).await?;
-        }
+        // the article's original content is its first revision
+        self.articles_repository
+            .create_article_revision(unit_of_work.as_mut(), created_article.id, user_id)
+            .await?;
 
-        // re-query the tags table to get all the existing tags with their associated IDs
-        // while mapping them into a tuple of tag IDs and article ID so we can create
-        // the related article tags for the article
-        let article_tags_to_create = self
-            .tags_repository
-            .get_tags(deduped_tag_list.clone())
-            .await?
-            .into_iter()
-            .map(|tag| (tag.id, created_article.id))
-            .collect_vec();
+        let mentions = self
+            .mentions_tracker
+            .record_mentions(
+                unit_of_work.as_mut(),
+                user_id,
+                created_article.id,
+                None,
+                &created_article.body,
+                status == ArticleStatus::Published,
+            )
+            .await?;
+
+        let article_tags = self
+            .get_or_create_tags(unit_of_work.as_mut(), deduped_tag_list.clone())
+            .await?;
+
+        // finally, map the tags into a tuple of tag IDs and article ID and create the related article tags
+        if !article_tags.is_empty() {
+            let article_tags_to_create = article_tags
+                .into_iter()
+                .map(|tag| (tag.id, created_article.id))
+                .collect_vec();
+
+            self.tags_repository
+                .create_article_tags(unit_of_work.as_mut(), article_tags_to_create)
+                .await?;
+        }
 
-        // finally, create the article tags
-        self.tags_repository.create_article_tags(article_tags_to_create).await?;
+        unit_of_work.commit().await?;
 
-        Ok(created_article.into_dto(deduped_tag_list))
+        Ok(ArticleDto {
+            mentions,
+            ..created_article.into_dto(deduped_tag_list)
+        })
     }
 
     async fn update_article(
         &self,
         user_id: i64,
+        role: Role,
         slug: String,
-        title: Option<String>,
-        description: Option<String>,
-        body: Option<String>,
+        request: UpdateArticleDto,
     ) -> ConduitResult<ArticleDto> {
-        // verify first that an article with the updated title does not already exists, as slugs are indexed
-        let mut updated_title: String = String::from("");
-
-        if let Some(new_title) = title {
-            updated_title = new_title;
-
-            info!("verifying article {:?} does not already exist", updated_title);
-
-            let article_with_updated_slug_exists = self
-                .articles_repository
-                .get_article_by_slug(None, slugify(&updated_title))
-                .await?
-                .is_some();
-
-            if article_with_updated_slug_exists {
-                return Err(ConduitError::ObjectConflict(String::from(
-                    "article with updated title already exists",
-                )));
-            }
-        }
+        let publish_at = request.publish_at.as_deref().map(validate_publish_at).transpose()?;
 
         info!("retrieving article {:?} for user {:?}", slug, user_id);
 
@@ -140,32 +157,103 @@ impl ArticlesService for ConduitArticlesService {
             .await?;
 
         if let Some(existing_article) = article_to_update {
-            // verify the user IDs match, we could also query for the article based on user ID and slug as well
-            if existing_article.user_id != user_id {
-                return Err(ConduitError::Unauthorized);
+            // only the author or a moderator may edit the article
+            if existing_article.user_id != user_id && !role.has_permission(Permission::ModerateContent) {
+                return Err(ConduitError::Forbidden);
             }
 
-            let updated_description = description.unwrap_or(existing_article.description);
-            let updated_body = body.unwrap_or(existing_article.body);
-
-            // if the slug has yet to be initialized, as the title was not updated, assign it back to the original slug
-            if updated_title.is_empty() {
-                updated_title = existing_article.slug;
+            // published articles are already live, so only unpublished articles may be scheduled
+            if publish_at.is_some() && existing_article.status == ArticleStatus::Published.as_str() {
+                return Err(ConduitError::BadRequest(String::from(
+                    "published articles cannot be scheduled",
+                )));
             }
 
-            let updated_slug = slugify(&updated_title);
+            let updated_description = request.description.unwrap_or(existing_article.description);
+            let is_body_updated = request.body.is_some();
+            let updated_body = request.body.unwrap_or(existing_article.body);
 
-            let updated_article = self
+            let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+            // the slug follows the title, though an unchanged title keeps the article at its current slug
+            let updated_slug = match &request.title {
+                Some(updated_title) if slugify(updated_title) != existing_article.slug => {
+                    self.generate_unique_slug(unit_of_work.as_mut(), updated_title, Some(existing_article.id))
+                        .await?
+                },
+                _ => existing_article.slug.clone(),
+            };
+
+            let updated_title = request.title.unwrap_or(existing_article.title);
+
+            let mut updated_article = self
                 .articles_repository
                 .update_article(
+                    unit_of_work.as_mut(),
                     existing_article.id,
                     updated_title,
-                    updated_slug,
+                    updated_slug.clone(),
                     updated_description,
                     updated_body,
                 )
                 .await?;
 
+            // links to the previous slug keep resolving to the article after it has been renamed
+            if updated_slug != existing_article.slug {
+                info!(
+                    "renaming article {:?} from {:?} to {:?}",
+                    existing_article.id, existing_article.slug, updated_slug
+                );
+                self.articles_repository
+                    .record_slug_change(
+                        unit_of_work.as_mut(),
+                        existing_article.id,
+                        existing_article.slug,
+                        updated_slug,
+                    )
+                    .await?;
+            }
+
+            if let Some(publish_at) = publish_at {
+                info!(
+                    "scheduling article {:?} to publish at {:?}",
+                    existing_article.id, publish_at
+                );
+                updated_article = self
+                    .articles_repository
+                    .update_article_publish_at(unit_of_work.as_mut(), existing_article.id, publish_at)
+                    .await?;
+            }
+
+            if let Some(tag_list) = request.tag_list {
+                self.replace_article_tags(unit_of_work.as_mut(), existing_article.id, tag_list)
+                    .await?;
+            }
+
+            // mentions only change along with the body, so users are not notified again when other fields are edited
+            let updated_mentions = if is_body_updated {
+                Some(
+                    self.mentions_tracker
+                        .record_mentions(
+                            unit_of_work.as_mut(),
+                            existing_article.user_id,
+                            existing_article.id,
+                            None,
+                            &updated_article.body,
+                            existing_article.status == ArticleStatus::Published.as_str(),
+                        )
+                        .await?,
+                )
+            } else {
+                None
+            };
+
+            self.articles_repository
+                .create_article_revision(unit_of_work.as_mut(), existing_article.id, user_id)
+                .await?;
+
+            unit_of_work.commit().await?;
+
             let article_tags = self
                 .tags_repository
                 .get_article_tags_by_article_id(updated_article.id)
@@ -174,7 +262,15 @@ impl ArticlesService for ConduitArticlesService {
                 .map(|tag| tag.tag)
                 .collect_vec();
 
-            return Ok(updated_article.into_dto(article_tags));
+            let mentions = match updated_mentions {
+                Some(mentions) => mentions,
+                None => self.mentions_tracker.get_article_mentions(updated_article.id).await?,
+            };
+
+            return Ok(ArticleDto {
+                mentions,
+                ..updated_article.into_dto(article_tags)
+            });
         }
 
         Err(ConduitError::NotFound(String::from("article not found")))
@@ -183,23 +279,28 @@ impl ArticlesService for ConduitArticlesService {
     async fn get_articles(
         &self,
         user_id: Option<i64>,
-        tag: Option<String>,
-        author: Option<String>,
-        favorited: Option<String>,
-        limit: i64,
-        offset: i64,
-    ) -> ConduitResult<Vec<ArticleDto>> {
-        let articles = self
-            .articles_repository
-            .get_articles(user_id, tag, author, favorited, limit, offset)
-            .await?;
-
-        self.map_to_articles(articles).await
+        request: GetArticlesServiceRequest,
+    ) -> ConduitResult<ArticlesResponse> {
+        self.get_articles_page(user_id, request).await
     }
 
     async fn get_article(&self, user_id: Option<i64>, slug: String) -> ConduitResult<ArticleDto> {
         info!("retrieving article {:?}", slug);
-        let article = self.articles_repository.get_article_by_slug(user_id, slug).await?;
+        let mut article = self
+            .articles_repository
+            .get_article_by_slug(user_id, slug.clone())
+            .await?;
+
+        // the slug may belong to an article that has since been renamed, in which case the article is found by its new slug
+        if article.is_none() {
+            if let Some(renamed_slug) = self.articles_repository.get_renamed_article_slug(slug).await? {
+                info!("retrieving renamed article {:?}", renamed_slug);
+                article = self
+                    .articles_repository
+                    .get_article_by_slug(user_id, renamed_slug)
+                    .await?;
+            }
+        }
 
         if let Some(existing_article) = article {
             info!("retrieving article tags for article {:?}", existing_article.id);
@@ -211,31 +312,281 @@ impl ArticlesService for ConduitArticlesService {
                 .map(|article_tag| article_tag.tag)
                 .collect_vec();
 
-            return Ok(existing_article.into_dto(article_tags));
+            let article_reactions = self
+                .reactions_repository
+                .get_article_reactions(user_id, vec![existing_article.id])
+                .await?;
+
+            let mentions = self.mentions_tracker.get_article_mentions(existing_article.id).await?;
+
+            let mut article = existing_article.into_dto(article_tags);
+            article.body_html = Some(render_markdown(&article.body));
+            article.mentions = mentions;
+            (article.reactions, article.my_reactions) = collect_reactions(article.id, &article_reactions);
+
+            return Ok(article);
         }
 
         Err(ConduitError::NotFound(String::from("article not found")))
     }
 
-    async fn get_feed(&self, user_id: i64, limit: i64, offset: i64) -> ConduitResult<Vec<ArticleDto>> {
-        let articles = self
+    async fn get_user_drafts(
+        &self,
+        user_id: i64,
+        request: GetArticlesServiceRequest,
+    ) -> ConduitResult<ArticlesResponse> {
+        let cursor = request.cursor.as_deref().map(ArticleCursor::decode).transpose()?;
+
+        info!("retrieving drafts count for user {:?}", user_id);
+        let drafts_count = self.articles_repository.get_user_drafts_count(user_id).await?;
+
+        info!("retrieving drafts for user {:?}", user_id);
+        let drafts = self
+            .articles_repository
+            .get_user_drafts(user_id, request.limit, request.offset, cursor)
+            .await?;
+
+        // drafts are listed by when they were last edited, so that is where the next page picks up from
+        let next_cursor = match drafts.last() {
+            Some(last_draft) if drafts.len() as i64 == request.limit => {
+                Some(ArticleCursor::new(last_draft.updated_at, last_draft.id).encode())
+            },
+            _ => None,
+        };
+
+        Ok(ArticlesResponse {
+            articles: self.map_to_articles(Some(user_id), drafts).await?,
+            articles_count: drafts_count as usize,
+            next_cursor,
+        })
+    }
+
+    async fn publish_article(&self, user_id: i64, slug: String) -> ConduitResult<ArticleDto> {
+        info!("retrieving article {:?} to publish for user {:?}", slug, user_id);
+        let article = self
+            .articles_repository
+            .get_article_by_slug(Some(user_id), slug)
+            .await?
+            .ok_or_else(|| ConduitError::NotFound(String::from("article was not found")))?;
+
+        // only the author decides when their article is published
+        if article.user_id != user_id {
+            return Err(ConduitError::Forbidden);
+        }
+
+        let article_tags = self
+            .tags_repository
+            .get_article_tags_by_article_id(article.id)
+            .await?
+            .into_iter()
+            .map(|article_tag| article_tag.tag)
+            .collect_vec();
+
+        if article.status == ArticleStatus::Published.as_str() {
+            info!("article {:?} is already published", article.id);
+            return Ok(article.into_dto(article_tags));
+        }
+
+        info!("publishing article {:?}", article.id);
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let published_article = self
+            .articles_repository
+            .publish_article(unit_of_work.as_mut(), article.id)
+            .await?;
+
+        // users mentioned while the article was a draft learn of it now that they are able to read it
+        self.mentions_tracker
+            .notify_article_mentions(unit_of_work.as_mut(), article.user_id, article.id)
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(published_article.into_dto(article_tags))
+    }
+
+    async fn publish_scheduled_articles(&self) -> ConduitResult<u64> {
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let published_articles = self
+            .articles_repository
+            .publish_due_articles(unit_of_work.as_mut())
+            .await?;
+
+        for article in &published_articles {
+            self.mentions_tracker
+                .notify_article_mentions(unit_of_work.as_mut(), article.user_id, article.id)
+                .await?;
+        }
+
+        unit_of_work.commit().await?;
+
+        if !published_articles.is_empty() {
+            info!("published {} scheduled articles", published_articles.len());
+        }
+
+        Ok(published_articles.len() as u64)
+    }
+
+    async fn get_article_revisions(
+        &self,
+        user_id: Option<i64>,
+        slug: String,
+    ) -> ConduitResult<Vec<ArticleRevisionDto>> {
+        let article = self.get_existing_article(user_id, slug).await?;
+
+        info!("retrieving revisions for article {:?}", article.id);
+        let revisions = self
+            .articles_repository
+            .get_article_revisions(article.id)
+            .await?
+            .into_iter()
+            .map(|revision| revision.into_dto())
+            .collect_vec();
+
+        Ok(revisions)
+    }
+
+    async fn get_article_revision(
+        &self,
+        user_id: Option<i64>,
+        slug: String,
+        revision: i32,
+    ) -> ConduitResult<ArticleRevisionDto> {
+        let article = self.get_existing_article(user_id, slug).await?;
+
+        Ok(self.get_existing_revision(article.id, revision).await?.into_dto())
+    }
+
+    async fn get_article_revision_diff(
+        &self,
+        user_id: Option<i64>,
+        slug: String,
+        revision: i32,
+        from: Option<i32>,
+    ) -> ConduitResult<ArticleRevisionDiffDto> {
+        let article = self.get_existing_article(user_id, slug).await?;
+        let from = from.unwrap_or(revision - 1);
+
+        info!(
+            "comparing revision {:?} of article {:?} against revision {:?}",
+            revision, article.id, from
+        );
+        let modified = self.get_existing_revision(article.id, revision).await?;
+
+        // the first revision has nothing before it, so it is compared against an empty article
+        let (original_title, original_description, original_body) = if from == 0 {
+            (String::new(), String::new(), String::new())
+        } else {
+            let original = self.get_existing_revision(article.id, from).await?;
+            (original.title, original.description, original.body)
+        };
+
+        Ok(ArticleRevisionDiffDto {
+            from,
+            to: revision,
+            title: diff_field("title", from, &original_title, revision, &modified.title),
+            description: diff_field(
+                "description",
+                from,
+                &original_description,
+                revision,
+                &modified.description,
+            ),
+            body: diff_field("body", from, &original_body, revision, &modified.body),
+        })
+    }
+
+    async fn restore_article_revision(&self, user_id: i64, slug: String, revision: i32) -> ConduitResult<ArticleDto> {
+        let article = self.get_existing_article(Some(user_id), slug).await?;
+
+        // only the author may restore their article's content
+        if article.user_id != user_id {
+            return Err(ConduitError::Forbidden);
+        }
+
+        let restored_revision = self.get_existing_revision(article.id, revision).await?;
+
+        info!("restoring article {:?} to revision {:?}", article.id, revision);
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        // restoring an earlier title keeps the current slug, so existing links to the article keep working
+        let restored_article = self
             .articles_repository
-            .get_articles(Some(user_id), None, None, None, limit, offset)
+            .update_article(
+                unit_of_work.as_mut(),
+                article.id,
+                restored_revision.title,
+                article.slug,
+                restored_revision.description,
+                restored_revision.body,
+            )
+            .await?;
+
+        let mentions = self
+            .mentions_tracker
+            .record_mentions(
+                unit_of_work.as_mut(),
+                user_id,
+                article.id,
+                None,
+                &restored_article.body,
+                article.status == ArticleStatus::Published.as_str(),
+            )
+            .await?;
+
+        self.articles_repository
+            .create_article_revision(unit_of_work.as_mut(), article.id, user_id)
             .await?;
 
-        self.map_to_articles(articles).await
+        unit_of_work.commit().await?;
+
+        let article_tags = self
+            .tags_repository
+            .get_article_tags_by_article_id(article.id)
+            .await?
+            .into_iter()
+            .map(|article_tag| article_tag.tag)
+            .collect_vec();
+
+        Ok(ArticleDto {
+            mentions,
+            ..restored_article.into_dto(article_tags)
+        })
+    }
+
+    async fn get_feed(&self, user_id: i64, request: GetArticlesServiceRequest) -> ConduitResult<ArticlesResponse> {
+        self.get_articles_page(
+            Some(user_id),
+            GetArticlesServiceRequest {
+                limit: request.limit,
+                offset: request.offset,
+                cursor: request.cursor,
+                ..Default::default()
+            },
+        )
+        .await
     }
 
-    async fn delete_article(&self, user_id: i64, slug: String) -> ConduitResult<()> {
-        let article = self.articles_repository.get_article_by_slug(None, slug).await?;
+    async fn delete_article(&self, user_id: i64, role: Role, slug: String) -> ConduitResult<()> {
+        let article = self
+            .articles_repository
+            .get_article_by_slug(Some(user_id), slug)
+            .await?;
 
         if let Some(existing_article) = article {
-            // verify the user IDs match on the request and the article
-            if existing_article.user_id != user_id {
-                return Err(ConduitError::Unauthorized);
+            // only the author or a moderator may delete the article
+            if existing_article.user_id != user_id && !role.has_permission(Permission::ModerateContent) {
+                return Err(ConduitError::Forbidden);
             }
 
-            self.articles_repository.delete_article(existing_article.id).await?;
+            let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+            self.articles_repository
+                .delete_article(unit_of_work.as_mut(), existing_article.id, user_id)
+                .await?;
+
+            unit_of_work.commit().await?;
 
             return Ok(());
         }
@@ -245,7 +596,10 @@ impl ArticlesService for ConduitArticlesService {
 
     async fn favorite_article(&self, user_id: i64, slug: String) -> ConduitResult<ArticleDto> {
         // verify the article exists before attempting to favorite
-        let article = self.articles_repository.get_article_by_slug(None, slug).await?;
+        let article = self
+            .articles_repository
+            .get_article_by_slug(Some(user_id), slug)
+            .await?;
 
         if let Some(mut existing_article) = article {
             // verify the user has not already favorited the article - alternatively, query for the article favorite by user ID and article ID
@@ -280,7 +634,10 @@ impl ArticlesService for ConduitArticlesService {
 
     async fn unfavorite_article(&self, user_id: i64, slug: String) -> ConduitResult<ArticleDto> {
         // verify the article exists before attempting to unfavorite
-        let article = self.articles_repository.get_article_by_slug(None, slug).await?;
+        let article = self
+            .articles_repository
+            .get_article_by_slug(Some(user_id), slug)
+            .await?;
 
         if let Some(existing_article) = article {
             info!("unfavoriting article {:?} for user {:?}", existing_article.id, user_id);
@@ -304,8 +661,202 @@ impl ArticlesService for ConduitArticlesService {
     }
 }
 
+/// Parses the time to publish a scheduled article at, which must be in the future.
+fn validate_publish_at(publish_at: &str) -> ConduitResult<OffsetDateTime> {
+    let publish_at = parse_publish_at(publish_at).map_err(ConduitError::BadRequest)?;
+
+    if publish_at <= OffsetDateTime::now_utc() {
+        return Err(ConduitError::BadRequest(String::from(
+            "publish time must be in the future",
+        )));
+    }
+
+    Ok(publish_at)
+}
+
+/// Builds a unified diff of the field between two revisions, or nothing when the field is unchanged.
+fn diff_field(field: &str, from: i32, original: &str, to: i32, modified: &str) -> Option<String> {
+    if original == modified {
+        return None;
+    }
+
+    let diff = TextDiff::from_lines(original, modified)
+        .unified_diff()
+        .header(
+            &format!("{} (revision {})", field, from),
+            &format!("{} (revision {})", field, to),
+        )
+        .to_string();
+
+    Some(diff)
+}
+
 impl ConduitArticlesService {
-    async fn map_to_articles(&self, articles: Vec<GetArticleQuery>) -> ConduitResult<Vec<ArticleDto>> {
+    async fn get_existing_article(&self, user_id: Option<i64>, slug: String) -> ConduitResult<GetArticleQuery> {
+        self.articles_repository
+            .get_article_by_slug(user_id, slug)
+            .await?
+            .ok_or_else(|| ConduitError::NotFound(String::from("article was not found")))
+    }
+
+    async fn get_existing_revision(&self, article_id: i64, revision: i32) -> ConduitResult<ArticleRevisionEntity> {
+        self.articles_repository
+            .get_article_revision(article_id, revision)
+            .await?
+            .ok_or_else(|| ConduitError::NotFound(String::from("article revision was not found")))
+    }
+
+    async fn generate_unique_slug(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        title: &str,
+        article_id: Option<i64>,
+    ) -> ConduitResult<String> {
+        let slug = slugify(title);
+        let taken_slugs = self
+            .articles_repository
+            .get_taken_slugs(unit_of_work, slug.clone(), article_id)
+            .await?;
+
+        Ok(next_available_slug(&slug, &taken_slugs))
+    }
+
+    /// Retrieves the tags, creating any that do not yet exist within the unit of work.
+    async fn get_or_create_tags(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        tags: Vec<String>,
+    ) -> ConduitResult<Vec<TagEntity>> {
+        // an empty search returns every tag, so only search when there are tags to look for
+        if tags.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // search for existing tags, as we want to create a new tag if the request contains a tag that doesn't exist in the database
+        let mut existing_tags = self.tags_repository.get_tags(tags.clone()).await?;
+
+        let tags_to_create = tags
+            .into_iter()
+            .filter(|tag| !existing_tags.iter().any(|existing_tag| existing_tag.tag == *tag))
+            .collect_vec();
+
+        // as the created tags are not yet committed, keep track of them rather than re-querying the tags table for their IDs
+        if !tags_to_create.is_empty() {
+            let created_tags = self.tags_repository.create_tags(unit_of_work, tags_to_create).await?;
+
+            existing_tags.extend(created_tags);
+        }
+
+        Ok(existing_tags)
+    }
+
+    /// Replaces the article's tags with the tag list, removing tags that are no longer used by any article.
+    async fn replace_article_tags(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        article_id: i64,
+        tag_list: Vec<String>,
+    ) -> ConduitResult<()> {
+        let deduped_tag_list = tag_list.into_iter().unique().collect_vec();
+
+        let current_tag_ids = self
+            .tags_repository
+            .get_article_tags_by_article_id(article_id)
+            .await?
+            .into_iter()
+            .map(|article_tag| article_tag.tag_id)
+            .collect_vec();
+
+        let updated_tag_ids = self
+            .get_or_create_tags(unit_of_work, deduped_tag_list)
+            .await?
+            .into_iter()
+            .map(|tag| tag.id)
+            .collect_vec();
+
+        let article_tags_to_create = updated_tag_ids
+            .iter()
+            .filter(|tag_id| !current_tag_ids.contains(tag_id))
+            .map(|tag_id| (*tag_id, article_id))
+            .collect_vec();
+
+        let stale_tag_ids = current_tag_ids
+            .into_iter()
+            .filter(|tag_id| !updated_tag_ids.contains(tag_id))
+            .collect_vec();
+
+        if !article_tags_to_create.is_empty() {
+            info!(
+                "adding {} tags to article {:?}",
+                article_tags_to_create.len(),
+                article_id
+            );
+            self.tags_repository
+                .create_article_tags(unit_of_work, article_tags_to_create)
+                .await?;
+        }
+
+        if !stale_tag_ids.is_empty() {
+            info!("removing {} tags from article {:?}", stale_tag_ids.len(), article_id);
+            self.tags_repository
+                .delete_article_tags(unit_of_work, article_id, stale_tag_ids.clone())
+                .await?;
+
+            // tags only exist to group articles, so a tag is dropped once its last article lets go of it
+            let deleted_tags = self
+                .tags_repository
+                .delete_unreferenced_tags(unit_of_work, stale_tag_ids)
+                .await?;
+
+            if deleted_tags > 0 {
+                info!("removed {} unreferenced tags", deleted_tags);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_articles_page(
+        &self,
+        user_id: Option<i64>,
+        request: GetArticlesServiceRequest,
+    ) -> ConduitResult<ArticlesResponse> {
+        // search results are ordered by relevance, which has no stable position for a cursor to pick up from
+        if request.q.is_some() && request.cursor.is_some() {
+            return Err(ConduitError::BadRequest(String::from(
+                "cursor pagination is not supported when searching articles",
+            )));
+        }
+
+        let cursor = request.cursor.as_deref().map(ArticleCursor::decode).transpose()?;
+        let is_searching = request.q.is_some();
+        let limit = request.limit;
+
+        info!("retrieving articles count for request {:?}", request);
+        let articles_count = self.articles_repository.get_articles_count(request.clone()).await?;
+
+        let articles = self.articles_repository.get_articles(user_id, request, cursor).await?;
+
+        // a full page may be followed by more articles, while a partial page is always the last
+        let next_cursor = match articles.last() {
+            Some(last_article) if !is_searching && articles.len() as i64 == limit => {
+                Some(ArticleCursor::new(last_article.created_at, last_article.id).encode())
+            },
+            _ => None,
+        };
+
+        Ok(ArticlesResponse {
+            articles: self.map_to_articles(user_id, articles).await?,
+            articles_count: articles_count as usize,
+            next_cursor,
+        })
+    }
+
+    async fn map_to_articles(
+        &self,
+        user_id: Option<i64>,
+        articles: Vec<GetArticleQuery>,
+    ) -> ConduitResult<Vec<ArticleDto>> {
         info!("found {} articles in feed", articles.len());
 
         let mut mapped_articles: Vec<ArticleDto> = Vec::new();
@@ -313,7 +864,15 @@ impl ConduitArticlesService {
         if !articles.is_empty() {
             let article_ids = articles.iter().map(|article| article.id).collect_vec();
 
-            let associated_article_tags = self.tags_repository.get_article_tags_article_ids(article_ids).await?;
+            let associated_article_tags = self
+                .tags_repository
+                .get_article_tags_article_ids(article_ids.clone())
+                .await?;
+
+            let associated_reactions = self
+                .reactions_repository
+                .get_article_reactions(user_id, article_ids)
+                .await?;
 
             for article in articles {
                 let article_tags = associated_article_tags
@@ -322,7 +881,15 @@ impl ConduitArticlesService {
                     .map(|tag| tag.tag.clone())
                     .collect_vec();
 
-                mapped_articles.push(article.into_dto(article_tags));
+                let (reactions, my_reactions) = collect_reactions(article.id, &associated_reactions);
+                let snippet = article.snippet.as_deref().map(render_snippet);
+
+                mapped_articles.push(ArticleDto {
+                    reactions,
+                    my_reactions,
+                    snippet,
+                    ..article.into_dto(article_tags)
+                });
             }
         }
 
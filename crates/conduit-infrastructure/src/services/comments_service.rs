@@ -1,75 +1,311 @@
+use std::ops::Add;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
 use async_trait::async_trait;
 use itertools::Itertools;
+use sqlx::types::time::OffsetDateTime;
 
 use conduit_core::articles::repository::DynArticlesRepository;
-use conduit_core::comments::repository::DynCommentsRepository;
+use conduit_core::comments::cursor::CommentCursor;
+use conduit_core::comments::repository::{CommentEntity, DynCommentsRepository};
 use conduit_core::comments::service::CommentsService;
+use conduit_core::comments::thread::build_comment_tree;
+use conduit_core::config::AppConfig;
 use conduit_core::errors::{ConduitError, ConduitResult};
-use conduit_domain::comments::CommentDto;
+use conduit_core::reactions::collect_reactions;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::users::roles::{Permission, Role};
+use conduit_core::utils::unit_of_work::DynUnitOfWorkFactory;
+use conduit_domain::comments::requests::GetCommentsServiceRequest;
+use conduit_domain::comments::responses::CommentsResponse;
+use conduit_domain::comments::{CommentDto, CommentRevisionDto};
+
+use crate::services::utils::markdown::render_markdown;
+use crate::services::utils::mentions_tracker::MentionsTracker;
 
 pub struct ConduitCommentsService {
     comments_repository: DynCommentsRepository,
     articles_repository: DynArticlesRepository,
+    reactions_repository: DynReactionsRepository,
+    mentions_tracker: MentionsTracker,
+    unit_of_work_factory: DynUnitOfWorkFactory,
+    config: Arc<AppConfig>,
 }
 
 impl ConduitCommentsService {
-    pub fn new(comments_repository: DynCommentsRepository, articles_repository: DynArticlesRepository) -> Self {
+    pub fn new(
+        comments_repository: DynCommentsRepository,
+        articles_repository: DynArticlesRepository,
+        reactions_repository: DynReactionsRepository,
+        mentions_tracker: MentionsTracker,
+        unit_of_work_factory: DynUnitOfWorkFactory,
+        config: Arc<AppConfig>,
+    ) -> Self {
         Self {
             comments_repository,
             articles_repository,
+            reactions_repository,
+            mentions_tracker,
+            unit_of_work_factory,
+            config,
         }
     }
+
+    fn edit_window(&self) -> Duration {
+        Duration::from_secs(self.config.comment_edit_window_minutes * 60)
+    }
+
+    /// Retrieves the comment, treating comments on any other article than the one in the route as not found.
+    async fn get_article_comment(&self, slug: String, comment_id: i64) -> ConduitResult<CommentEntity> {
+        let article = self
+            .articles_repository
+            .get_article_by_slug(None, slug)
+            .await?
+            .ok_or_else(|| ConduitError::NotFound(String::from("article not found")))?;
+
+        self.comments_repository
+            .get_comment(comment_id)
+            .await?
+            .filter(|comment| comment.article_id == article.id)
+            .ok_or_else(|| ConduitError::NotFound(String::from("comment not found")))
+    }
 }
 
 #[async_trait]
 impl CommentsService for ConduitCommentsService {
-    async fn get_comments(&self, user_id: Option<i64>, slug: String) -> ConduitResult<Vec<CommentDto>> {
+    async fn get_comments(
+        &self,
+        user_id: Option<i64>,
+        slug: String,
+        request: GetCommentsServiceRequest,
+    ) -> ConduitResult<CommentsResponse> {
         // verify the article exists before adding comments
         let article = self.articles_repository.get_article_by_slug(None, slug).await?;
 
         if let Some(existing_article) = article {
-            let comments = self
+            let cursor = request.cursor.as_deref().map(CommentCursor::decode).transpose()?;
+
+            let page = self
                 .comments_repository
-                .get_comments(user_id, existing_article.id)
-                .await?
+                .get_comments(user_id, existing_article.id, request.sort, request.limit, cursor)
+                .await?;
+
+            // only a full page may be followed by more comments
+            let next_cursor = match page.last() {
+                Some(last_comment) if page.len() as i64 == request.limit => Some(
+                    CommentCursor::new(last_comment.reactions_count, last_comment.created_at, last_comment.id).encode(),
+                ),
+                _ => None,
+            };
+
+            let replies = if page.is_empty() {
+                Vec::new()
+            } else {
+                let comment_ids = page.iter().map(|comment| comment.id).collect_vec();
+                self.comments_repository
+                    .get_comment_replies(user_id, comment_ids)
+                    .await?
+            };
+
+            let comments_count = self.comments_repository.get_comments_count(existing_article.id).await?;
+
+            let comment_ids = page
+                .iter()
+                .chain(replies.iter())
+                .map(|comment| comment.id)
+                .collect_vec();
+            let (comment_reactions, comment_mentions) = if comment_ids.is_empty() {
+                (Vec::new(), Vec::new())
+            } else {
+                (
+                    self.reactions_repository
+                        .get_comment_reactions(user_id, comment_ids.clone())
+                        .await?,
+                    self.mentions_tracker.get_comment_mentions(comment_ids).await?,
+                )
+            };
+
+            // top-level comments keep the page's order, as the tree lists comments in the order it is given them
+            let comments = page
                 .into_iter()
-                .map_into::<CommentDto>()
+                .chain(replies)
+                .map(|comment| {
+                    let mut comment = CommentDto::from(comment);
+
+                    if !comment.deleted {
+                        comment.body_html = Some(render_markdown(&comment.body));
+                        (comment.reactions, comment.my_reactions) = collect_reactions(comment.id, &comment_reactions);
+                        comment.mentions = comment_mentions
+                            .iter()
+                            .filter(|mention| mention.comment_id == comment.id)
+                            .map(|mention| mention.username.clone())
+                            .collect_vec();
+                    }
+
+                    comment
+                })
                 .collect_vec();
 
-            return Ok(comments);
+            return Ok(CommentsResponse {
+                comments: build_comment_tree(comments, self.config.comment_max_depth),
+                comments_count: comments_count as usize,
+                next_cursor,
+            });
         }
 
         return Err(ConduitError::NotFound(String::from("article not found for comments")));
     }
 
-    async fn add_comment(&self, user_id: i64, slug: String, body: String) -> ConduitResult<CommentDto> {
+    async fn add_comment(
+        &self,
+        user_id: i64,
+        slug: String,
+        parent_id: Option<i64>,
+        body: String,
+    ) -> ConduitResult<CommentDto> {
         // verify the article exists before adding comments
         let article = self.articles_repository.get_article_by_slug(None, slug).await?;
 
         if let Some(existing_article) = article {
-            let comments = self
+            // replies may only be made to comments on the same article that have not been deleted
+            if let Some(parent_id) = parent_id {
+                let parent_comment = self
+                    .comments_repository
+                    .get_comment(parent_id)
+                    .await?
+                    .ok_or_else(|| ConduitError::NotFound(String::from("parent comment not found")))?;
+
+                if parent_comment.article_id != existing_article.id {
+                    return Err(ConduitError::BadRequest(String::from(
+                        "parent comment belongs to another article",
+                    )));
+                }
+            }
+
+            let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+            let comment = self
                 .comments_repository
-                .create_comment(existing_article.id, user_id, body)
-                .await?
-                .into();
+                .create_comment(unit_of_work.as_mut(), existing_article.id, user_id, parent_id, body)
+                .await?;
+
+            let mentions = self
+                .mentions_tracker
+                .record_mentions(
+                    unit_of_work.as_mut(),
+                    user_id,
+                    existing_article.id,
+                    Some(comment.id),
+                    &comment.body,
+                    true,
+                )
+                .await?;
 
-            return Ok(comments);
+            unit_of_work.commit().await?;
+
+            return Ok(CommentDto {
+                mentions,
+                ..comment.into()
+            });
         }
 
         return Err(ConduitError::NotFound(String::from("article not found for comments")));
     }
 
-    async fn remove_comment(&self, user_id: i64, comment_id: i64) -> ConduitResult<()> {
+    async fn update_comment(
+        &self,
+        user_id: i64,
+        slug: String,
+        comment_id: i64,
+        body: String,
+    ) -> ConduitResult<CommentDto> {
+        let comment = self.get_article_comment(slug, comment_id).await?;
+
+        // only the commenter may edit the comment, moderators remove comments rather than rewording them
+        if comment.user_id != user_id {
+            return Err(ConduitError::Forbidden);
+        }
+
+        if comment.created_at.add(self.edit_window()) < OffsetDateTime::from(SystemTime::now()) {
+            return Err(ConduitError::BadRequest(format!(
+                "comments may only be edited within {} minutes of being posted",
+                self.config.comment_edit_window_minutes
+            )));
+        }
+
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        self.comments_repository
+            .create_comment_revision(unit_of_work.as_mut(), comment_id)
+            .await?;
+
+        let updated_comment = self
+            .comments_repository
+            .update_comment(unit_of_work.as_mut(), comment_id, body)
+            .await?;
+
+        let mentions = self
+            .mentions_tracker
+            .record_mentions(
+                unit_of_work.as_mut(),
+                user_id,
+                comment.article_id,
+                Some(comment_id),
+                &updated_comment.body,
+                true,
+            )
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(CommentDto {
+            mentions,
+            ..updated_comment.into()
+        })
+    }
+
+    async fn get_comment_revisions(
+        &self,
+        role: Role,
+        slug: String,
+        comment_id: i64,
+    ) -> ConduitResult<Vec<CommentRevisionDto>> {
+        if !role.has_permission(Permission::ModerateContent) {
+            return Err(ConduitError::Forbidden);
+        }
+
+        // verify the comment exists on the article before retrieving its history
+        self.get_article_comment(slug, comment_id).await?;
+
+        let revisions = self
+            .comments_repository
+            .get_comment_revisions(comment_id)
+            .await?
+            .into_iter()
+            .map(|revision| revision.into_dto())
+            .collect_vec();
+
+        Ok(revisions)
+    }
+
+    async fn remove_comment(&self, user_id: i64, role: Role, comment_id: i64) -> ConduitResult<()> {
         // verify the comment exists before removing
         let comment = self.comments_repository.get_comment(comment_id).await?;
 
         if let Some(existing_comment) = comment {
-            // verify the commenter ID and the request ID match before removing
-            if existing_comment.user_id != user_id {
-                return Err(ConduitError::Unauthorized);
+            // only the commenter or a moderator may remove the comment
+            if existing_comment.user_id != user_id && !role.has_permission(Permission::ModerateContent) {
+                return Err(ConduitError::Forbidden);
             }
 
-            self.comments_repository.delete_comment(comment_id).await?;
+            let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+            self.comments_repository
+                .delete_comment(unit_of_work.as_mut(), comment_id, user_id)
+                .await?;
+
+            unit_of_work.commit().await?;
 
             return Ok(());
         }
@@ -0,0 +1,218 @@
+use std::fmt::Write;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use itertools::Itertools;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use sha2::{Digest, Sha256};
+use sqlx::types::time::{OffsetDateTime, UtcOffset};
+use tracing::info;
+
+use conduit_core::articles::repository::{DynArticlesRepository, GetArticleQuery};
+use conduit_core::config::AppConfig;
+use conduit_core::errors::ConduitResult;
+use conduit_core::feeds::service::FeedsService;
+use conduit_core::feeds::{Feed, FeedFormat};
+use conduit_core::tags::repository::{ArticleTagQuery, DynTagsRepository};
+use conduit_domain::articles::requests::GetArticlesServiceRequest;
+
+use crate::services::utils::markdown::render_markdown;
+
+/// The number of articles included in a feed, newest first.
+const FEED_SIZE: i64 = 20;
+
+const ATOM_DATE_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+const RSS_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S +0000";
+
+pub struct ConduitFeedsService {
+    articles_repository: DynArticlesRepository,
+    tags_repository: DynTagsRepository,
+    config: Arc<AppConfig>,
+}
+
+/// The metadata describing a feed as a whole, shared between the feed formats.
+struct FeedMetadata {
+    title: String,
+    feed_url: String,
+    site_url: String,
+    updated_at: OffsetDateTime,
+}
+
+impl ConduitFeedsService {
+    pub fn new(
+        articles_repository: DynArticlesRepository,
+        tags_repository: DynTagsRepository,
+        config: Arc<AppConfig>,
+    ) -> Self {
+        Self {
+            articles_repository,
+            tags_repository,
+            config,
+        }
+    }
+
+    /// Feeds are served from the root of the server rather than under the API's `/api` prefix.
+    fn server_url(&self) -> &str {
+        let api_url = self.config.api_url.trim_end_matches('/');
+        api_url.strip_suffix("/api").unwrap_or(api_url)
+    }
+
+    fn article_url(&self, slug: &str) -> String {
+        format!("{}/article/{}", self.config.site_url, slug)
+    }
+
+    fn render_atom(&self, metadata: &FeedMetadata, articles: &[GetArticleQuery], tags: &[ArticleTagQuery]) -> String {
+        let mut feed = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+
+        let _ = write!(
+            feed,
+            r#"<feed xmlns="http://www.w3.org/2005/Atom"><title>{}</title><id>{}</id><link rel="self" href="{}"/><link rel="alternate" href="{}"/><updated>{}</updated>"#,
+            escape_xml(&metadata.title),
+            escape_xml(&metadata.feed_url),
+            escape_xml(&metadata.feed_url),
+            escape_xml(&metadata.site_url),
+            format_utc(metadata.updated_at, ATOM_DATE_FORMAT),
+        );
+
+        for article in articles {
+            let article_url = escape_xml(&self.article_url(&article.slug));
+            let published_at = article.published_at.unwrap_or(article.created_at);
+
+            let _ = write!(
+                feed,
+                r#"<entry><title>{}</title><id>{}</id><link rel="alternate" href="{}"/><published>{}</published><updated>{}</updated><author><name>{}</name></author><summary>{}</summary><content type="html">{}</content>"#,
+                escape_xml(&article.title),
+                article_url,
+                article_url,
+                format_utc(published_at, ATOM_DATE_FORMAT),
+                format_utc(article.updated_at, ATOM_DATE_FORMAT),
+                escape_xml(&article.author_username),
+                escape_xml(&article.description),
+                escape_xml(&render_markdown(&article.body)),
+            );
+
+            for tag in tags.iter().filter(|tag| tag.article_id == article.id) {
+                let _ = write!(feed, r#"<category term="{}"/>"#, escape_xml(&tag.tag));
+            }
+
+            feed.push_str("</entry>");
+        }
+
+        feed.push_str("</feed>");
+        feed
+    }
+
+    fn render_rss(&self, metadata: &FeedMetadata, articles: &[GetArticleQuery], tags: &[ArticleTagQuery]) -> String {
+        let mut feed = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+
+        let _ = write!(
+            feed,
+            r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom" xmlns:dc="http://purl.org/dc/elements/1.1/"><channel><title>{}</title><link>{}</link><description>{}</description><atom:link rel="self" type="application/rss+xml" href="{}"/><lastBuildDate>{}</lastBuildDate>"#,
+            escape_xml(&metadata.title),
+            escape_xml(&metadata.site_url),
+            escape_xml(&metadata.title),
+            escape_xml(&metadata.feed_url),
+            format_utc(metadata.updated_at, RSS_DATE_FORMAT),
+        );
+
+        for article in articles {
+            let article_url = escape_xml(&self.article_url(&article.slug));
+            let published_at = article.published_at.unwrap_or(article.created_at);
+
+            let _ = write!(
+                feed,
+                r#"<item><title>{}</title><link>{}</link><guid isPermaLink="true">{}</guid><pubDate>{}</pubDate><dc:creator>{}</dc:creator><description>{}</description>"#,
+                escape_xml(&article.title),
+                article_url,
+                article_url,
+                format_utc(published_at, RSS_DATE_FORMAT),
+                escape_xml(&article.author_username),
+                escape_xml(&render_markdown(&article.body)),
+            );
+
+            for tag in tags.iter().filter(|tag| tag.article_id == article.id) {
+                let _ = write!(feed, "<category>{}</category>", escape_xml(&tag.tag));
+            }
+
+            feed.push_str("</item>");
+        }
+
+        feed.push_str("</channel></rss>");
+        feed
+    }
+}
+
+#[async_trait]
+impl FeedsService for ConduitFeedsService {
+    async fn get_articles_feed(
+        &self,
+        format: FeedFormat,
+        author: Option<String>,
+        tag: Option<String>,
+    ) -> ConduitResult<Feed> {
+        let (title, feed_path) = match (&author, &tag) {
+            (Some(author), _) => (
+                format!("Conduit: {}", author),
+                format!("profiles/{}", utf8_percent_encode(author, NON_ALPHANUMERIC)),
+            ),
+            (None, Some(tag)) => (
+                format!("Conduit: #{}", tag),
+                format!("tags/{}", utf8_percent_encode(tag, NON_ALPHANUMERIC)),
+            ),
+            (None, None) => (String::from("Conduit"), String::from("articles")),
+        };
+
+        info!("building {} feed {:?}", format, feed_path);
+        let articles = self
+            .articles_repository
+            .get_articles(
+                None,
+                GetArticlesServiceRequest {
+                    author,
+                    tag,
+                    limit: FEED_SIZE,
+                    ..Default::default()
+                },
+                None,
+            )
+            .await?;
+
+        let tags = if articles.is_empty() {
+            Vec::new()
+        } else {
+            let article_ids = articles.iter().map(|article| article.id).collect_vec();
+            self.tags_repository.get_article_tags_article_ids(article_ids).await?
+        };
+
+        // the feed changes whenever one of its articles does, so it was last updated along with its latest article
+        let last_updated_at = articles.iter().map(|article| article.updated_at).max();
+
+        let metadata = FeedMetadata {
+            title,
+            feed_url: format!("{}/feeds/{}.{}", self.server_url(), feed_path, format),
+            site_url: self.config.site_url.clone(),
+            updated_at: last_updated_at.unwrap_or_else(OffsetDateTime::now_utc),
+        };
+
+        let content = match format {
+            FeedFormat::Atom => self.render_atom(&metadata, &articles, &tags),
+            FeedFormat::Rss => self.render_rss(&metadata, &articles, &tags),
+        };
+
+        let etag = format!("\"{}\"", &hex::encode(Sha256::digest(content.as_bytes()))[..32]);
+
+        Ok(Feed { content, etag })
+    }
+}
+
+fn format_utc(date_time: OffsetDateTime, format: &str) -> String {
+    date_time.to_offset(UtcOffset::UTC).format(format)
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
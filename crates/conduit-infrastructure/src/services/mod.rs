@@ -1,6 +1,14 @@
+pub mod access_tokens_service;
+pub mod admin_service;
 pub mod articles_service;
 pub mod comments_service;
+pub mod feeds_service;
+pub mod notifications_service;
 pub mod profiles_service;
+pub mod reactions_service;
+pub mod sessions_service;
 pub mod tags_service;
+pub mod trash_service;
+pub mod two_factor_service;
 pub mod users_service;
 pub mod utils;
@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use itertools::Itertools;
+
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::notifications::service::NotificationsService;
+use conduit_domain::notifications::requests::GetNotificationsServiceRequest;
+use conduit_domain::notifications::responses::NotificationsResponse;
+use conduit_domain::notifications::NotificationDto;
+
+pub struct ConduitNotificationsService {
+    notifications_repository: DynNotificationsRepository,
+}
+
+impl ConduitNotificationsService {
+    pub fn new(notifications_repository: DynNotificationsRepository) -> Self {
+        Self {
+            notifications_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationsService for ConduitNotificationsService {
+    async fn get_notifications(
+        &self,
+        user_id: i64,
+        request: GetNotificationsServiceRequest,
+    ) -> ConduitResult<NotificationsResponse> {
+        let notifications = self
+            .notifications_repository
+            .get_notifications(user_id, request.limit, request.offset)
+            .await?
+            .into_iter()
+            .map(NotificationDto::from)
+            .collect_vec();
+
+        let unread_count = self
+            .notifications_repository
+            .get_unread_notifications_count(user_id)
+            .await?;
+
+        Ok(NotificationsResponse {
+            notifications,
+            unread_count,
+        })
+    }
+
+    async fn mark_notification_read(&self, user_id: i64, notification_id: i64) -> ConduitResult<()> {
+        // notifications of other users are not found rather than forbidden, so their existence is not given away
+        let marked = self
+            .notifications_repository
+            .mark_notifications_read(user_id, Some(notification_id))
+            .await?;
+
+        if marked == 0 {
+            return Err(ConduitError::NotFound(String::from("notification not found")));
+        }
+
+        Ok(())
+    }
+
+    async fn mark_all_notifications_read(&self, user_id: i64) -> ConduitResult<()> {
+        self.notifications_repository
+            .mark_notifications_read(user_id, None)
+            .await?;
+
+        Ok(())
+    }
+}
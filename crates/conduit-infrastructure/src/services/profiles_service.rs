@@ -26,7 +26,12 @@ impl ConduitProfilesService {
 impl ProfilesService for ConduitProfilesService {
     async fn get_profile(&self, username: &str, current_user_id: Option<i64>) -> ConduitResult<ProfileDto> {
         info!("retrieving profile for user {:?}", username);
-        let user = self.users_repository.get_user_by_username(username).await?;
+        // suspended users are hidden from other users as if they did not exist
+        let user = self
+            .users_repository
+            .get_user_by_username(username)
+            .await?
+            .filter(|user| user.suspended_at.is_none());
 
         if user.is_none() {
             return Err(ConduitError::NotFound(String::from("profile was not found")));
@@ -56,7 +61,11 @@ impl ProfilesService for ConduitProfilesService {
             "add profile follow to user {:?} from user ID {:?}",
             username, current_user_id
         );
-        let user = self.users_repository.get_user_by_username(username).await?;
+        let user = self
+            .users_repository
+            .get_user_by_username(username)
+            .await?
+            .filter(|user| user.suspended_at.is_none());
 
         if user.is_none() {
             return Err(ConduitError::NotFound(String::from("profile to follow was not found")));
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use conduit_core::articles::repository::DynArticlesRepository;
+use conduit_core::comments::repository::DynCommentsRepository;
+use conduit_core::config::AppConfig;
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::reactions::service::ReactionsService;
+use conduit_core::reactions::{collect_reactions, parse_reaction_emojis, ReactionTarget};
+use conduit_core::utils::unit_of_work::DynUnitOfWorkFactory;
+use conduit_domain::reactions::responses::ReactionsResponse;
+
+pub struct ConduitReactionsService {
+    reactions_repository: DynReactionsRepository,
+    articles_repository: DynArticlesRepository,
+    comments_repository: DynCommentsRepository,
+    unit_of_work_factory: DynUnitOfWorkFactory,
+    config: Arc<AppConfig>,
+}
+
+impl ConduitReactionsService {
+    pub fn new(
+        reactions_repository: DynReactionsRepository,
+        articles_repository: DynArticlesRepository,
+        comments_repository: DynCommentsRepository,
+        unit_of_work_factory: DynUnitOfWorkFactory,
+        config: Arc<AppConfig>,
+    ) -> Self {
+        Self {
+            reactions_repository,
+            articles_repository,
+            comments_repository,
+            unit_of_work_factory,
+            config,
+        }
+    }
+
+    async fn toggle_reaction(
+        &self,
+        user_id: i64,
+        target: ReactionTarget,
+        emoji: String,
+    ) -> ConduitResult<ReactionsResponse> {
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let removed = self
+            .reactions_repository
+            .remove_reaction(unit_of_work.as_mut(), user_id, target, emoji.clone())
+            .await?;
+
+        if removed == 0 {
+            self.reactions_repository
+                .add_reaction(unit_of_work.as_mut(), user_id, target, emoji)
+                .await?;
+        }
+
+        unit_of_work.commit().await?;
+
+        let (target_id, reactions) = match target {
+            ReactionTarget::Article(article_id) => (
+                article_id,
+                self.reactions_repository
+                    .get_article_reactions(Some(user_id), vec![article_id])
+                    .await?,
+            ),
+            ReactionTarget::Comment(comment_id) => (
+                comment_id,
+                self.reactions_repository
+                    .get_comment_reactions(Some(user_id), vec![comment_id])
+                    .await?,
+            ),
+        };
+
+        let (reactions, my_reactions) = collect_reactions(target_id, &reactions);
+
+        Ok(ReactionsResponse {
+            reactions,
+            my_reactions,
+        })
+    }
+
+    fn validate_emoji(&self, emoji: &str) -> ConduitResult<()> {
+        if !parse_reaction_emojis(&self.config.reaction_emojis)
+            .iter()
+            .any(|supported_emoji| supported_emoji == emoji)
+        {
+            return Err(ConduitError::BadRequest(String::from("reaction is not supported")));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ReactionsService for ConduitReactionsService {
+    async fn toggle_article_reaction(
+        &self,
+        user_id: i64,
+        slug: String,
+        emoji: String,
+    ) -> ConduitResult<ReactionsResponse> {
+        self.validate_emoji(&emoji)?;
+
+        let article = self
+            .articles_repository
+            .get_article_by_slug(None, slug)
+            .await?
+            .ok_or_else(|| ConduitError::NotFound(String::from("article not found")))?;
+
+        self.toggle_reaction(user_id, ReactionTarget::Article(article.id), emoji)
+            .await
+    }
+
+    async fn toggle_comment_reaction(
+        &self,
+        user_id: i64,
+        slug: String,
+        comment_id: i64,
+        emoji: String,
+    ) -> ConduitResult<ReactionsResponse> {
+        self.validate_emoji(&emoji)?;
+
+        let article = self
+            .articles_repository
+            .get_article_by_slug(None, slug)
+            .await?
+            .ok_or_else(|| ConduitError::NotFound(String::from("article not found")))?;
+
+        // deleted comments are not found, so placeholders left for their replies cannot be reacted to, and neither
+        // can comments on any other article than the one in the route
+        let comment = self.comments_repository.get_comment(comment_id).await?;
+
+        if !matches!(comment, Some(comment) if comment.article_id == article.id) {
+            return Err(ConduitError::NotFound(String::from("comment not found")));
+        }
+
+        self.toggle_reaction(user_id, ReactionTarget::Comment(comment_id), emoji)
+            .await
+    }
+}
@@ -0,0 +1,187 @@
+use std::ops::Add;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
+use tracing::{info, warn};
+
+use conduit_core::config::AppConfig;
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::sessions::service::SessionsService;
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::users::roles::Role;
+use conduit_core::utils::token_service::{DynTokenService, TokenClaims};
+use conduit_core::utils::unit_of_work::DynUnitOfWorkFactory;
+use conduit_domain::users::UserDto;
+
+pub struct ConduitSessionsService {
+    sessions_repository: DynSessionsRepository,
+    users_repository: DynUsersRepository,
+    token_service: DynTokenService,
+    unit_of_work_factory: DynUnitOfWorkFactory,
+    config: Arc<AppConfig>,
+}
+
+impl ConduitSessionsService {
+    pub fn new(
+        sessions_repository: DynSessionsRepository,
+        users_repository: DynUsersRepository,
+        token_service: DynTokenService,
+        unit_of_work_factory: DynUnitOfWorkFactory,
+        config: Arc<AppConfig>,
+    ) -> Self {
+        Self {
+            sessions_repository,
+            users_repository,
+            token_service,
+            unit_of_work_factory,
+            config,
+        }
+    }
+
+    fn refresh_token_expiration(&self) -> OffsetDateTime {
+        let from_now = Duration::from_secs(self.config.refresh_token_lifetime_days * 24 * 60 * 60);
+        OffsetDateTime::from(SystemTime::now().add(from_now))
+    }
+}
+
+#[async_trait]
+impl SessionsService for ConduitSessionsService {
+    async fn new_refresh_token(&self, user_id: i64) -> ConduitResult<String> {
+        let refresh_token = self.token_service.new_opaque_token();
+        let token_hash = self.token_service.hash_opaque_token(&refresh_token);
+
+        info!("creating refresh token for user {:?}", user_id);
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        // a new login starts a new family of refresh tokens, identified by the first token in the family
+        self.sessions_repository
+            .create_refresh_token(
+                unit_of_work.as_mut(),
+                user_id,
+                token_hash.clone(),
+                token_hash,
+                self.refresh_token_expiration(),
+            )
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(refresh_token)
+    }
+
+    async fn refresh_session(&self, refresh_token: String) -> ConduitResult<UserDto> {
+        let token_hash = self.token_service.hash_opaque_token(&refresh_token);
+
+        let existing_token = self.sessions_repository.get_refresh_token(token_hash).await?;
+
+        if existing_token.is_none() {
+            return Err(ConduitError::Unauthorized);
+        }
+
+        let existing_token = existing_token.unwrap();
+
+        if existing_token.expires_at < OffsetDateTime::now_utc() {
+            info!("refresh token {:?} has expired", existing_token.id);
+            return Err(ConduitError::Unauthorized);
+        }
+
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        // a revoked token being presented again means it has leaked, as the legitimate client would hold
+        // the rotated token - revoke every token in the family so neither party can continue the session
+        let revoked = existing_token.revoked_at.is_none()
+            && self
+                .sessions_repository
+                .revoke_refresh_token(unit_of_work.as_mut(), existing_token.id)
+                .await?;
+
+        if !revoked {
+            warn!(
+                "refresh token reuse detected for user {:?}, revoking token family",
+                existing_token.user_id
+            );
+
+            self.sessions_repository
+                .revoke_refresh_token_family(unit_of_work.as_mut(), existing_token.family)
+                .await?;
+
+            unit_of_work.commit().await?;
+
+            return Err(ConduitError::Unauthorized);
+        }
+
+        let rotated_refresh_token = self.token_service.new_opaque_token();
+
+        info!("rotating refresh token for user {:?}", existing_token.user_id);
+        self.sessions_repository
+            .create_refresh_token(
+                unit_of_work.as_mut(),
+                existing_token.user_id,
+                self.token_service.hash_opaque_token(&rotated_refresh_token),
+                existing_token.family,
+                self.refresh_token_expiration(),
+            )
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        let user = self.users_repository.get_user_by_id(existing_token.user_id).await?;
+
+        if user.suspended_at.is_some() {
+            info!("user {:?} is suspended, refusing to refresh session", user.id);
+            return Err(ConduitError::Unauthorized);
+        }
+        let token = self.token_service.new_token(user.id, &user.email, user.role())?;
+
+        let mut user = user.into_dto(token);
+        user.refresh_token = Some(rotated_refresh_token);
+
+        Ok(user)
+    }
+
+    async fn revoke_session(&self, claims: TokenClaims, refresh_token: String) -> ConduitResult<()> {
+        let token_hash = self.token_service.hash_opaque_token(&refresh_token);
+
+        let existing_token = self.sessions_repository.get_refresh_token(token_hash).await?;
+
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        if let Some(existing_token) = existing_token {
+            // only allow users to revoke their own sessions
+            if existing_token.user_id == claims.user_id {
+                info!("revoking refresh token for user {:?}", claims.user_id);
+                self.sessions_repository
+                    .revoke_refresh_token(unit_of_work.as_mut(), existing_token.id)
+                    .await?;
+            }
+        }
+
+        if !claims.token_id.is_empty() {
+            info!("revoking access token for user {:?}", claims.user_id);
+            self.sessions_repository
+                .revoke_access_token(
+                    unit_of_work.as_mut(),
+                    claims.token_id,
+                    OffsetDateTime::from_unix_timestamp(claims.expires_at),
+                )
+                .await?;
+        }
+
+        unit_of_work.commit().await?;
+
+        Ok(())
+    }
+
+    async fn is_session_revoked(&self, token_id: String, user_id: i64, role: Role) -> ConduitResult<bool> {
+        // permissions come from the role within the token, so tokens issued before a role change must not be honoured
+        let revoked = self
+            .sessions_repository
+            .is_session_revoked(token_id, user_id, role.to_string())
+            .await?;
+
+        Ok(revoked)
+    }
+}
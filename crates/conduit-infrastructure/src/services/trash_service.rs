@@ -0,0 +1,137 @@
+use std::ops::{Add, Sub};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use itertools::Itertools;
+use sqlx::types::time::OffsetDateTime;
+use tracing::info;
+
+use conduit_core::articles::repository::DynArticlesRepository;
+use conduit_core::comments::repository::DynCommentsRepository;
+use conduit_core::config::AppConfig;
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::trash::service::TrashService;
+use conduit_domain::trash::{TrashedArticleDto, TrashedCommentDto};
+
+pub struct ConduitTrashService {
+    articles_repository: DynArticlesRepository,
+    comments_repository: DynCommentsRepository,
+    config: Arc<AppConfig>,
+}
+
+impl ConduitTrashService {
+    pub fn new(
+        articles_repository: DynArticlesRepository,
+        comments_repository: DynCommentsRepository,
+        config: Arc<AppConfig>,
+    ) -> Self {
+        Self {
+            articles_repository,
+            comments_repository,
+            config,
+        }
+    }
+
+    fn retention(&self) -> Duration {
+        Duration::from_secs(self.config.trash_retention_days * 24 * 60 * 60)
+    }
+
+    /// Items deleted at or before the cutoff have outlived the retention window and are due to be purged.
+    fn retention_cutoff(&self) -> OffsetDateTime {
+        OffsetDateTime::from(SystemTime::now().sub(self.retention()))
+    }
+}
+
+#[async_trait]
+impl TrashService for ConduitTrashService {
+    async fn get_trash(&self, user_id: i64) -> ConduitResult<(Vec<TrashedArticleDto>, Vec<TrashedCommentDto>)> {
+        let retention_cutoff = self.retention_cutoff();
+
+        info!("retrieving deleted articles and comments for user {:?}", user_id);
+        let articles = self
+            .articles_repository
+            .get_deleted_articles(user_id, retention_cutoff)
+            .await?
+            .into_iter()
+            .map(|article| {
+                let purge_at = article.deleted_at.add(self.retention());
+                article.into_dto(purge_at)
+            })
+            .collect_vec();
+
+        let comments = self
+            .comments_repository
+            .get_deleted_comments(user_id, retention_cutoff)
+            .await?
+            .into_iter()
+            .map(|comment| {
+                let purge_at = comment.deleted_at.add(self.retention());
+                comment.into_dto(purge_at)
+            })
+            .collect_vec();
+
+        Ok((articles, comments))
+    }
+
+    async fn restore_article(&self, user_id: i64, slug: String) -> ConduitResult<()> {
+        let article = self
+            .articles_repository
+            .get_deleted_article(slug)
+            .await?
+            // articles past the retention window are as good as gone, even if they have yet to be purged
+            .filter(|article| article.deleted_at > self.retention_cutoff())
+            .ok_or_else(|| ConduitError::NotFound(String::from("article was not found in the trash")))?;
+
+        // only the user that deleted the article may restore it, so authors cannot undo a moderator's removal
+        if article.deleted_by != Some(user_id) {
+            return Err(ConduitError::Forbidden);
+        }
+
+        info!("restoring article {:?} for user {:?}", article.id, user_id);
+        self.articles_repository.restore_article(article.id).await?;
+
+        Ok(())
+    }
+
+    async fn restore_comment(&self, user_id: i64, comment_id: i64) -> ConduitResult<()> {
+        let comment = self
+            .comments_repository
+            .get_deleted_comment(comment_id)
+            .await?
+            .filter(|comment| comment.deleted_at > self.retention_cutoff())
+            .ok_or_else(|| ConduitError::NotFound(String::from("comment was not found in the trash")))?;
+
+        if comment.deleted_by != Some(user_id) {
+            return Err(ConduitError::Forbidden);
+        }
+
+        info!("restoring comment {:?} for user {:?}", comment.id, user_id);
+        self.comments_repository.restore_comment(comment.id).await?;
+
+        Ok(())
+    }
+
+    async fn purge_expired(&self) -> ConduitResult<u64> {
+        let retention_cutoff = self.retention_cutoff();
+
+        // comments are purged first, as purging an article removes its comments along with it
+        let purged_comments = self
+            .comments_repository
+            .purge_deleted_comments(retention_cutoff)
+            .await?;
+        let purged_articles = self
+            .articles_repository
+            .purge_deleted_articles(retention_cutoff)
+            .await?;
+
+        if purged_comments > 0 || purged_articles > 0 {
+            info!(
+                "purged {} deleted articles and {} deleted comments",
+                purged_articles, purged_comments
+            );
+        }
+
+        Ok(purged_articles + purged_comments)
+    }
+}
@@ -0,0 +1,290 @@
+use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
+use tracing::{error, info};
+
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::two_factor::service::TwoFactorService;
+use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::totp_service::DynTotpService;
+use conduit_core::utils::unit_of_work::{DynUnitOfWork, DynUnitOfWorkFactory};
+use conduit_domain::two_factor::TwoFactorEnrollmentDto;
+use conduit_domain::users::UserDto;
+
+const RECOVERY_CODE_COUNT: usize = 10;
+const RECOVERY_CODE_LENGTH: usize = 10;
+const MAX_CHALLENGE_ATTEMPTS: i32 = 5;
+
+#[derive(Clone)]
+pub struct ConduitTwoFactorService {
+    users_repository: DynUsersRepository,
+    sessions_repository: DynSessionsRepository,
+    totp_service: DynTotpService,
+    token_service: DynTokenService,
+    unit_of_work_factory: DynUnitOfWorkFactory,
+}
+
+impl ConduitTwoFactorService {
+    pub fn new(
+        users_repository: DynUsersRepository,
+        sessions_repository: DynSessionsRepository,
+        totp_service: DynTotpService,
+        token_service: DynTokenService,
+        unit_of_work_factory: DynUnitOfWorkFactory,
+    ) -> Self {
+        Self {
+            users_repository,
+            sessions_repository,
+            totp_service,
+            token_service,
+            unit_of_work_factory,
+        }
+    }
+
+    fn invalid_code() -> ConduitError {
+        ConduitError::BadRequest(String::from("two-factor code is invalid"))
+    }
+
+    /// Recovery codes are displayed grouped for readability, so ignore grouping and casing when they are entered.
+    fn normalize_recovery_code(code: &str) -> String {
+        code.chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase()
+    }
+
+    fn new_recovery_codes(&self) -> Vec<String> {
+        (0..RECOVERY_CODE_COUNT)
+            .map(|_| {
+                let code = &self.token_service.new_opaque_token()[..RECOVERY_CODE_LENGTH];
+                format!(
+                    "{}-{}",
+                    &code[..RECOVERY_CODE_LENGTH / 2],
+                    &code[RECOVERY_CODE_LENGTH / 2..]
+                )
+            })
+            .collect()
+    }
+
+    /// Verifies either a current TOTP code or an unused recovery code, consuming it so that it cannot be used again.
+    async fn verify_second_factor(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user: &UserEntity,
+        code: &str,
+    ) -> ConduitResult<bool> {
+        let secret = user
+            .totp_secret
+            .as_ref()
+            .ok_or_else(|| ConduitError::BadRequest(String::from("two-factor authentication is not enabled")))?;
+
+        if let Some(step) = self.totp_service.verify_code(secret, code)? {
+            let unused = self
+                .users_repository
+                .update_user_totp_last_used_step(unit_of_work, user.id, step)
+                .await?;
+
+            return Ok(unused);
+        }
+
+        let code_hash = self
+            .token_service
+            .hash_opaque_token(&Self::normalize_recovery_code(code));
+
+        let redeemed = self
+            .users_repository
+            .use_recovery_code(unit_of_work, user.id, code_hash)
+            .await?;
+
+        if redeemed {
+            info!("user {:?} redeemed a recovery code", user.id);
+        }
+
+        Ok(redeemed)
+    }
+}
+
+#[async_trait]
+impl TwoFactorService for ConduitTwoFactorService {
+    async fn begin_enrollment(&self, user_id: i64) -> ConduitResult<TwoFactorEnrollmentDto> {
+        info!("retrieving user {:?}", user_id);
+        let user = self.users_repository.get_user_by_id(user_id).await?;
+
+        if user.totp_enabled_at.is_some() {
+            return Err(ConduitError::BadRequest(String::from(
+                "two-factor authentication is already enabled",
+            )));
+        }
+
+        info!("generating TOTP secret for user {:?}", user_id);
+        let secret = self.totp_service.new_secret();
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        self.users_repository
+            .update_user_totp_secret(unit_of_work.as_mut(), user_id, Some(secret.clone()))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(TwoFactorEnrollmentDto {
+            provisioning_uri: self.totp_service.provisioning_uri(&secret, &user.email),
+            secret,
+        })
+    }
+
+    async fn confirm_enrollment(&self, user_id: i64, code: String) -> ConduitResult<Vec<String>> {
+        info!("retrieving user {:?}", user_id);
+        let user = self.users_repository.get_user_by_id(user_id).await?;
+
+        if user.totp_enabled_at.is_some() {
+            return Err(ConduitError::BadRequest(String::from(
+                "two-factor authentication is already enabled",
+            )));
+        }
+
+        let secret = user
+            .totp_secret
+            .ok_or_else(|| ConduitError::BadRequest(String::from("two-factor enrollment has not been started")))?;
+
+        let step = self.totp_service.verify_code(&secret, &code)?.ok_or_else(|| {
+            error!("invalid code confirming two-factor enrollment for user {:?}", user_id);
+            Self::invalid_code()
+        })?;
+
+        info!("enabling two-factor authentication for user {:?}", user_id);
+        let recovery_codes = self.new_recovery_codes();
+        let code_hashes = recovery_codes
+            .iter()
+            .map(|code| {
+                self.token_service
+                    .hash_opaque_token(&Self::normalize_recovery_code(code))
+            })
+            .collect();
+
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        self.users_repository
+            .update_user_totp_last_used_step(unit_of_work.as_mut(), user_id, step)
+            .await?;
+
+        self.users_repository
+            .enable_user_totp(unit_of_work.as_mut(), user_id)
+            .await?;
+
+        self.users_repository
+            .delete_recovery_codes(unit_of_work.as_mut(), user_id)
+            .await?;
+
+        self.users_repository
+            .create_recovery_codes(unit_of_work.as_mut(), user_id, code_hashes)
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(recovery_codes)
+    }
+
+    async fn disable_two_factor(&self, user_id: i64, code: String) -> ConduitResult<()> {
+        info!("retrieving user {:?}", user_id);
+        let user = self.users_repository.get_user_by_id(user_id).await?;
+
+        if user.totp_enabled_at.is_none() {
+            return Err(ConduitError::BadRequest(String::from(
+                "two-factor authentication is not enabled",
+            )));
+        }
+
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        if !self.verify_second_factor(unit_of_work.as_mut(), &user, &code).await? {
+            error!(
+                "invalid code disabling two-factor authentication for user {:?}",
+                user_id
+            );
+            return Err(Self::invalid_code());
+        }
+
+        info!("disabling two-factor authentication for user {:?}", user_id);
+        self.users_repository
+            .update_user_totp_secret(unit_of_work.as_mut(), user_id, None)
+            .await?;
+
+        self.users_repository
+            .delete_recovery_codes(unit_of_work.as_mut(), user_id)
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(())
+    }
+
+    async fn complete_login(&self, challenge_token: String, code: String) -> ConduitResult<UserDto> {
+        let token_hash = self.token_service.hash_opaque_token(&challenge_token);
+        let existing_challenge = self.sessions_repository.get_login_challenge(token_hash).await?;
+
+        if existing_challenge.is_none() {
+            error!("login challenge was not found");
+            return Err(ConduitError::Unauthorized);
+        }
+
+        let challenge = existing_challenge.unwrap();
+
+        if challenge.used_at.is_some()
+            || challenge.expires_at < OffsetDateTime::now_utc()
+            || challenge.attempts >= MAX_CHALLENGE_ATTEMPTS
+        {
+            error!("login challenge {:?} has been used, expired or exhausted", challenge.id);
+            return Err(ConduitError::Unauthorized);
+        }
+
+        info!("retrieving user {:?}", challenge.user_id);
+        let user = self.users_repository.get_user_by_id(challenge.user_id).await?;
+
+        if user.suspended_at.is_some() {
+            error!("login challenge for suspended user {:?}", user.id);
+            return Err(ConduitError::Forbidden);
+        }
+
+        if user.totp_enabled_at.is_none() {
+            error!(
+                "user {:?} disabled two-factor authentication since the challenge was issued",
+                user.id
+            );
+            return Err(ConduitError::Unauthorized);
+        }
+
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        if !self.verify_second_factor(unit_of_work.as_mut(), &user, &code).await? {
+            drop(unit_of_work);
+
+            let attempts = self
+                .sessions_repository
+                .record_login_challenge_attempt(challenge.id)
+                .await?;
+
+            error!(
+                "invalid code for login challenge {:?}, {:?} of {:?} attempts used",
+                challenge.id, attempts, MAX_CHALLENGE_ATTEMPTS
+            );
+            return Err(ConduitError::Unauthorized);
+        }
+
+        let completed = self
+            .sessions_repository
+            .use_login_challenge(unit_of_work.as_mut(), challenge.id)
+            .await?;
+
+        if !completed {
+            return Err(ConduitError::Unauthorized);
+        }
+
+        unit_of_work.commit().await?;
+
+        info!("login challenge completed for user {:?}, generating token", user.id);
+        let token = self.token_service.new_token(user.id, &user.email, user.role())?;
+
+        Ok(user.into_dto(token))
+    }
+}
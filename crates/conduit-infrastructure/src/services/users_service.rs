@@ -1,33 +1,149 @@
+use std::ops::Add;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
 use async_trait::async_trait;
-use tracing::{error, info};
+use sqlx::types::time::OffsetDateTime;
+use tracing::{error, info, warn};
 
+use conduit_core::config::AppConfig;
 use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::sessions::repository::DynSessionsRepository;
 use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::users::roles::Role;
 use conduit_core::users::service::UsersService;
+use conduit_core::utils::mail_service::DynMailService;
 use conduit_core::utils::security_service::DynSecurityService;
 use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::unit_of_work::{DynUnitOfWork, DynUnitOfWorkFactory};
 use conduit_domain::users::requests::{LoginUserDto, RegisterUserDto, UpdateUserDto};
-use conduit_domain::users::UserDto;
+use conduit_domain::two_factor::TwoFactorChallengeDto;
+use conduit_domain::users::{LoginResultDto, UserDto};
+
+/// A throwaway password, whose hash is verified against on logins for emails that do not belong to any user.
+pub(crate) const DUMMY_PASSWORD: &str = "conduit dummy password";
 
 #[derive(Clone)]
 pub struct ConduitUsersService {
     repository: DynUsersRepository,
+    sessions_repository: DynSessionsRepository,
     security_service: DynSecurityService,
     token_service: DynTokenService,
+    mail_service: DynMailService,
+    unit_of_work_factory: DynUnitOfWorkFactory,
+    config: Arc<AppConfig>,
+    dummy_password_hash: String,
 }
 
 impl ConduitUsersService {
     pub fn new(
         repository: DynUsersRepository,
+        sessions_repository: DynSessionsRepository,
         security_service: DynSecurityService,
         token_service: DynTokenService,
+        mail_service: DynMailService,
+        unit_of_work_factory: DynUnitOfWorkFactory,
+        config: Arc<AppConfig>,
     ) -> Self {
+        // hashed with the configured parameters so unknown emails cost as much to verify as real accounts
+        let dummy_password_hash = security_service
+            .hash_password(DUMMY_PASSWORD)
+            .expect("dummy password hash could not be computed");
+
         Self {
             repository,
+            sessions_repository,
             security_service,
             token_service,
+            mail_service,
+            unit_of_work_factory,
+            config,
+            dummy_password_hash,
         }
     }
+
+    fn invalid_password_reset_token() -> ConduitError {
+        ConduitError::BadRequest(String::from("password reset token is invalid or has expired"))
+    }
+
+    fn invalid_email_verification_token() -> ConduitError {
+        ConduitError::BadRequest(String::from("email verification token is invalid or has expired"))
+    }
+
+    /// Records the failed login against each throttle key, locking out any key that has exceeded its allowed failures
+    /// for exponentially longer with each further failure.
+    async fn record_failed_login(&self, throttle_keys: &[(String, i32)]) -> ConduitResult<()> {
+        for (key, max_failed_attempts) in throttle_keys {
+            let failures = self
+                .sessions_repository
+                .record_login_failure(key.clone(), self.config.login_failure_window_minutes)
+                .await?;
+
+            if failures < *max_failed_attempts {
+                continue;
+            }
+
+            let exponent = (failures - max_failed_attempts).min(16) as u32;
+            let lockout_seconds = self
+                .config
+                .login_lockout_seconds
+                .saturating_mul(2_u64.pow(exponent))
+                .min(self.config.login_max_lockout_seconds);
+
+            warn!(
+                "locking out {:?} for {:?} seconds after {:?} failed logins",
+                key, lockout_seconds, failures
+            );
+            let scope = key.split(':').next().unwrap_or_default().to_owned();
+            metrics::increment_counter!("login_lockouts_total", "scope" => scope);
+
+            self.sessions_repository
+                .lock_login(
+                    key.clone(),
+                    OffsetDateTime::from(SystemTime::now().add(Duration::from_secs(lockout_seconds))),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Stages a new email verification token within the unit of work, returning the raw token to be mailed once the
+    /// unit of work has been committed.
+    async fn create_email_verification_token(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        user_id: i64,
+        email: &str,
+    ) -> ConduitResult<String> {
+        let verification_token = self.token_service.new_opaque_token();
+        let lifetime = Duration::from_secs(self.config.email_verification_token_lifetime_hours * 60 * 60);
+
+        info!("creating email verification token for user {:?}", user_id);
+        self.repository
+            .create_email_verification_token(
+                unit_of_work,
+                user_id,
+                String::from(email),
+                self.token_service.hash_opaque_token(&verification_token),
+                OffsetDateTime::from(SystemTime::now().add(lifetime)),
+            )
+            .await?;
+
+        Ok(verification_token)
+    }
+
+    async fn send_email_verification(&self, email: &str, verification_token: &str) -> ConduitResult<()> {
+        let body = format!(
+            "Confirm your email address for your Conduit account by visiting the following link, it will expire in \
+            {} hours:\n\n{}/users/verify?token={}",
+            self.config.email_verification_token_lifetime_hours, self.config.api_url, verification_token
+        );
+
+        self.mail_service
+            .send_mail(email, "Verify your Conduit email address", &body)
+            .await
+    }
 }
 
 #[async_trait]
@@ -51,41 +167,146 @@ impl UsersService for ConduitUsersService {
         let hashed_password = self.security_service.hash_password(&password)?;
 
         info!("password hashed successfully, creating user {:?}", email);
-        let created_user = self.repository.create_user(&email, &username, &hashed_password).await?;
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let created_user = self
+            .repository
+            .create_user(unit_of_work.as_mut(), &email, &username, &hashed_password)
+            .await?;
+
+        let verification_token = self
+            .create_email_verification_token(unit_of_work.as_mut(), created_user.id, &created_user.email)
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        // the user exists at this point, so a failed delivery is only logged and can be recovered from with a resend
+        info!("user {:?} created, mailing email verification token", created_user.id);
+        if let Err(err) = self
+            .send_email_verification(&created_user.email, &verification_token)
+            .await
+        {
+            warn!(
+                "could not mail email verification token to user {:?}: {:?}",
+                created_user.id, err
+            );
+        }
 
         info!("user successfully created, generating token");
-        let token = self.token_service.new_token(created_user.id, &created_user.email)?;
+        let token = self
+            .token_service
+            .new_token(created_user.id, &created_user.email, created_user.role())?;
 
         Ok(created_user.into_dto(token))
     }
 
-    async fn login_user(&self, request: LoginUserDto) -> ConduitResult<UserDto> {
+    async fn login_user(&self, request: LoginUserDto, client_address: Option<String>) -> ConduitResult<LoginResultDto> {
         let email = request.email.unwrap();
         let attempted_password = request.password.unwrap();
 
-        info!("searching for existing user {:?}", email);
-        let existing_user = self.repository.get_user_by_email(&email).await?;
+        // throttle on the attempted email rather than the user, so that lockouts do not reveal which emails exist
+        let account_key = format!("account:{}", email.to_lowercase());
+        let mut throttle_keys = vec![(account_key.clone(), self.config.login_max_failed_attempts)];
 
-        if existing_user.is_none() {
-            return Err(ConduitError::NotFound(String::from("user email does not exist")));
+        if let Some(client_address) = client_address {
+            throttle_keys.push((
+                format!("ip:{}", client_address),
+                self.config.login_max_failed_attempts_per_ip,
+            ));
         }
 
-        let user = existing_user.unwrap();
+        let lockout = self
+            .sessions_repository
+            .get_login_lockout(throttle_keys.iter().map(|(key, _)| key.clone()).collect())
+            .await?;
+
+        if let Some(locked_until) = lockout {
+            error!("login for user {:?} is locked out until {:?}", email, locked_until);
+            metrics::increment_counter!("login_failures_total", "reason" => "locked_out");
+            let retry_after_seconds = (locked_until - OffsetDateTime::now_utc()).whole_seconds().max(1);
+            return Err(ConduitError::TooManyLoginAttempts(retry_after_seconds as u64));
+        }
 
-        info!("user found, verifying password hash for user {:?}", email);
-        let is_valid_login_attempt = self
-            .security_service
-            .verify_password(&user.password, attempted_password)?;
+        info!("searching for existing user {:?}", email);
+        let existing_user = self.repository.get_user_by_email(&email).await?;
 
+        let is_valid_login_attempt = match existing_user.as_ref() {
+            Some(user) => {
+                info!("user found, verifying password hash for user {:?}", email);
+                self.security_service
+                    .verify_password(&user.password, attempted_password.clone())?
+            },
+            None => {
+                // spend the same effort on unknown emails, otherwise response times would reveal which accounts exist
+                info!(
+                    "user not found, verifying password against a dummy hash for {:?}",
+                    email
+                );
+                self.security_service
+                    .verify_password(&self.dummy_password_hash, attempted_password.clone())?;
+                false
+            },
+        };
+
+        // respond the same whether the email or the password is wrong, so logins cannot be used to discover accounts
         if !is_valid_login_attempt {
             error!("invalid login attempt for user {:?}", email);
+            metrics::increment_counter!("login_failures_total", "reason" => "invalid_credentials");
+            self.record_failed_login(&throttle_keys).await?;
             return Err(ConduitError::InvalidLoginAttmpt);
         }
 
+        let user = existing_user.unwrap();
+        self.sessions_repository.clear_login_failures(account_key).await?;
+
+        if user.suspended_at.is_some() {
+            error!("login attempt for suspended user {:?}", email);
+            return Err(ConduitError::Forbidden);
+        }
+
+        // the raw password is only ever available on login, so take the opportunity to migrate outdated hashes
+        if self.security_service.needs_rehash(&user.password)? {
+            info!("password hash for user {:?} is outdated, re-hashing", email);
+            let rehashed_password = self.security_service.hash_password(&attempted_password)?;
+            let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+            let rehashed = self
+                .repository
+                .update_user_password(unit_of_work.as_mut(), user.id, rehashed_password)
+                .await;
+
+            match rehashed {
+                Ok(_) => unit_of_work.commit().await?,
+                Err(err) => warn!("could not re-hash password for user {:?}: {:?}", email, err),
+            }
+        }
+
+        if user.totp_enabled_at.is_some() {
+            info!(
+                "user {:?} has two-factor authentication enabled, issuing login challenge",
+                email
+            );
+            let challenge_token = self.token_service.new_opaque_token();
+            let lifetime = Duration::from_secs(self.config.two_factor_challenge_lifetime_minutes * 60);
+
+            self.sessions_repository
+                .create_login_challenge(
+                    user.id,
+                    self.token_service.hash_opaque_token(&challenge_token),
+                    OffsetDateTime::from(SystemTime::now().add(lifetime)),
+                )
+                .await?;
+
+            return Ok(LoginResultDto::TwoFactorRequired(TwoFactorChallengeDto {
+                challenge_token,
+                expires_in: lifetime.as_secs(),
+            }));
+        }
+
         info!("user login successful, generating token");
-        let token = self.token_service.new_token(user.id, &user.email)?;
+        let token = self.token_service.new_token(user.id, &user.email, user.role())?;
 
-        Ok(user.into_dto(token))
+        Ok(LoginResultDto::Authenticated(user.into_dto(token)))
     }
 
     async fn get_current_user(&self, user_id: i64) -> ConduitResult<UserDto> {
@@ -93,7 +314,9 @@ impl UsersService for ConduitUsersService {
         let user = self.repository.get_user_by_id(user_id).await?;
 
         info!("user found with email {:?}, generating new token", user.email);
-        let token = self.token_service.new_token(user.id, user.email.as_str())?;
+        let token = self
+            .token_service
+            .new_token(user.id, user.email.as_str(), user.role())?;
 
         Ok(user.into_dto(token))
     }
@@ -102,7 +325,8 @@ impl UsersService for ConduitUsersService {
         info!("retrieving user {:?}", user_id);
         let user = self.repository.get_user_by_id(user_id).await?;
 
-        let updated_email = request.email.unwrap_or(user.email);
+        let updated_email = request.email.unwrap_or_else(|| user.email.clone());
+        let email_changed = updated_email != user.email;
         let updated_username = request.username.unwrap_or(user.username);
         let updated_bio = request.bio.unwrap_or(user.bio);
         let updated_image = request.image.unwrap_or(user.image);
@@ -116,9 +340,12 @@ impl UsersService for ConduitUsersService {
         }
 
         info!("updating user {:?}", user_id);
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
         let updated_user = self
             .repository
             .update_user(
+                unit_of_work.as_mut(),
                 user_id,
                 updated_email.clone(),
                 updated_username,
@@ -128,9 +355,226 @@ impl UsersService for ConduitUsersService {
             )
             .await?;
 
+        // changing the email resets verification, so the new address needs to be confirmed
+        let verification_token = if email_changed {
+            Some(
+                self.create_email_verification_token(unit_of_work.as_mut(), user_id, &updated_email)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        unit_of_work.commit().await?;
+
+        if let Some(verification_token) = verification_token {
+            info!("email for user {:?} changed, mailing email verification token", user_id);
+            if let Err(err) = self.send_email_verification(&updated_email, &verification_token).await {
+                warn!(
+                    "could not mail email verification token to user {:?}: {:?}",
+                    user_id, err
+                );
+            }
+        }
+
         info!("user {:?} updated, generating a new token", user_id);
-        let token = self.token_service.new_token(user_id, updated_email.as_str())?;
+        let token = self
+            .token_service
+            .new_token(user_id, updated_email.as_str(), updated_user.role())?;
 
         Ok(updated_user.into_dto(token))
     }
+
+    async fn verify_email(&self, token: String) -> ConduitResult<()> {
+        let token_hash = self.token_service.hash_opaque_token(&token);
+        let existing_token = self.repository.get_email_verification_token(token_hash).await?;
+
+        if existing_token.is_none() {
+            return Err(Self::invalid_email_verification_token());
+        }
+
+        let verification_token = existing_token.unwrap();
+
+        if verification_token.used_at.is_some() || verification_token.expires_at < OffsetDateTime::now_utc() {
+            info!(
+                "email verification token {:?} has been used or has expired",
+                verification_token.id
+            );
+            return Err(Self::invalid_email_verification_token());
+        }
+
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let redeemed = self
+            .repository
+            .use_email_verification_token(unit_of_work.as_mut(), verification_token.id)
+            .await?;
+
+        if !redeemed {
+            return Err(Self::invalid_email_verification_token());
+        }
+
+        info!("verifying email for user {:?}", verification_token.user_id);
+        let verified = self
+            .repository
+            .verify_user_email(
+                unit_of_work.as_mut(),
+                verification_token.user_id,
+                verification_token.email,
+            )
+            .await?;
+
+        if !verified {
+            info!(
+                "user {:?} has changed their email since the token was issued",
+                verification_token.user_id
+            );
+            return Err(Self::invalid_email_verification_token());
+        }
+
+        unit_of_work.commit().await?;
+
+        Ok(())
+    }
+
+    async fn resend_email_verification(&self, user_id: i64) -> ConduitResult<()> {
+        info!("retrieving user {:?}", user_id);
+        let user = self.repository.get_user_by_id(user_id).await?;
+
+        if user.verified_at.is_some() {
+            return Err(ConduitError::BadRequest(String::from("email is already verified")));
+        }
+
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+        let verification_token = self
+            .create_email_verification_token(unit_of_work.as_mut(), user.id, &user.email)
+            .await?;
+
+        // only keep the new token when it could be delivered, the caller is told to try again otherwise
+        info!("email verification token created, mailing user {:?}", user.id);
+        self.send_email_verification(&user.email, &verification_token).await?;
+        unit_of_work.commit().await?;
+
+        Ok(())
+    }
+
+    async fn ensure_verified(&self, user_id: i64) -> ConduitResult<()> {
+        if !self.config.require_email_verification {
+            return Ok(());
+        }
+
+        let user = self.repository.get_user_by_id(user_id).await?;
+
+        if user.verified_at.is_none() {
+            error!("user {:?} has not verified their email", user_id);
+            return Err(ConduitError::Forbidden);
+        }
+
+        Ok(())
+    }
+
+    async fn update_user_role(&self, username: String, role: Role) -> ConduitResult<()> {
+        info!("assigning role {:?} to user {:?}", role, username);
+        let updated = self.repository.update_user_role(username, role.to_string()).await?;
+
+        if !updated {
+            return Err(ConduitError::NotFound(String::from("user was not found")));
+        }
+
+        Ok(())
+    }
+
+    async fn request_password_reset(&self, email: String) -> ConduitResult<()> {
+        info!("searching for existing user {:?}", email);
+        let existing_user = self.repository.get_user_by_email(&email).await?;
+
+        // respond the same whether or not the user exists, so the endpoint cannot be used to discover accounts
+        if existing_user.is_none() {
+            info!("password reset requested for unknown user {:?}", email);
+            return Ok(());
+        }
+
+        let user = existing_user.unwrap();
+        let reset_token = self.token_service.new_opaque_token();
+        let lifetime = Duration::from_secs(self.config.password_reset_token_lifetime_minutes * 60);
+
+        info!("creating password reset token for user {:?}", user.id);
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        // only the most recently requested token should be redeemable
+        self.repository
+            .invalidate_password_reset_tokens(unit_of_work.as_mut(), user.id)
+            .await?;
+
+        self.repository
+            .create_password_reset_token(
+                unit_of_work.as_mut(),
+                user.id,
+                self.token_service.hash_opaque_token(&reset_token),
+                OffsetDateTime::from(SystemTime::now().add(lifetime)),
+            )
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        info!("password reset token created, mailing user {:?}", user.id);
+        let body = format!(
+            "A password reset was requested for your Conduit account. Use the following token to choose a new \
+            password, it will expire in {} minutes:\n\n{}\n\nIf you did not request a reset, you can ignore this email.",
+            self.config.password_reset_token_lifetime_minutes, reset_token
+        );
+
+        // a failed delivery must not change the response either, otherwise it would only ever surface for known emails
+        if let Err(err) = self
+            .mail_service
+            .send_mail(&user.email, "Reset your Conduit password", &body)
+            .await
+        {
+            warn!("could not mail password reset token to user {:?}: {:?}", user.id, err);
+        }
+
+        Ok(())
+    }
+
+    async fn confirm_password_reset(&self, token: String, password: String) -> ConduitResult<()> {
+        let token_hash = self.token_service.hash_opaque_token(&token);
+        let existing_token = self.repository.get_password_reset_token(token_hash).await?;
+
+        if existing_token.is_none() {
+            return Err(Self::invalid_password_reset_token());
+        }
+
+        let reset_token = existing_token.unwrap();
+
+        if reset_token.used_at.is_some() || reset_token.expires_at < OffsetDateTime::now_utc() {
+            info!("password reset token {:?} has been used or has expired", reset_token.id);
+            return Err(Self::invalid_password_reset_token());
+        }
+
+        let hashed_password = self.security_service.hash_password(&password)?;
+        let mut unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        // marking the token as used guards against concurrent redemptions, dropping the unit of work rolls back
+        let redeemed = self
+            .repository
+            .use_password_reset_token(unit_of_work.as_mut(), reset_token.id)
+            .await?;
+
+        if !redeemed {
+            return Err(Self::invalid_password_reset_token());
+        }
+
+        info!("resetting password for user {:?}", reset_token.user_id);
+        self.repository
+            .update_user_password(unit_of_work.as_mut(), reset_token.user_id, hashed_password)
+            .await?;
+
+        self.sessions_repository
+            .revoke_user_refresh_tokens(unit_of_work.as_mut(), reset_token.user_id)
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(())
+    }
 }
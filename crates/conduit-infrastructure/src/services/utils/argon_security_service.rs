@@ -1,11 +1,14 @@
 use std::sync::Arc;
 
-use argon2::Config;
+use argon2::{Config, Variant, Version};
+use rand::RngCore;
 
 use conduit_core::config::AppConfig;
 use conduit_core::errors::{ConduitError, ConduitResult};
 use conduit_core::utils::security_service::SecurityService;
 
+const SALT_LENGTH: usize = 16;
+
 pub struct ArgonSecurityService {
     config: Arc<AppConfig>,
 }
@@ -14,13 +17,33 @@ impl ArgonSecurityService {
     pub fn new(config: Arc<AppConfig>) -> Self {
         Self { config }
     }
+
+    fn hash_config(&self) -> ConduitResult<Config<'static>> {
+        let variant = Variant::from_str(&self.config.argon_variant).map_err(|_| {
+            ConduitError::InternalServerErrorWithContext(format!(
+                "unsupported argon variant {:?}",
+                self.config.argon_variant
+            ))
+        })?;
+
+        Ok(Config {
+            variant,
+            version: Version::Version13,
+            mem_cost: self.config.argon_memory_cost_kib,
+            time_cost: self.config.argon_iterations,
+            ..Config::default()
+        })
+    }
 }
 
 impl SecurityService for ArgonSecurityService {
     fn hash_password(&self, raw_password: &str) -> ConduitResult<String> {
+        let mut salt = [0_u8; SALT_LENGTH];
+        rand::thread_rng().fill_bytes(&mut salt);
+
         let password_bytes = raw_password.as_bytes();
-        let hashed_password =
-            argon2::hash_encoded(password_bytes, self.config.argon_salt.as_bytes(), &Config::default()).unwrap();
+        let hashed_password = argon2::hash_encoded(password_bytes, &salt, &self.hash_config()?)
+            .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
 
         Ok(hashed_password)
     }
@@ -31,4 +54,24 @@ impl SecurityService for ArgonSecurityService {
 
         Ok(hashes_match)
     }
+
+    fn needs_rehash(&self, stored_password: &str) -> ConduitResult<bool> {
+        let config = self.hash_config()?;
+
+        // encoded hashes take the form of $argon2id$v=19$m=19456,t=2,p=1$<salt>$<hash>
+        let segments: Vec<&str> = stored_password.split('$').collect();
+
+        if segments.len() != 6 {
+            return Ok(true);
+        }
+
+        let expected_version = format!("v={}", config.version.as_u32());
+        let expected_parameters = format!("m={},t={},p={}", config.mem_cost, config.time_cost, config.lanes);
+        let legacy_salt = base64::encode_config(self.config.argon_salt.as_bytes(), base64::STANDARD_NO_PAD);
+
+        Ok(segments[1] != config.variant.as_lowercase_str()
+            || segments[2] != expected_version
+            || segments[3] != expected_parameters
+            || segments[4] == legacy_salt)
+    }
 }
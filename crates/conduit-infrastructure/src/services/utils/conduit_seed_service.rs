@@ -6,7 +6,8 @@ use conduit_core::comments::service::DynCommentsService;
 use conduit_core::errors::ConduitResult;
 use conduit_core::profiles::service::DynProfilesService;
 use conduit_core::users::service::DynUsersService;
-use conduit_domain::users::requests::{LoginUserDto, RegisterUserDto};
+use conduit_domain::articles::models::CreateArticleDto;
+use conduit_domain::users::requests::RegisterUserDto;
 use conduit_domain::users::UserDto;
 
 use crate::service_register::ServiceRegister;
@@ -41,13 +42,11 @@ impl ConduitSeedService {
     }
 
     pub async fn seed(&self) -> ConduitResult<()> {
-        // assume that if we have an active user in the users table, data has been seeded
+        // assume that if we have an active user in the users table, data has been seeded, checking the profile rather
+        // than logging in so that seeding a fresh database does not count as a failed login
         let seed_data_exists = self
-            .users_service
-            .login_user(LoginUserDto {
-                email: Some(String::from(*TEST_USER_1_EMAIL)),
-                password: Some(String::from(*TEST_USER_1_PASSWORD)),
-            })
+            .profiles_service
+            .get_profile(*TEST_USER_1_USERNAME, None)
             .await
             .is_ok();
 
@@ -90,30 +89,42 @@ impl ConduitSeedService {
             .articles_service
             .create_article(
                 created_user_1.id,
-                String::from("testuser1 article 1"),
-                String::from("testuser1 description 1"),
-                String::from("testuser1 body 1"),
-                vec![String::from("tag1"), String::from("tag2")],
+                CreateArticleDto {
+                    title: Some(String::from("testuser1 article 1")),
+                    description: Some(String::from("testuser1 description 1")),
+                    body: Some(String::from("testuser1 body 1")),
+                    tag_list: vec![String::from("tag1"), String::from("tag2")],
+                    status: None,
+                    publish_at: None,
+                },
             )
             .await?;
 
         self.articles_service
             .create_article(
                 created_user_1.id,
-                String::from("testuser1 article 2"),
-                String::from("testuser1 description 2"),
-                String::from("testuser1 body 2"),
-                vec![String::from("tag2"), String::from("tag3")],
+                CreateArticleDto {
+                    title: Some(String::from("testuser1 article 2")),
+                    description: Some(String::from("testuser1 description 2")),
+                    body: Some(String::from("testuser1 body 2")),
+                    tag_list: vec![String::from("tag2"), String::from("tag3")],
+                    status: None,
+                    publish_at: None,
+                },
             )
             .await?;
 
         self.articles_service
             .create_article(
                 created_user_2.id,
-                String::from("testuser2 article 1"),
-                String::from("testuser2 description 1"),
-                String::from("testuser2 body 1"),
-                vec![],
+                CreateArticleDto {
+                    title: Some(String::from("testuser2 article 1")),
+                    description: Some(String::from("testuser2 description 1")),
+                    body: Some(String::from("testuser2 body 1")),
+                    tag_list: vec![],
+                    status: None,
+                    publish_at: None,
+                },
             )
             .await?;
 
@@ -124,6 +135,7 @@ impl ConduitSeedService {
             .add_comment(
                 created_user_2.id,
                 article_1.slug.clone(),
+                None,
                 String::from("testuser2 comments on article 1"),
             )
             .await?;
@@ -133,6 +145,7 @@ impl ConduitSeedService {
             .add_comment(
                 created_user_2.id,
                 article_1.slug,
+                None,
                 String::from("testuser2 comments on article 2"),
             )
             .await?;
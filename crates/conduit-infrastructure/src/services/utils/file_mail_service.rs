@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::types::time::OffsetDateTime;
+use tracing::info;
+
+use conduit_core::config::AppConfig;
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::utils::mail_service::MailService;
+
+/// A mail service for tests and local development, writing each mail to the configured directory, or the logs
+/// when no directory is configured, rather than delivering it.
+pub struct FileMailService {
+    config: Arc<AppConfig>,
+}
+
+impl FileMailService {
+    pub fn new(config: Arc<AppConfig>) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl MailService for FileMailService {
+    async fn send_mail(&self, to: &str, subject: &str, body: &str) -> ConduitResult<()> {
+        let mail = format!(
+            "From: {}\nTo: {}\nSubject: {}\n\n{}\n",
+            self.config.mail_from, to, subject, body
+        );
+
+        match &self.config.mail_directory {
+            Some(directory) if !directory.is_empty() => {
+                let file_name = format!("{}-{}.eml", OffsetDateTime::now_utc().unix_timestamp_nanos(), to);
+                let path = PathBuf::from(directory).join(file_name);
+
+                info!("writing mail for {:?} to {:?}", to, path);
+                tokio::fs::create_dir_all(directory)
+                    .await
+                    .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
+
+                tokio::fs::write(&path, mail)
+                    .await
+                    .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
+            },
+            _ => info!("sending mail:\n{}", mail),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use base32::Alphabet;
+use hmac::{Hmac, Mac};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use rand::RngCore;
+use sha1::Sha1;
+use sqlx::types::time::OffsetDateTime;
+
+use conduit_core::config::AppConfig;
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::utils::totp_service::TotpService;
+
+type HmacSha1 = Hmac<Sha1>;
+
+/// Authenticator apps default to, and many only support, SHA1 with six digit codes that rotate every 30 seconds.
+const SECRET_LENGTH: usize = 20;
+const CODE_DIGITS: usize = 6;
+const STEP_SECONDS: i64 = 30;
+const ALLOWED_DRIFT_STEPS: i64 = 1;
+const SECRET_ALPHABET: Alphabet = Alphabet::RFC4648 { padding: false };
+
+/// An RFC 6238 implementation of time-based one-time passwords.
+pub struct HmacTotpService {
+    config: Arc<AppConfig>,
+}
+
+impl HmacTotpService {
+    pub fn new(config: Arc<AppConfig>) -> Self {
+        Self { config }
+    }
+
+    fn code_for_step(secret: &[u8], step: i64) -> ConduitResult<String> {
+        let mut mac = HmacSha1::new_from_slice(secret)
+            .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
+        mac.update(&step.to_be_bytes());
+        let digest = mac.finalize().into_bytes();
+
+        // dynamic truncation, as described in RFC 4226
+        let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+        let truncated = u32::from_be_bytes([
+            digest[offset] & 0x7f,
+            digest[offset + 1],
+            digest[offset + 2],
+            digest[offset + 3],
+        ]);
+
+        Ok(format!(
+            "{:0width$}",
+            truncated % 10_u32.pow(CODE_DIGITS as u32),
+            width = CODE_DIGITS
+        ))
+    }
+
+    /// Compares the codes in constant time, so response timing gives nothing away about how close a guess was.
+    fn codes_match(expected: &str, attempted: &str) -> bool {
+        expected.len() == attempted.len()
+            && expected
+                .bytes()
+                .zip(attempted.bytes())
+                .fold(0, |difference, (left, right)| difference | (left ^ right))
+                == 0
+    }
+}
+
+impl TotpService for HmacTotpService {
+    fn new_secret(&self) -> String {
+        let mut secret = vec![0_u8; SECRET_LENGTH];
+        rand::thread_rng().fill_bytes(&mut secret);
+        base32::encode(SECRET_ALPHABET, &secret)
+    }
+
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> String {
+        let issuer = utf8_percent_encode(&self.config.two_factor_issuer, NON_ALPHANUMERIC).to_string();
+        let account_name = utf8_percent_encode(account_name, NON_ALPHANUMERIC).to_string();
+
+        format!(
+            "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+            issuer, account_name, secret, issuer, CODE_DIGITS, STEP_SECONDS
+        )
+    }
+
+    fn verify_code(&self, secret: &str, code: &str) -> ConduitResult<Option<i64>> {
+        let decoded_secret = base32::decode(SECRET_ALPHABET, secret).ok_or_else(|| {
+            ConduitError::InternalServerErrorWithContext(String::from("stored TOTP secret is not valid base32"))
+        })?;
+
+        let attempted_code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+
+        if attempted_code.len() != CODE_DIGITS || !attempted_code.chars().all(|c| c.is_ascii_digit()) {
+            return Ok(None);
+        }
+
+        let current_step = OffsetDateTime::now_utc().unix_timestamp() / STEP_SECONDS;
+
+        for step in (current_step - ALLOWED_DRIFT_STEPS)..=(current_step + ALLOWED_DRIFT_STEPS) {
+            if Self::codes_match(&Self::code_for_step(&decoded_secret, step)?, &attempted_code) {
+                return Ok(Some(step));
+            }
+        }
+
+        Ok(None)
+    }
+}
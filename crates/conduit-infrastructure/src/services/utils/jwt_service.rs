@@ -1,14 +1,18 @@
 use std::ops::Add;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 
 use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use rand::RngCore;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use sqlx::types::time::OffsetDateTime;
 
 use conduit_core::config::AppConfig;
 use conduit_core::errors::{ConduitError, ConduitResult};
-use conduit_core::utils::token_service::TokenService;
+use conduit_core::users::roles::Role;
+use conduit_core::utils::token_service::{TokenClaims, TokenService};
 
 /// Our claims struct, it needs to derive `Serialize` and/or `Deserialize`
 #[derive(Debug, Serialize, Deserialize)]
@@ -16,6 +20,12 @@ struct Claims {
     sub: String,
     user_id: i64,
     exp: usize,
+    // tokens issued before revocation support will not carry an ID, default them so they remain valid until expiry
+    #[serde(default)]
+    jti: String,
+    // similarly, tokens issued before roles were introduced default to the least privileged role
+    #[serde(default)]
+    role: String,
 }
 
 pub struct JwtService {
@@ -26,11 +36,17 @@ impl JwtService {
     pub fn new(config: Arc<AppConfig>) -> Self {
         Self { config }
     }
+
+    fn random_hex_string(byte_length: usize) -> String {
+        let mut bytes = vec![0_u8; byte_length];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        hex::encode(bytes)
+    }
 }
 
 impl TokenService for JwtService {
-    fn new_token(&self, user_id: i64, email: &str) -> ConduitResult<String> {
-        let from_now = Duration::from_secs(3600);
+    fn new_token(&self, user_id: i64, email: &str, role: Role) -> ConduitResult<String> {
+        let from_now = Duration::from_secs(self.config.access_token_lifetime_seconds);
         let expired_future_time = SystemTime::now().add(from_now);
         let exp = OffsetDateTime::from(expired_future_time);
 
@@ -38,6 +54,8 @@ impl TokenService for JwtService {
             sub: String::from(email),
             exp: exp.unix_timestamp() as usize,
             user_id,
+            jti: Self::random_hex_string(16),
+            role: role.to_string(),
         };
 
         let token = encode(
@@ -50,7 +68,7 @@ impl TokenService for JwtService {
         Ok(token)
     }
 
-    fn get_user_id_from_token(&self, token: String) -> ConduitResult<i64> {
+    fn get_claims_from_token(&self, token: String) -> ConduitResult<TokenClaims> {
         let decoded_token = decode::<Claims>(
             token.as_str(),
             &DecodingKey::from_secret(self.config.token_secret.as_bytes()),
@@ -58,6 +76,20 @@ impl TokenService for JwtService {
         )
         .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
 
-        Ok(decoded_token.claims.user_id)
+        Ok(TokenClaims {
+            user_id: decoded_token.claims.user_id,
+            token_id: decoded_token.claims.jti,
+            expires_at: decoded_token.claims.exp as i64,
+            role: Role::from_str(&decoded_token.claims.role).unwrap_or_default(),
+            scopes: None,
+        })
+    }
+
+    fn new_opaque_token(&self) -> String {
+        Self::random_hex_string(32)
+    }
+
+    fn hash_opaque_token(&self, token: &str) -> String {
+        hex::encode(Sha256::digest(token.as_bytes()))
     }
 }
@@ -0,0 +1,66 @@
+use std::collections::{HashMap, HashSet};
+
+use ammonia::Builder;
+use pulldown_cmark::{html, Options, Parser};
+
+/// The elements Markdown renders to, the only elements allowed through the sanitizer.
+const ALLOWED_TAGS: [&str; 25] = [
+    "a",
+    "blockquote",
+    "br",
+    "code",
+    "del",
+    "em",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "img",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "strong",
+    "table",
+    "tbody",
+    "td",
+    "th",
+    "thead",
+    "tr",
+];
+
+/// Renders the Markdown as HTML, sanitized against an allowlist so raw HTML within the Markdown cannot inject scripts,
+/// event handlers or styles into the page displaying it.
+pub fn render_markdown(markdown: &str) -> String {
+    let parser = Parser::new_ext(markdown, Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH);
+
+    let mut unsafe_html = String::new();
+    html::push_html(&mut unsafe_html, parser);
+
+    let tag_attributes = HashMap::from([
+        ("a", HashSet::from(["href", "title"])),
+        ("img", HashSet::from(["src", "alt", "title"])),
+        ("ol", HashSet::from(["start"])),
+    ]);
+
+    Builder::empty()
+        .tags(HashSet::from(ALLOWED_TAGS))
+        .tag_attributes(tag_attributes)
+        .url_schemes(HashSet::from(["http", "https", "mailto"]))
+        .link_rel(Some("noopener noreferrer nofollow"))
+        .clean(&unsafe_html)
+        .to_string()
+}
+
+/// Sanitizes a search snippet highlighted straight from the raw Markdown body, keeping only the `<mark>` elements
+/// highlighting matches so markup within the body is escaped rather than injected into the page displaying it.
+pub fn render_snippet(snippet: &str) -> String {
+    Builder::empty()
+        .tags(HashSet::from(["mark"]))
+        .clean_content_tags(HashSet::from(["script", "style"]))
+        .clean(snippet)
+        .to_string()
+}
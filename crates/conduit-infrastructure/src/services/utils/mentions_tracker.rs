@@ -0,0 +1,118 @@
+use tracing::info;
+
+use conduit_core::errors::ConduitResult;
+use conduit_core::mentions::parse_mentions;
+use conduit_core::mentions::repository::{CommentMentionQuery, DynMentionsRepository};
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::utils::unit_of_work::DynUnitOfWork;
+
+/// Keeps track of the users `@mentioned` in articles and comments, notifying each user the first time they are
+/// mentioned.
+pub struct MentionsTracker {
+    users_repository: DynUsersRepository,
+    mentions_repository: DynMentionsRepository,
+    notifications_repository: DynNotificationsRepository,
+}
+
+impl MentionsTracker {
+    pub fn new(
+        users_repository: DynUsersRepository,
+        mentions_repository: DynMentionsRepository,
+        notifications_repository: DynNotificationsRepository,
+    ) -> Self {
+        Self {
+            users_repository,
+            mentions_repository,
+            notifications_repository,
+        }
+    }
+
+    /// Replaces the mentions of the article's body, or of one of its comments when a comment is given, with the users
+    /// mentioned in the body, returning their usernames. Mentions of users that do not exist are left as plain text,
+    /// and users are never notified of mentioning themselves. Newly mentioned users are only notified when `notify` is
+    /// set, as users mentioned in a draft are notified once it is published instead.
+    pub async fn record_mentions(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        actor_id: i64,
+        article_id: i64,
+        comment_id: Option<i64>,
+        body: &str,
+        notify: bool,
+    ) -> ConduitResult<Vec<String>> {
+        let mut usernames = Vec::new();
+        let mut user_ids = Vec::new();
+
+        for username in parse_mentions(body) {
+            if let Some(user) = self.users_repository.get_user_by_username(&username).await? {
+                usernames.push(user.username);
+                user_ids.push(user.id);
+            }
+        }
+
+        let newly_mentioned_user_ids = self
+            .mentions_repository
+            .replace_mentions(unit_of_work, article_id, comment_id, user_ids)
+            .await?
+            .into_iter()
+            .filter(|user_id| *user_id != actor_id)
+            .collect::<Vec<_>>();
+
+        if notify && !newly_mentioned_user_ids.is_empty() {
+            info!(
+                "notifying {} users mentioned in article {:?}",
+                newly_mentioned_user_ids.len(),
+                article_id
+            );
+            self.notifications_repository
+                .create_mention_notifications(unit_of_work, actor_id, article_id, comment_id, newly_mentioned_user_ids)
+                .await?;
+        }
+
+        Ok(usernames)
+    }
+
+    /// Notifies the users mentioned in the body of an article that has just been published, whose mentions were
+    /// recorded without notifying anyone while the article was a draft.
+    pub async fn notify_article_mentions(
+        &self,
+        unit_of_work: &mut DynUnitOfWork,
+        author_id: i64,
+        article_id: i64,
+    ) -> ConduitResult<()> {
+        let mentioned_user_ids = self
+            .mentions_repository
+            .get_article_mentioned_user_ids(article_id)
+            .await?
+            .into_iter()
+            .filter(|user_id| *user_id != author_id)
+            .collect::<Vec<_>>();
+
+        if !mentioned_user_ids.is_empty() {
+            info!(
+                "notifying {} users mentioned in published article {:?}",
+                mentioned_user_ids.len(),
+                article_id
+            );
+            self.notifications_repository
+                .create_mention_notifications(unit_of_work, author_id, article_id, None, mentioned_user_ids)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_article_mentions(&self, article_id: i64) -> ConduitResult<Vec<String>> {
+        Ok(self.mentions_repository.get_article_mentions(article_id).await?)
+    }
+
+    pub async fn get_comment_mentions(&self, comment_ids: Vec<i64>) -> ConduitResult<Vec<CommentMentionQuery>> {
+        // an empty page of comments has no mentions to look for
+        if comment_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(self.mentions_repository.get_comment_mentions(comment_ids).await?)
+    }
+}
@@ -1,3 +1,8 @@
 pub mod argon_security_service;
 pub mod conduit_seed_service;
+pub mod file_mail_service;
+pub mod hmac_totp_service;
 pub mod jwt_service;
+pub mod markdown;
+pub mod mentions_tracker;
+pub mod smtp_mail_service;
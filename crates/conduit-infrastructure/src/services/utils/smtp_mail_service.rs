@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+use conduit_core::config::AppConfig;
+use conduit_core::errors::{ConduitError, ConduitResult};
+use conduit_core::utils::mail_service::MailService;
+
+pub struct SmtpMailService {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailService {
+    pub fn new(config: Arc<AppConfig>) -> anyhow::Result<Self> {
+        let host = config
+            .smtp_host
+            .as_ref()
+            .context("an SMTP host is required when using the smtp mail transport")?;
+
+        let mut transport = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+            .context("could not initialize the SMTP transport")?
+            .port(config.smtp_port);
+
+        if let (Some(username), Some(password)) = (&config.smtp_username, &config.smtp_password) {
+            transport = transport.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        let from = config.mail_from.parse().context("mail from address is invalid")?;
+
+        Ok(Self {
+            transport: transport.build(),
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl MailService for SmtpMailService {
+    async fn send_mail(&self, to: &str, subject: &str, body: &str) -> ConduitResult<()> {
+        let to = to
+            .parse::<Mailbox>()
+            .map_err(|err| ConduitError::BadRequest(err.to_string()))?;
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(subject)
+            .body(String::from(body))
+            .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|err| ConduitError::InternalServerErrorWithContext(err.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use std::any::Any;
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+
+use conduit_core::utils::unit_of_work::{DynUnitOfWork, UnitOfWork, UnitOfWorkFactory};
+
+use crate::connection_pool::ConduitConnectionPool;
+
+#[derive(Clone)]
+pub struct PostgresUnitOfWorkFactory {
+    pool: ConduitConnectionPool,
+}
+
+impl PostgresUnitOfWorkFactory {
+    pub fn new(pool: ConduitConnectionPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for PostgresUnitOfWorkFactory {
+    async fn begin(&self) -> anyhow::Result<Box<dyn UnitOfWork>> {
+        let transaction = self
+            .pool
+            .begin()
+            .await
+            .context("an unexpected error occurred while starting a transaction")?;
+
+        Ok(Box::new(PostgresUnitOfWork { transaction }))
+    }
+}
+
+pub struct PostgresUnitOfWork {
+    transaction: Transaction<'static, Postgres>,
+}
+
+impl PostgresUnitOfWork {
+    /// Recovers the underlying Postgres transaction from a unit of work handed to a repository.
+    pub fn transaction(unit_of_work: &mut DynUnitOfWork) -> anyhow::Result<&mut Transaction<'static, Postgres>> {
+        (unit_of_work as &mut dyn Any)
+            .downcast_mut::<PostgresUnitOfWork>()
+            .map(|unit_of_work| &mut unit_of_work.transaction)
+            .ok_or_else(|| anyhow!("unit of work was not opened against the Postgres connection pool"))
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for PostgresUnitOfWork {
+    async fn commit(self: Box<Self>) -> anyhow::Result<()> {
+        self.transaction
+            .commit()
+            .await
+            .context("an unexpected error occurred while committing the transaction")
+    }
+
+    async fn rollback(self: Box<Self>) -> anyhow::Result<()> {
+        self.transaction
+            .rollback()
+            .await
+            .context("an unexpected error occurred while rolling back the transaction")
+    }
+}
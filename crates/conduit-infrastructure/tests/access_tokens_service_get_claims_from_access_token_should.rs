@@ -0,0 +1,153 @@
+use std::ops::Sub;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use conduit_core::access_tokens::repository::{AccessTokenEntity, DynAccessTokensRepository};
+use conduit_core::access_tokens::scopes::Scope;
+use conduit_core::access_tokens::service::AccessTokensService;
+use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::utils::token_service::DynTokenService;
+use conduit_infrastructure::mocks::{stub_access_token_entity, AccessTokensServiceTestFixture};
+use conduit_infrastructure::services::access_tokens_service::ConduitAccessTokensService;
+use mockall::predicate::*;
+use sqlx::types::time::OffsetDateTime;
+
+fn new_access_tokens_service(fixture: AccessTokensServiceTestFixture) -> ConduitAccessTokensService {
+    ConduitAccessTokensService::new(
+        Arc::new(fixture.mock_repository) as DynAccessTokensRepository,
+        Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+        Arc::new(fixture.mock_token_service) as DynTokenService,
+    )
+}
+
+#[tokio::test]
+async fn return_scoped_claims_when_token_is_valid() {
+    // arrange
+    let mut fixture = AccessTokensServiceTestFixture::default();
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .with(eq("cpat_stub token"))
+        .times(1)
+        .return_once(move |_| String::from("stub token hash"));
+
+    fixture
+        .mock_repository
+        .expect_get_access_token()
+        .with(eq(String::from("stub token hash")))
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(AccessTokenEntity {
+                scopes: vec![String::from("articles:write")],
+                ..stub_access_token_entity()
+            }))
+        });
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_id()
+        .with(eq(1_i64))
+        .times(1)
+        .return_once(move |_| Ok(UserEntity::default()));
+
+    fixture
+        .mock_repository
+        .expect_update_access_token_last_used()
+        .with(eq(1_i64))
+        .times(1)
+        .return_once(move |_| Ok(()));
+
+    let access_tokens_service = new_access_tokens_service(fixture);
+
+    // act
+    let response = access_tokens_service
+        .get_claims_from_access_token(String::from("cpat_stub token"))
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+    let claims = response.unwrap();
+    assert_eq!(claims.user_id, 1);
+    assert!(claims.has_scope(Scope::Read));
+    assert!(claims.has_scope(Scope::ArticlesWrite));
+    assert!(!claims.has_scope(Scope::CommentsWrite));
+}
+
+#[tokio::test]
+async fn return_error_when_token_has_expired() {
+    // arrange
+    let mut fixture = AccessTokensServiceTestFixture::default();
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .times(1)
+        .return_once(move |_| String::from("stub token hash"));
+
+    fixture
+        .mock_repository
+        .expect_get_access_token()
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(AccessTokenEntity {
+                expires_at: Some(OffsetDateTime::from(SystemTime::now().sub(Duration::from_secs(3600)))),
+                ..stub_access_token_entity()
+            }))
+        });
+
+    fixture.mock_users_repository.expect_get_user_by_id().times(0);
+
+    fixture.mock_repository.expect_update_access_token_last_used().times(0);
+
+    let access_tokens_service = new_access_tokens_service(fixture);
+
+    // act
+    let response = access_tokens_service
+        .get_claims_from_access_token(String::from("cpat_stub token"))
+        .await;
+
+    // assert
+    assert!(response.is_err());
+}
+
+#[tokio::test]
+async fn return_error_when_user_is_suspended() {
+    // arrange
+    let mut fixture = AccessTokensServiceTestFixture::default();
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .times(1)
+        .return_once(move |_| String::from("stub token hash"));
+
+    fixture
+        .mock_repository
+        .expect_get_access_token()
+        .times(1)
+        .return_once(move |_| Ok(Some(stub_access_token_entity())));
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_id()
+        .times(1)
+        .return_once(move |_| {
+            Ok(UserEntity {
+                suspended_at: Some(OffsetDateTime::now_utc()),
+                ..Default::default()
+            })
+        });
+
+    fixture.mock_repository.expect_update_access_token_last_used().times(0);
+
+    let access_tokens_service = new_access_tokens_service(fixture);
+
+    // act
+    let response = access_tokens_service
+        .get_claims_from_access_token(String::from("cpat_stub token"))
+        .await;
+
+    // assert
+    assert!(response.is_err());
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use conduit_core::admin::service::AdminService;
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::users::service::DynUsersService;
+use conduit_core::utils::security_service::DynSecurityService;
+use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+use conduit_infrastructure::mocks::AdminServiceTestFixture;
+use conduit_infrastructure::services::admin_service::ConduitAdminService;
+use mockall::predicate::*;
+use sqlx::types::time::OffsetDateTime;
+
+fn new_admin_service(fixture: AdminServiceTestFixture) -> ConduitAdminService {
+    ConduitAdminService::new(
+        Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+        Arc::new(fixture.mock_sessions_repository) as DynSessionsRepository,
+        Arc::new(fixture.mock_users_service) as DynUsersService,
+        Arc::new(fixture.mock_security_service) as DynSecurityService,
+        Arc::new(fixture.mock_token_service) as DynTokenService,
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    )
+}
+
+#[tokio::test]
+async fn return_success_and_revoke_sessions_when_user_exists() {
+    // arrange
+    let mut fixture = AdminServiceTestFixture::default();
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_username()
+        .with(eq("stub username"))
+        .times(1)
+        .return_once(move |_| Ok(Some(UserEntity::default())));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_users_repository
+        .expect_update_user_suspension()
+        .withf(|_, id, suspended| *id == 1 && *suspended)
+        .times(1)
+        .return_once(move |_, _, _| {
+            Ok(UserEntity {
+                suspended_at: Some(OffsetDateTime::now_utc()),
+                ..Default::default()
+            })
+        });
+
+    fixture
+        .mock_sessions_repository
+        .expect_revoke_user_refresh_tokens()
+        .withf(|_, user_id| *user_id == 1)
+        .times(1)
+        .return_once(move |_, _| Ok(()));
+
+    let admin_service = new_admin_service(fixture);
+
+    // act
+    let response = admin_service.suspend_user(2, String::from("stub username")).await;
+
+    // assert
+    assert!(response.is_ok());
+    assert!(response.unwrap().suspended);
+}
+
+#[tokio::test]
+async fn return_error_when_admin_suspends_themselves() {
+    // arrange
+    let mut fixture = AdminServiceTestFixture::default();
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_username()
+        .with(eq("stub username"))
+        .times(1)
+        .return_once(move |_| Ok(Some(UserEntity::default())));
+
+    fixture.mock_unit_of_work_factory.expect_begin().times(0);
+
+    fixture.mock_users_repository.expect_update_user_suspension().times(0);
+
+    let admin_service = new_admin_service(fixture);
+
+    // act
+    let response = admin_service.suspend_user(1, String::from("stub username")).await;
+
+    // assert
+    assert!(response.is_err());
+}
+
+#[tokio::test]
+async fn return_error_when_user_does_not_exist() {
+    // arrange
+    let mut fixture = AdminServiceTestFixture::default();
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_username()
+        .with(eq("stub username"))
+        .times(1)
+        .return_once(move |_| Ok(None));
+
+    fixture.mock_users_repository.expect_update_user_suspension().times(0);
+
+    let admin_service = new_admin_service(fixture);
+
+    // act
+    let response = admin_service.suspend_user(2, String::from("stub username")).await;
+
+    // assert
+    assert!(response.is_err());
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use conduit_core::admin::service::AdminService;
+use conduit_core::errors::ConduitError;
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::users::roles::Role;
+use conduit_core::users::service::DynUsersService;
+use conduit_core::utils::security_service::DynSecurityService;
+use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::unit_of_work::DynUnitOfWorkFactory;
+use conduit_infrastructure::mocks::AdminServiceTestFixture;
+use conduit_infrastructure::services::admin_service::ConduitAdminService;
+use mockall::predicate::*;
+
+fn new_admin_service(fixture: AdminServiceTestFixture) -> ConduitAdminService {
+    ConduitAdminService::new(
+        Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+        Arc::new(fixture.mock_sessions_repository) as DynSessionsRepository,
+        Arc::new(fixture.mock_users_service) as DynUsersService,
+        Arc::new(fixture.mock_security_service) as DynSecurityService,
+        Arc::new(fixture.mock_token_service) as DynTokenService,
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    )
+}
+
+#[tokio::test]
+async fn return_success_when_user_exists() {
+    // arrange
+    let mut fixture = AdminServiceTestFixture::default();
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_username()
+        .with(eq("stub username"))
+        .times(1)
+        .return_once(move |_| Ok(Some(UserEntity::default())));
+
+    fixture
+        .mock_users_service
+        .expect_update_user_role()
+        .with(eq(String::from("stub username")), eq(Role::Moderator))
+        .times(1)
+        .return_once(move |_, _| Ok(()));
+
+    let admin_service = new_admin_service(fixture);
+
+    // act
+    let response = admin_service
+        .update_user_role(2, String::from("stub username"), Role::Moderator)
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn return_error_when_admin_updates_their_own_role() {
+    // arrange
+    let mut fixture = AdminServiceTestFixture::default();
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_username()
+        .with(eq("stub username"))
+        .times(1)
+        .return_once(move |_| Ok(Some(UserEntity::default())));
+
+    fixture.mock_users_service.expect_update_user_role().times(0);
+
+    let admin_service = new_admin_service(fixture);
+
+    // act
+    let response = admin_service
+        .update_user_role(1, String::from("stub username"), Role::User)
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::BadRequest(_))));
+}
@@ -0,0 +1,552 @@
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use anyhow::anyhow;
+use conduit_core::articles::service::ArticlesService;
+use conduit_infrastructure::mocks::{stub_upsert_article_query, ArticlesServiceTestFixture};
+use mockall::predicate::*;
+use sqlx::types::time::OffsetDateTime;
+
+use conduit_core::articles::repository::{DynArticlesRepository, UpsertArticleQuery};
+use conduit_core::articles::status::ArticleStatus;
+use conduit_core::errors::ConduitError;
+use conduit_core::mentions::repository::DynMentionsRepository;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::tags::repository::{DynTagsRepository, TagEntity};
+use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+use conduit_domain::articles::models::CreateArticleDto;
+
+use conduit_infrastructure::services::articles_service::ConduitArticlesService;
+use conduit_infrastructure::services::utils::mentions_tracker::MentionsTracker;
+
+fn stub_tag(id: i64, tag: &str) -> TagEntity {
+    TagEntity {
+        id,
+        tag: String::from(tag),
+        created_at: OffsetDateTime::from(SystemTime::now()),
+    }
+}
+
+#[tokio::test]
+async fn return_success_and_commit_when_downstream_services_succeed() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_taken_slugs()
+        .withf(|_, slug, article_id| slug == "stub-title" && article_id.is_none())
+        .times(1)
+        .return_once(move |_, _, _| Ok(vec![]));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_tags()
+        .with(eq(vec![String::from("tag1"), String::from("tag2")]))
+        .times(1)
+        .return_once(move |_| Ok(vec![stub_tag(1, "tag1")]));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article()
+        .withf(|_, article| article.slug == "stub-title" && article.status == ArticleStatus::Draft)
+        .times(1)
+        .return_once(move |_, _| Ok(stub_upsert_article_query()));
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article_revision()
+        .withf(|_, article_id, editor_id| *article_id == 1 && *editor_id == 1)
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture
+        .mock_tags_repository
+        .expect_create_tags()
+        .withf(|_, tags| *tags == vec![String::from("tag2")])
+        .times(1)
+        .return_once(move |_, _| Ok(vec![stub_tag(2, "tag2")]));
+
+    fixture
+        .mock_tags_repository
+        .expect_create_article_tags()
+        .withf(|_, article_tags| *article_tags == vec![(1, 1), (2, 1)])
+        .times(1)
+        .return_once(move |_, _| Ok(()));
+
+    fixture
+        .mock_mentions_repository
+        .expect_replace_mentions()
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(vec![]));
+
+    let articles_service = ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    );
+
+    // act
+    let response = articles_service
+        .create_article(
+            1,
+            CreateArticleDto {
+                tag_list: vec![String::from("tag1"), String::from("tag2"), String::from("tag1")],
+                status: Some(String::from("draft")),
+                ..CreateArticleDto::new_stub()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+    assert_eq!(
+        response.unwrap().tag_list,
+        vec![String::from("tag1"), String::from("tag2")]
+    );
+}
+
+#[tokio::test]
+async fn ignore_email_addresses_and_mentions_within_code() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_taken_slugs()
+        .times(1)
+        .return_once(move |_, _, _| Ok(vec![]));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article()
+        .times(1)
+        .return_once(move |_, article| {
+            Ok(UpsertArticleQuery {
+                body: article.body,
+                ..stub_upsert_article_query()
+            })
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article_revision()
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_username()
+        .withf(|username| username == "alice")
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(UserEntity {
+                id: 3,
+                username: String::from("alice"),
+                ..Default::default()
+            }))
+        });
+
+    fixture
+        .mock_mentions_repository
+        .expect_replace_mentions()
+        .withf(|_, article_id, comment_id, user_ids| *article_id == 1 && comment_id.is_none() && *user_ids == vec![3])
+        .times(1)
+        .return_once(move |_, _, _, user_ids| Ok(user_ids));
+
+    fixture
+        .mock_notifications_repository
+        .expect_create_mention_notifications()
+        .withf(|_, actor_id, _, _, user_ids| *actor_id == 1 && *user_ids == vec![3])
+        .times(1)
+        .return_once(move |_, _, _, _, _| Ok(()));
+
+    let articles_service = ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    );
+
+    // act
+    let response = articles_service
+        .create_article(
+            1,
+            CreateArticleDto {
+                body: Some(String::from("ask bob@example.com, run `@decorator` or ping @alice")),
+                ..CreateArticleDto::new_stub()
+            },
+        )
+        .await;
+
+    // assert
+    assert_eq!(response.unwrap().mentions, vec!["alice"]);
+}
+
+#[tokio::test]
+async fn not_commit_when_creating_article_tags_fails() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_taken_slugs()
+        .times(1)
+        .return_once(move |_, _, _| Ok(vec![]));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_tags()
+        .times(1)
+        .return_once(move |_| Ok(vec![stub_tag(1, "tag1")]));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(0);
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article()
+        .times(1)
+        .return_once(move |_, _| Ok(stub_upsert_article_query()));
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article_revision()
+        .withf(|_, article_id, editor_id| *article_id == 1 && *editor_id == 1)
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture.mock_tags_repository.expect_create_tags().times(0);
+
+    fixture
+        .mock_tags_repository
+        .expect_create_article_tags()
+        .times(1)
+        .return_once(move |_, _| Err(anyhow!("stub error")));
+
+    fixture
+        .mock_mentions_repository
+        .expect_replace_mentions()
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(vec![]));
+
+    let articles_service = ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    );
+
+    // act
+    let response = articles_service
+        .create_article(
+            1,
+            CreateArticleDto {
+                tag_list: vec![String::from("tag1")],
+                ..CreateArticleDto::new_stub()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(response.is_err());
+}
+
+#[tokio::test]
+async fn create_article_with_suffixed_slug_when_slug_is_taken() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_taken_slugs()
+        .times(1)
+        .return_once(move |_, _, _| Ok(vec![String::from("stub-title"), String::from("stub-title-2")]));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article()
+        .withf(|_, article| article.slug == "stub-title-3")
+        .times(1)
+        .return_once(move |_, _| Ok(stub_upsert_article_query()));
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article_revision()
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture
+        .mock_mentions_repository
+        .expect_replace_mentions()
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(vec![]));
+
+    let articles_service = ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    );
+
+    // act
+    let response = articles_service.create_article(1, CreateArticleDto::new_stub()).await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn hold_back_scheduled_article_as_draft() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_taken_slugs()
+        .times(1)
+        .return_once(move |_, _, _| Ok(vec![]));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article()
+        .withf(|_, article| article.status == ArticleStatus::Draft && article.publish_at.is_some())
+        .times(1)
+        .return_once(move |_, _| Ok(stub_upsert_article_query()));
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article_revision()
+        .withf(|_, article_id, editor_id| *article_id == 1 && *editor_id == 1)
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture
+        .mock_mentions_repository
+        .expect_replace_mentions()
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(vec![]));
+
+    let articles_service = ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    );
+
+    // act
+    let response = articles_service
+        .create_article(
+            1,
+            CreateArticleDto {
+                publish_at: Some(String::from("2999-01-01T00:00:00Z")),
+                ..CreateArticleDto::new_stub()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn return_bad_request_when_publish_time_has_passed() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture.mock_articles_repository.expect_get_taken_slugs().times(0);
+
+    fixture.mock_articles_repository.expect_create_article().times(0);
+
+    let articles_service = ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    );
+
+    // act
+    let response = articles_service
+        .create_article(
+            1,
+            CreateArticleDto {
+                publish_at: Some(String::from("2000-01-01T00:00:00Z")),
+                ..CreateArticleDto::new_stub()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::BadRequest(_))));
+}
+
+#[tokio::test]
+async fn not_notify_users_mentioned_in_draft() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_taken_slugs()
+        .times(1)
+        .return_once(move |_, _, _| Ok(vec![]));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article()
+        .times(1)
+        .return_once(move |_, article| {
+            Ok(UpsertArticleQuery {
+                body: article.body,
+                ..stub_upsert_article_query()
+            })
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article_revision()
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_username()
+        .withf(|username| username == "alice")
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(UserEntity {
+                id: 3,
+                username: String::from("alice"),
+                ..Default::default()
+            }))
+        });
+
+    fixture
+        .mock_mentions_repository
+        .expect_replace_mentions()
+        .withf(|_, article_id, comment_id, user_ids| *article_id == 1 && comment_id.is_none() && *user_ids == vec![3])
+        .times(1)
+        .return_once(move |_, _, _, user_ids| Ok(user_ids));
+
+    fixture
+        .mock_notifications_repository
+        .expect_create_mention_notifications()
+        .times(0);
+
+    let articles_service = ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    );
+
+    // act
+    let response = articles_service
+        .create_article(
+            1,
+            CreateArticleDto {
+                body: Some(String::from("draft for @alice")),
+                status: Some(String::from("draft")),
+                ..CreateArticleDto::new_stub()
+            },
+        )
+        .await;
+
+    // assert
+    assert_eq!(response.unwrap().mentions, vec!["alice"]);
+}
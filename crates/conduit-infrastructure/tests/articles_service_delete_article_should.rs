@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use conduit_core::articles::service::ArticlesService;
+use conduit_infrastructure::mocks::{stub_get_article_query, ArticlesServiceTestFixture};
+use mockall::predicate::*;
+
+use conduit_core::articles::repository::DynArticlesRepository;
+use conduit_core::errors::ConduitError;
+use conduit_core::mentions::repository::DynMentionsRepository;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::tags::repository::DynTagsRepository;
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::users::roles::Role;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+
+use conduit_infrastructure::services::articles_service::ConduitArticlesService;
+use conduit_infrastructure::services::utils::mentions_tracker::MentionsTracker;
+
+#[tokio::test]
+async fn return_success_when_moderator_deletes_another_users_article() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .with(eq(Some(2)), eq(String::from("stub-title")))
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_delete_article()
+        .withf(|_, id, deleted_by| *id == 1 && *deleted_by == 2)
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    let articles_service = ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    );
+
+    // act
+    let response = articles_service
+        .delete_article(2, Role::Moderator, String::from("stub-title"))
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn return_forbidden_when_user_deletes_another_users_article() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture.mock_unit_of_work_factory.expect_begin().times(0);
+
+    fixture.mock_articles_repository.expect_delete_article().times(0);
+
+    let articles_service = ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    );
+
+    let expected_err = ConduitError::Forbidden.to_string();
+
+    // act
+    let response = articles_service
+        .delete_article(2, Role::User, String::from("stub-title"))
+        .await;
+
+    // assert
+    assert!(response.is_err());
+    assert_eq!(response.unwrap_err().to_string(), expected_err);
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use conduit_core::articles::service::ArticlesService;
+use conduit_infrastructure::mocks::{stub_article_revision_entity, stub_get_article_query, ArticlesServiceTestFixture};
+use mockall::predicate::*;
+
+use conduit_core::articles::repository::{ArticleRevisionEntity, DynArticlesRepository};
+use conduit_core::mentions::repository::DynMentionsRepository;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::tags::repository::DynTagsRepository;
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::utils::unit_of_work::DynUnitOfWorkFactory;
+
+use conduit_infrastructure::services::articles_service::ConduitArticlesService;
+use conduit_infrastructure::services::utils::mentions_tracker::MentionsTracker;
+
+fn new_articles_service(fixture: ArticlesServiceTestFixture) -> ConduitArticlesService {
+    ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    )
+}
+
+#[tokio::test]
+async fn compare_against_previous_revision_and_skip_unchanged_fields() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_revision()
+        .with(eq(1), eq(2))
+        .times(1)
+        .return_once(move |_, _| {
+            Ok(Some(ArticleRevisionEntity {
+                revision: 2,
+                body: String::from("first line\nchanged line\n"),
+                ..stub_article_revision_entity()
+            }))
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_revision()
+        .with(eq(1), eq(3))
+        .times(1)
+        .return_once(move |_, _| {
+            Ok(Some(ArticleRevisionEntity {
+                revision: 3,
+                body: String::from("first line\nsecond line\n"),
+                ..stub_article_revision_entity()
+            }))
+        });
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .get_article_revision_diff(None, String::from("stub-title"), 3, None)
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+    let diff = response.unwrap();
+    assert_eq!(diff.from, 2);
+    assert!(diff.title.is_none());
+    assert!(diff.description.is_none());
+    let body_diff = diff.body.unwrap();
+    assert!(body_diff.contains("-changed line"));
+    assert!(body_diff.contains("+second line"));
+}
+
+#[tokio::test]
+async fn compare_first_revision_against_an_empty_article() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_revision()
+        .with(eq(1), eq(1))
+        .times(1)
+        .return_once(move |_, _| {
+            Ok(Some(ArticleRevisionEntity {
+                revision: 1,
+                body: String::from("first line\n"),
+                ..stub_article_revision_entity()
+            }))
+        });
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .get_article_revision_diff(None, String::from("stub-title"), 1, None)
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+    let diff = response.unwrap();
+    assert_eq!(diff.from, 0);
+    assert!(diff.title.unwrap().contains("+stub title"));
+    assert!(diff.body.unwrap().contains("+first line"));
+}
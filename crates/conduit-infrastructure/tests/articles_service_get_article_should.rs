@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use conduit_core::articles::service::ArticlesService;
+use conduit_infrastructure::mocks::{stub_get_article_query, stub_reaction_count_query, ArticlesServiceTestFixture};
+use mockall::predicate::*;
+
+use conduit_core::articles::repository::DynArticlesRepository;
+use conduit_core::errors::ConduitError;
+use conduit_core::mentions::repository::DynMentionsRepository;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::reactions::repository::{DynReactionsRepository, ReactionCountQuery};
+use conduit_core::tags::repository::DynTagsRepository;
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::utils::unit_of_work::DynUnitOfWorkFactory;
+
+use conduit_infrastructure::services::articles_service::ConduitArticlesService;
+use conduit_infrastructure::services::utils::mentions_tracker::MentionsTracker;
+
+fn new_articles_service(fixture: ArticlesServiceTestFixture) -> ConduitArticlesService {
+    ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    )
+}
+
+#[tokio::test]
+async fn return_renamed_article_when_requested_by_previous_slug() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .with(eq(None), eq(String::from("previous-title")))
+        .times(1)
+        .return_once(move |_, _| Ok(None));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_renamed_article_slug()
+        .with(eq(String::from("previous-title")))
+        .times(1)
+        .return_once(move |_| Ok(Some(String::from("stub-title"))));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .with(eq(None), eq(String::from("stub-title")))
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_by_article_id()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    fixture
+        .mock_reactions_repository
+        .expect_get_article_reactions()
+        .withf(|user_id, article_ids| user_id.is_none() && *article_ids == vec![1])
+        .times(1)
+        .return_once(move |_, _| {
+            Ok(vec![
+                stub_reaction_count_query(),
+                ReactionCountQuery {
+                    emoji: String::from("❤️"),
+                    count: 2,
+                    ..stub_reaction_count_query()
+                },
+            ])
+        });
+
+    fixture
+        .mock_mentions_repository
+        .expect_get_article_mentions()
+        .with(eq(1))
+        .times(1)
+        .return_once(move |_| Ok(vec![String::from("stub username")]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service.get_article(None, String::from("previous-title")).await;
+
+    // assert
+    assert!(response.is_ok());
+    let article = response.unwrap();
+    assert_eq!(article.slug, "stub-title");
+    assert_eq!(
+        article
+            .reactions
+            .iter()
+            .map(|reaction| (reaction.emoji.as_str(), reaction.count))
+            .collect::<Vec<_>>(),
+        vec![("👍", 1), ("❤️", 2)]
+    );
+    assert!(article.my_reactions.is_empty());
+    assert_eq!(article.mentions, vec!["stub username"]);
+}
+
+#[tokio::test]
+async fn return_not_found_when_slug_was_never_used() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(None));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_renamed_article_slug()
+        .times(1)
+        .return_once(move |_| Ok(None));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service.get_article(None, String::from("unknown-title")).await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::NotFound(_))));
+}
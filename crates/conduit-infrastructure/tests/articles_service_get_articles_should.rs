@@ -0,0 +1,303 @@
+use std::sync::Arc;
+
+use conduit_core::articles::service::ArticlesService;
+use conduit_infrastructure::mocks::{stub_get_article_query, ArticlesServiceTestFixture};
+
+use conduit_core::articles::cursor::ArticleCursor;
+use conduit_core::articles::repository::{DynArticlesRepository, GetArticleQuery};
+use conduit_core::errors::ConduitError;
+use conduit_core::mentions::repository::DynMentionsRepository;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::tags::repository::DynTagsRepository;
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::utils::unit_of_work::DynUnitOfWorkFactory;
+use conduit_domain::articles::requests::GetArticlesServiceRequest;
+use sqlx::types::time::OffsetDateTime;
+
+use conduit_infrastructure::services::articles_service::ConduitArticlesService;
+use conduit_infrastructure::services::utils::mentions_tracker::MentionsTracker;
+
+fn new_articles_service(fixture: ArticlesServiceTestFixture) -> ConduitArticlesService {
+    ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    )
+}
+
+#[tokio::test]
+async fn return_total_count_and_next_cursor_when_page_is_full() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+    let created_at = OffsetDateTime::from_unix_timestamp(1_666_000_000);
+
+    fixture
+        .mock_articles_repository
+        .expect_get_articles_count()
+        .times(1)
+        .return_once(move |_| Ok(5));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_articles()
+        .withf(|_, request, cursor| request.limit == 1 && cursor.is_none())
+        .times(1)
+        .return_once(move |_, _, _| {
+            Ok(vec![GetArticleQuery {
+                id: 3,
+                created_at,
+                ..stub_get_article_query()
+            }])
+        });
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_article_ids()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    fixture
+        .mock_reactions_repository
+        .expect_get_article_reactions()
+        .times(1)
+        .return_once(move |_, _| Ok(vec![]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .get_articles(
+            None,
+            GetArticlesServiceRequest {
+                limit: 1,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(response.articles.len(), 1);
+    assert_eq!(response.articles_count, 5);
+    assert_eq!(response.next_cursor, Some(ArticleCursor::new(created_at, 3).encode()));
+}
+
+#[tokio::test]
+async fn continue_after_the_cursor_and_omit_next_cursor_on_the_last_page() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+    let cursor = ArticleCursor::new(OffsetDateTime::from_unix_timestamp(1_666_000_000), 3);
+
+    fixture
+        .mock_articles_repository
+        .expect_get_articles_count()
+        .times(1)
+        .return_once(move |_| Ok(5));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_articles()
+        .withf(move |_, _, requested_cursor| *requested_cursor == Some(cursor))
+        .times(1)
+        .return_once(move |_, _, _| Ok(vec![stub_get_article_query()]));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_article_ids()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    fixture
+        .mock_reactions_repository
+        .expect_get_article_reactions()
+        .times(1)
+        .return_once(move |_, _| Ok(vec![]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .get_articles(
+            None,
+            GetArticlesServiceRequest {
+                limit: 20,
+                cursor: Some(cursor.encode()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(response.articles_count, 5);
+    assert!(response.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn return_bad_request_when_cursor_is_invalid() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture.mock_articles_repository.expect_get_articles().times(0);
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .get_articles(
+            None,
+            GetArticlesServiceRequest {
+                limit: 20,
+                cursor: Some(String::from("not a cursor")),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::BadRequest(_))));
+}
+
+#[tokio::test]
+async fn return_bad_request_when_searching_with_a_cursor() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+    let cursor = ArticleCursor::new(OffsetDateTime::from_unix_timestamp(1_666_000_000), 3);
+
+    fixture.mock_articles_repository.expect_get_articles().times(0);
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .get_articles(
+            None,
+            GetArticlesServiceRequest {
+                q: Some(String::from("rust")),
+                limit: 20,
+                cursor: Some(cursor.encode()),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::BadRequest(_))));
+}
+
+#[tokio::test]
+async fn escape_markup_in_search_snippets_except_highlights() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_articles_count()
+        .times(1)
+        .return_once(move |_| Ok(1));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_articles()
+        .times(1)
+        .return_once(move |_, _, _| {
+            Ok(vec![GetArticleQuery {
+                snippet: Some(String::from(
+                    "<script>alert('xss')</script> learn about <mark>rust</mark> <b>now</b>",
+                )),
+                ..stub_get_article_query()
+            }])
+        });
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_article_ids()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    fixture
+        .mock_reactions_repository
+        .expect_get_article_reactions()
+        .times(1)
+        .return_once(move |_, _| Ok(vec![]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .get_articles(
+            None,
+            GetArticlesServiceRequest {
+                q: Some(String::from("rust")),
+                limit: 20,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(
+        response.articles[0].snippet.as_deref(),
+        Some(" learn about <mark>rust</mark> now")
+    );
+}
+
+#[tokio::test]
+async fn count_only_articles_favorited_by_the_user_when_filtering_by_favorited() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_articles_count()
+        .withf(|request| request.favorited.as_deref() == Some("stub username"))
+        .times(1)
+        .return_once(move |_| Ok(1));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_articles()
+        .withf(|_, request, _| request.favorited.as_deref() == Some("stub username"))
+        .times(1)
+        .return_once(move |_, _, _| Ok(vec![stub_get_article_query()]));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_article_ids()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    fixture
+        .mock_reactions_repository
+        .expect_get_article_reactions()
+        .times(1)
+        .return_once(move |_, _| Ok(vec![]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .get_articles(
+            None,
+            GetArticlesServiceRequest {
+                favorited: Some(String::from("stub username")),
+                limit: 20,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(response.articles.len(), 1);
+    assert_eq!(response.articles_count, 1);
+    assert!(response.next_cursor.is_none());
+}
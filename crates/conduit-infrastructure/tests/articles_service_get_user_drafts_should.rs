@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use conduit_core::articles::service::ArticlesService;
+use conduit_infrastructure::mocks::{stub_get_article_query, ArticlesServiceTestFixture};
+
+use conduit_core::articles::cursor::ArticleCursor;
+use conduit_core::articles::repository::{DynArticlesRepository, GetArticleQuery};
+use conduit_core::mentions::repository::DynMentionsRepository;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::tags::repository::DynTagsRepository;
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::utils::unit_of_work::DynUnitOfWorkFactory;
+use conduit_domain::articles::requests::GetArticlesServiceRequest;
+use sqlx::types::time::OffsetDateTime;
+
+use conduit_infrastructure::services::articles_service::ConduitArticlesService;
+use conduit_infrastructure::services::utils::mentions_tracker::MentionsTracker;
+
+fn new_articles_service(fixture: ArticlesServiceTestFixture) -> ConduitArticlesService {
+    ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    )
+}
+
+#[tokio::test]
+async fn return_total_drafts_count_and_next_cursor_when_page_is_full() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+    let updated_at = OffsetDateTime::from_unix_timestamp(1_666_000_000);
+
+    fixture
+        .mock_articles_repository
+        .expect_get_user_drafts_count()
+        .withf(|user_id| *user_id == 1)
+        .times(1)
+        .return_once(move |_| Ok(4));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_user_drafts()
+        .withf(|user_id, limit, _, cursor| *user_id == 1 && *limit == 1 && cursor.is_none())
+        .times(1)
+        .return_once(move |_, _, _, _| {
+            Ok(vec![GetArticleQuery {
+                id: 3,
+                updated_at,
+                status: String::from("draft"),
+                published_at: None,
+                ..stub_get_article_query()
+            }])
+        });
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_article_ids()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    fixture
+        .mock_reactions_repository
+        .expect_get_article_reactions()
+        .times(1)
+        .return_once(move |_, _| Ok(vec![]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .get_user_drafts(
+            1,
+            GetArticlesServiceRequest {
+                limit: 1,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(response.articles.len(), 1);
+    assert_eq!(response.articles_count, 4);
+    assert_eq!(response.next_cursor, Some(ArticleCursor::new(updated_at, 3).encode()));
+}
+
+#[tokio::test]
+async fn continue_after_the_cursor_and_omit_next_cursor_on_the_last_page() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+    let cursor = ArticleCursor::new(OffsetDateTime::from_unix_timestamp(1_666_000_000), 3);
+
+    fixture
+        .mock_articles_repository
+        .expect_get_user_drafts_count()
+        .times(1)
+        .return_once(move |_| Ok(4));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_user_drafts()
+        .withf(move |_, _, _, given_cursor| *given_cursor == Some(cursor))
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(vec![]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .get_user_drafts(
+            1,
+            GetArticlesServiceRequest {
+                limit: 2,
+                cursor: Some(cursor.encode()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    // assert
+    assert!(response.articles.is_empty());
+    assert_eq!(response.articles_count, 4);
+    assert_eq!(response.next_cursor, None);
+}
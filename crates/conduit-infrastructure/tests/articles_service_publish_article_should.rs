@@ -0,0 +1,211 @@
+use std::sync::Arc;
+
+use conduit_core::articles::service::ArticlesService;
+use conduit_infrastructure::mocks::{stub_get_article_query, stub_upsert_article_query, ArticlesServiceTestFixture};
+use mockall::predicate::*;
+
+use conduit_core::articles::repository::{DynArticlesRepository, GetArticleQuery};
+use conduit_core::errors::ConduitError;
+use conduit_core::mentions::repository::DynMentionsRepository;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::tags::repository::DynTagsRepository;
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+
+use conduit_infrastructure::services::articles_service::ConduitArticlesService;
+use conduit_infrastructure::services::utils::mentions_tracker::MentionsTracker;
+
+fn new_articles_service(fixture: ArticlesServiceTestFixture) -> ConduitArticlesService {
+    ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    )
+}
+
+#[tokio::test]
+async fn return_published_article_when_author_publishes_draft() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .with(eq(Some(1)), eq(String::from("stub-title")))
+        .times(1)
+        .return_once(move |_, _| {
+            Ok(Some(GetArticleQuery {
+                status: String::from("draft"),
+                published_at: None,
+                ..stub_get_article_query()
+            }))
+        });
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_by_article_id()
+        .with(eq(1))
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_publish_article()
+        .withf(|_, id| *id == 1)
+        .times(1)
+        .return_once(move |_, _| Ok(stub_upsert_article_query()));
+
+    fixture
+        .mock_mentions_repository
+        .expect_get_article_mentioned_user_ids()
+        .with(eq(1))
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service.publish_article(1, String::from("stub-title")).await;
+
+    // assert
+    assert!(response.is_ok());
+    let article = response.unwrap();
+    assert_eq!(article.status, "published");
+    assert!(article.published_at.is_some());
+}
+
+#[tokio::test]
+async fn return_forbidden_when_user_publishes_another_users_article() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| {
+            Ok(Some(GetArticleQuery {
+                status: String::from("unlisted"),
+                ..stub_get_article_query()
+            }))
+        });
+
+    fixture.mock_articles_repository.expect_publish_article().times(0);
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service.publish_article(2, String::from("stub-title")).await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::Forbidden)));
+}
+
+#[tokio::test]
+async fn not_republish_when_article_is_already_published() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_by_article_id()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    fixture.mock_articles_repository.expect_publish_article().times(0);
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service.publish_article(1, String::from("stub-title")).await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn notify_users_mentioned_while_article_was_a_draft() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| {
+            Ok(Some(GetArticleQuery {
+                status: String::from("draft"),
+                published_at: None,
+                ..stub_get_article_query()
+            }))
+        });
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_by_article_id()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_publish_article()
+        .times(1)
+        .return_once(move |_, _| Ok(stub_upsert_article_query()));
+
+    fixture
+        .mock_mentions_repository
+        .expect_get_article_mentioned_user_ids()
+        .with(eq(1))
+        .times(1)
+        .return_once(move |_| Ok(vec![1, 2, 3]));
+
+    fixture
+        .mock_notifications_repository
+        .expect_create_mention_notifications()
+        .withf(|_, actor_id, article_id, comment_id, user_ids| {
+            *actor_id == 1 && *article_id == 1 && comment_id.is_none() && *user_ids == vec![2, 3]
+        })
+        .times(1)
+        .return_once(move |_, _, _, _, _| Ok(()));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service.publish_article(1, String::from("stub-title")).await;
+
+    // assert
+    assert!(response.is_ok());
+}
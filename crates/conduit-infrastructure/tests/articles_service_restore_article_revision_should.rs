@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use conduit_core::articles::service::ArticlesService;
+use conduit_infrastructure::mocks::{
+    stub_article_revision_entity, stub_get_article_query, stub_upsert_article_query, ArticlesServiceTestFixture,
+};
+use mockall::predicate::*;
+
+use conduit_core::articles::repository::{ArticleRevisionEntity, DynArticlesRepository};
+use conduit_core::errors::ConduitError;
+use conduit_core::mentions::repository::DynMentionsRepository;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::tags::repository::DynTagsRepository;
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+
+use conduit_infrastructure::services::articles_service::ConduitArticlesService;
+use conduit_infrastructure::services::utils::mentions_tracker::MentionsTracker;
+
+fn new_articles_service(fixture: ArticlesServiceTestFixture) -> ConduitArticlesService {
+    ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    )
+}
+
+#[tokio::test]
+async fn restore_content_and_record_revision_when_author_restores() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .with(eq(Some(1)), eq(String::from("stub-title")))
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_revision()
+        .with(eq(1), eq(2))
+        .times(1)
+        .return_once(move |_, _| {
+            Ok(Some(ArticleRevisionEntity {
+                revision: 2,
+                title: String::from("original title"),
+                ..stub_article_revision_entity()
+            }))
+        });
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_update_article()
+        .withf(|_, id, title, slug, _, _| *id == 1 && title == "original title" && slug == "stub-title")
+        .times(1)
+        .return_once(move |_, _, _, _, _, _| Ok(stub_upsert_article_query()));
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article_revision()
+        .withf(|_, article_id, editor_id| *article_id == 1 && *editor_id == 1)
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_by_article_id()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    fixture
+        .mock_mentions_repository
+        .expect_replace_mentions()
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(vec![]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .restore_article_revision(1, String::from("stub-title"), 2)
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn return_forbidden_when_user_restores_another_users_article() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture.mock_articles_repository.expect_get_article_revision().times(0);
+
+    fixture.mock_unit_of_work_factory.expect_begin().times(0);
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .restore_article_revision(2, String::from("stub-title"), 1)
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::Forbidden)));
+}
+
+#[tokio::test]
+async fn return_not_found_when_revision_does_not_exist() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_revision()
+        .times(1)
+        .return_once(move |_, _| Ok(None));
+
+    fixture.mock_unit_of_work_factory.expect_begin().times(0);
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .restore_article_revision(1, String::from("stub-title"), 5)
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::NotFound(_))));
+}
@@ -0,0 +1,451 @@
+use std::sync::Arc;
+
+use conduit_core::articles::service::ArticlesService;
+use conduit_infrastructure::mocks::{stub_get_article_query, stub_upsert_article_query, ArticlesServiceTestFixture};
+
+use conduit_core::articles::repository::{DynArticlesRepository, GetArticleQuery, UpsertArticleQuery};
+use conduit_core::errors::ConduitError;
+use conduit_core::mentions::repository::DynMentionsRepository;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::tags::repository::{ArticleTagQuery, DynTagsRepository, TagEntity};
+use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::users::roles::Role;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+use conduit_domain::articles::models::UpdateArticleDto;
+use sqlx::types::time::OffsetDateTime;
+
+use conduit_infrastructure::services::articles_service::ConduitArticlesService;
+use conduit_infrastructure::services::utils::mentions_tracker::MentionsTracker;
+
+fn new_articles_service(fixture: ArticlesServiceTestFixture) -> ConduitArticlesService {
+    ConduitArticlesService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    )
+}
+
+#[tokio::test]
+async fn schedule_draft_when_publish_time_is_given() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| {
+            Ok(Some(GetArticleQuery {
+                status: String::from("draft"),
+                published_at: None,
+                ..stub_get_article_query()
+            }))
+        });
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_update_article()
+        .times(1)
+        .return_once(move |_, _, _, _, _, _| Ok(stub_upsert_article_query()));
+
+    fixture
+        .mock_articles_repository
+        .expect_update_article_publish_at()
+        .withf(|_, id, _| *id == 1)
+        .times(1)
+        .return_once(move |_, _, _| Ok(stub_upsert_article_query()));
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article_revision()
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_by_article_id()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    fixture
+        .mock_mentions_repository
+        .expect_get_article_mentions()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .update_article(
+            1,
+            Role::User,
+            String::from("stub-title"),
+            UpdateArticleDto {
+                publish_at: Some(String::from("2999-01-01T00:00:00Z")),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn return_bad_request_when_scheduling_published_article() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture.mock_unit_of_work_factory.expect_begin().times(0);
+
+    fixture
+        .mock_articles_repository
+        .expect_update_article_publish_at()
+        .times(0);
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .update_article(
+            1,
+            Role::User,
+            String::from("stub-title"),
+            UpdateArticleDto {
+                publish_at: Some(String::from("2999-01-01T00:00:00Z")),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::BadRequest(_))));
+}
+
+#[tokio::test]
+async fn replace_article_tags_when_tag_list_is_given() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_update_article()
+        .times(1)
+        .return_once(move |_, _, _, _, _, _| Ok(stub_upsert_article_query()));
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article_revision()
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    // the article is currently tagged with "rust" and "stale"
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_by_article_id()
+        .times(2)
+        .returning(move |_| {
+            Ok(vec![
+                ArticleTagQuery {
+                    id: 1,
+                    tag_id: 1,
+                    article_id: 1,
+                    tag: String::from("rust"),
+                },
+                ArticleTagQuery {
+                    id: 2,
+                    tag_id: 2,
+                    article_id: 1,
+                    tag: String::from("stale"),
+                },
+            ])
+        });
+
+    fixture
+        .mock_tags_repository
+        .expect_get_tags()
+        .withf(|tags| *tags == vec![String::from("rust"), String::from("new")])
+        .times(1)
+        .return_once(move |_| {
+            Ok(vec![TagEntity {
+                id: 1,
+                tag: String::from("rust"),
+                created_at: OffsetDateTime::now_utc(),
+            }])
+        });
+
+    fixture
+        .mock_tags_repository
+        .expect_create_tags()
+        .withf(|_, tags| *tags == vec![String::from("new")])
+        .times(1)
+        .return_once(move |_, _| {
+            Ok(vec![TagEntity {
+                id: 3,
+                tag: String::from("new"),
+                created_at: OffsetDateTime::now_utc(),
+            }])
+        });
+
+    fixture
+        .mock_tags_repository
+        .expect_create_article_tags()
+        .withf(|_, tags| *tags == vec![(3, 1)])
+        .times(1)
+        .return_once(move |_, _| Ok(()));
+
+    fixture
+        .mock_tags_repository
+        .expect_delete_article_tags()
+        .withf(|_, article_id, tag_ids| *article_id == 1 && *tag_ids == vec![2])
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture
+        .mock_tags_repository
+        .expect_delete_unreferenced_tags()
+        .withf(|_, tag_ids| *tag_ids == vec![2])
+        .times(1)
+        .return_once(move |_, _| Ok(1));
+
+    fixture
+        .mock_mentions_repository
+        .expect_get_article_mentions()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .update_article(
+            1,
+            Role::User,
+            String::from("stub-title"),
+            UpdateArticleDto {
+                tag_list: Some(vec![String::from("rust"), String::from("new"), String::from("rust")]),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn record_previous_slug_when_article_is_renamed() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_articles_repository
+        .expect_get_taken_slugs()
+        .withf(|_, slug, article_id| slug == "renamed-title" && *article_id == Some(1))
+        .times(1)
+        .return_once(move |_, _, _| Ok(vec![String::from("renamed-title")]));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_update_article()
+        .withf(|_, _, title, slug, _, _| title == "renamed title" && slug == "renamed-title-2")
+        .times(1)
+        .return_once(move |_, _, _, _, _, _| Ok(stub_upsert_article_query()));
+
+    fixture
+        .mock_articles_repository
+        .expect_record_slug_change()
+        .withf(|_, article_id, previous_slug, slug| {
+            *article_id == 1 && previous_slug == "stub-title" && slug == "renamed-title-2"
+        })
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(()));
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article_revision()
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_by_article_id()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    fixture
+        .mock_mentions_repository
+        .expect_get_article_mentions()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .update_article(
+            1,
+            Role::User,
+            String::from("stub-title"),
+            UpdateArticleDto {
+                title: Some(String::from("renamed title")),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn attribute_mentions_to_author_when_moderator_edits_body() {
+    // arrange
+    let mut fixture = ArticlesServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_articles_repository
+        .expect_update_article()
+        .withf(|_, _, _, _, _, body| body == "thanks @reader")
+        .times(1)
+        .return_once(move |_, _, _, _, _, body| {
+            Ok(UpsertArticleQuery {
+                body,
+                ..stub_upsert_article_query()
+            })
+        });
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_username()
+        .withf(|username| username == "reader")
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(UserEntity {
+                id: 3,
+                username: String::from("reader"),
+                ..UserEntity::default()
+            }))
+        });
+
+    fixture
+        .mock_mentions_repository
+        .expect_replace_mentions()
+        .withf(|_, article_id, comment_id, user_ids| *article_id == 1 && comment_id.is_none() && *user_ids == vec![3])
+        .times(1)
+        .return_once(move |_, _, _, user_ids| Ok(user_ids));
+
+    // the article's author is credited with the mention rather than the moderator editing it
+    fixture
+        .mock_notifications_repository
+        .expect_create_mention_notifications()
+        .withf(|_, actor_id, article_id, comment_id, user_ids| {
+            *actor_id == 1 && *article_id == 1 && comment_id.is_none() && *user_ids == vec![3]
+        })
+        .times(1)
+        .return_once(move |_, _, _, _, _| Ok(()));
+
+    fixture
+        .mock_articles_repository
+        .expect_create_article_revision()
+        .withf(|_, article_id, user_id| *article_id == 1 && *user_id == 2)
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_by_article_id()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    let articles_service = new_articles_service(fixture);
+
+    // act
+    let response = articles_service
+        .update_article(
+            2,
+            Role::Moderator,
+            String::from("stub-title"),
+            UpdateArticleDto {
+                body: Some(String::from("thanks @reader")),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+    assert_eq!(response.unwrap().mentions, vec![String::from("reader")]);
+}
@@ -0,0 +1,311 @@
+use std::sync::Arc;
+
+use conduit_core::articles::repository::DynArticlesRepository;
+use conduit_core::comments::repository::{CommentEntity, CommentQuery, DynCommentsRepository};
+use conduit_core::comments::service::CommentsService;
+use conduit_core::errors::ConduitError;
+use conduit_core::mentions::repository::DynMentionsRepository;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+use conduit_infrastructure::mocks::{
+    stub_comment_entity, stub_comment_query, stub_get_article_query, CommentsServiceTestFixture,
+};
+use mockall::predicate::*;
+
+use conduit_infrastructure::services::comments_service::ConduitCommentsService;
+use conduit_infrastructure::services::utils::mentions_tracker::MentionsTracker;
+
+fn new_comments_service(fixture: CommentsServiceTestFixture) -> ConduitCommentsService {
+    ConduitCommentsService::new(
+        Arc::new(fixture.mock_comments_repository) as DynCommentsRepository,
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+        fixture.config,
+    )
+}
+
+#[tokio::test]
+async fn add_reply_to_comment_on_the_same_article() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment()
+        .with(eq(1))
+        .times(1)
+        .return_once(move |_| Ok(Some(stub_comment_entity())));
+
+    fixture
+        .mock_comments_repository
+        .expect_create_comment()
+        .withf(|_, article_id, user_id, parent_id, _| *article_id == 1 && *user_id == 2 && *parent_id == Some(1))
+        .times(1)
+        .return_once(move |_, _, _, parent_id, _| {
+            Ok(CommentQuery {
+                id: 2,
+                parent_id,
+                ..stub_comment_query()
+            })
+        });
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_mentions_repository
+        .expect_replace_mentions()
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(vec![]));
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let comment = comments_service
+        .add_comment(2, String::from("stub-title"), Some(1), String::from("stub body"))
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(comment.parent_id, Some(1));
+}
+
+#[tokio::test]
+async fn return_not_found_when_parent_comment_does_not_exist() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment()
+        .times(1)
+        .return_once(move |_| Ok(None));
+
+    fixture.mock_comments_repository.expect_create_comment().times(0);
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .add_comment(2, String::from("stub-title"), Some(1), String::from("stub body"))
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn return_bad_request_when_parent_comment_is_on_another_article() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment()
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(CommentEntity {
+                article_id: 2,
+                ..stub_comment_entity()
+            }))
+        });
+
+    fixture.mock_comments_repository.expect_create_comment().times(0);
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .add_comment(2, String::from("stub-title"), Some(1), String::from("stub body"))
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::BadRequest(_))));
+}
+
+#[tokio::test]
+async fn notify_existing_users_newly_mentioned_in_comment() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_comments_repository
+        .expect_create_comment()
+        .times(1)
+        .return_once(move |_, _, _, _, body| {
+            Ok(CommentQuery {
+                id: 2,
+                body,
+                ..stub_comment_query()
+            })
+        });
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_username()
+        .withf(|username| username == "alice")
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(UserEntity {
+                id: 3,
+                username: String::from("alice"),
+                ..Default::default()
+            }))
+        });
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_username()
+        .withf(|username| username == "nobody")
+        .times(1)
+        .return_once(move |_| Ok(None));
+
+    fixture
+        .mock_mentions_repository
+        .expect_replace_mentions()
+        .withf(|_, article_id, comment_id, user_ids| *article_id == 1 && *comment_id == Some(2) && *user_ids == vec![3])
+        .times(1)
+        .return_once(move |_, _, _, user_ids| Ok(user_ids));
+
+    fixture
+        .mock_notifications_repository
+        .expect_create_mention_notifications()
+        .withf(|_, actor_id, article_id, comment_id, user_ids| {
+            *actor_id == 2 && *article_id == 1 && *comment_id == Some(2) && *user_ids == vec![3]
+        })
+        .times(1)
+        .return_once(move |_, _, _, _, _| Ok(()));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let comment = comments_service
+        .add_comment(
+            2,
+            String::from("stub-title"),
+            None,
+            String::from("thanks @alice, and @nobody, as @alice said"),
+        )
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(comment.mentions, vec!["alice"]);
+}
+
+#[tokio::test]
+async fn not_notify_commenter_of_mentioning_themselves() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_comments_repository
+        .expect_create_comment()
+        .times(1)
+        .return_once(move |_, _, _, _, body| {
+            Ok(CommentQuery {
+                id: 2,
+                body,
+                ..stub_comment_query()
+            })
+        });
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_username()
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(UserEntity {
+                id: 2,
+                username: String::from("bob"),
+                ..Default::default()
+            }))
+        });
+
+    fixture
+        .mock_mentions_repository
+        .expect_replace_mentions()
+        .times(1)
+        .return_once(move |_, _, _, user_ids| Ok(user_ids));
+
+    fixture
+        .mock_notifications_repository
+        .expect_create_mention_notifications()
+        .times(0);
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let comment = comments_service
+        .add_comment(2, String::from("stub-title"), None, String::from("note to self @bob"))
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(comment.mentions, vec!["bob"]);
+}
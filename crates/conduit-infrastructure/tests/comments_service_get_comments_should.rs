@@ -0,0 +1,371 @@
+use std::sync::Arc;
+
+use conduit_core::articles::repository::DynArticlesRepository;
+use conduit_core::comments::cursor::CommentCursor;
+use conduit_core::comments::repository::{CommentQuery, DynCommentsRepository};
+use conduit_core::comments::service::CommentsService;
+use conduit_core::config::AppConfig;
+use conduit_core::errors::ConduitError;
+use conduit_core::mentions::repository::{CommentMentionQuery, DynMentionsRepository};
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::reactions::repository::{DynReactionsRepository, ReactionCountQuery};
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::utils::unit_of_work::DynUnitOfWorkFactory;
+use conduit_domain::comments::requests::{CommentSort, GetCommentsServiceRequest};
+use conduit_infrastructure::mocks::{
+    stub_comment_query, stub_config, stub_get_article_query, stub_reaction_count_query, CommentsServiceTestFixture,
+};
+use sqlx::types::time::OffsetDateTime;
+
+use conduit_infrastructure::services::comments_service::ConduitCommentsService;
+use conduit_infrastructure::services::utils::mentions_tracker::MentionsTracker;
+
+fn new_comments_service(fixture: CommentsServiceTestFixture) -> ConduitCommentsService {
+    ConduitCommentsService::new(
+        Arc::new(fixture.mock_comments_repository) as DynCommentsRepository,
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+        fixture.config,
+    )
+}
+
+fn stub_comment(id: i64, parent_id: Option<i64>, deleted: bool) -> CommentQuery {
+    CommentQuery {
+        id,
+        body: format!("stub body {}", id),
+        parent_id,
+        deleted,
+        ..stub_comment_query()
+    }
+}
+
+fn stub_request() -> GetCommentsServiceRequest {
+    GetCommentsServiceRequest {
+        limit: 20,
+        ..Default::default()
+    }
+}
+
+fn expect_article(fixture: &mut CommentsServiceTestFixture) {
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+}
+
+fn expect_comment_reactions(fixture: &mut CommentsServiceTestFixture, reactions: Vec<ReactionCountQuery>) {
+    fixture
+        .mock_reactions_repository
+        .expect_get_comment_reactions()
+        .times(1)
+        .return_once(move |_, _| Ok(reactions));
+}
+
+fn expect_comment_mentions(fixture: &mut CommentsServiceTestFixture, mentions: Vec<CommentMentionQuery>) {
+    fixture
+        .mock_mentions_repository
+        .expect_get_comment_mentions()
+        .times(1)
+        .return_once(move |_| Ok(mentions));
+}
+
+fn expect_comments_count(fixture: &mut CommentsServiceTestFixture, count: i64) {
+    fixture
+        .mock_comments_repository
+        .expect_get_comments_count()
+        .times(1)
+        .return_once(move |_| Ok(count));
+}
+
+#[tokio::test]
+async fn return_replies_nested_under_their_parent_comments() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+    expect_article(&mut fixture);
+    expect_comments_count(&mut fixture, 4);
+    expect_comment_reactions(
+        &mut fixture,
+        vec![ReactionCountQuery {
+            target_id: 2,
+            reacted: true,
+            ..stub_reaction_count_query()
+        }],
+    );
+    expect_comment_mentions(
+        &mut fixture,
+        vec![CommentMentionQuery {
+            comment_id: 3,
+            username: String::from("stub username"),
+        }],
+    );
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comments()
+        .times(1)
+        .return_once(move |_, _, _, _, _| Ok(vec![stub_comment(1, None, false), stub_comment(3, None, false)]));
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment_replies()
+        .withf(|_, comment_ids| *comment_ids == vec![1, 3])
+        .times(1)
+        .return_once(move |_, _| Ok(vec![stub_comment(2, Some(1), false), stub_comment(4, Some(2), false)]));
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .get_comments(None, String::from("stub-title"), stub_request())
+        .await
+        .unwrap();
+
+    // assert
+    let comments = response.comments;
+    assert_eq!(
+        comments.iter().map(|comment| comment.id).collect::<Vec<_>>(),
+        vec![1, 3]
+    );
+    assert_eq!(comments[0].replies[0].id, 2);
+    assert_eq!(comments[0].replies[0].replies[0].id, 4);
+    assert!(comments[1].replies.is_empty());
+    assert!(comments[0].reactions.is_empty());
+    assert_eq!(comments[0].replies[0].reactions[0].count, 1);
+    assert_eq!(comments[0].replies[0].my_reactions, vec!["👍"]);
+    assert!(comments[0].mentions.is_empty());
+    assert_eq!(comments[1].mentions, vec!["stub username"]);
+    assert_eq!(response.comments_count, 4);
+    assert!(response.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn list_replies_beyond_max_depth_under_their_ancestor_at_max_depth() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture {
+        config: Arc::new(AppConfig {
+            comment_max_depth: 1,
+            ..stub_config()
+        }),
+        ..Default::default()
+    };
+    expect_article(&mut fixture);
+    expect_comments_count(&mut fixture, 4);
+    expect_comment_reactions(&mut fixture, vec![]);
+    expect_comment_mentions(&mut fixture, vec![]);
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comments()
+        .times(1)
+        .return_once(move |_, _, _, _, _| Ok(vec![stub_comment(1, None, false)]));
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment_replies()
+        .times(1)
+        .return_once(move |_, _| {
+            Ok(vec![
+                stub_comment(2, Some(1), false),
+                stub_comment(3, Some(2), false),
+                stub_comment(4, Some(3), false),
+            ])
+        });
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .get_comments(None, String::from("stub-title"), stub_request())
+        .await
+        .unwrap();
+
+    // assert
+    let replies = &response.comments[0].replies[0].replies;
+    assert_eq!(replies.iter().map(|reply| reply.id).collect::<Vec<_>>(), vec![3, 4]);
+    assert!(replies.iter().all(|reply| reply.replies.is_empty()));
+}
+
+#[tokio::test]
+async fn keep_deleted_comments_with_replies_as_placeholders() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+    expect_article(&mut fixture);
+    expect_comments_count(&mut fixture, 1);
+    expect_comment_reactions(&mut fixture, vec![]);
+    expect_comment_mentions(&mut fixture, vec![]);
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comments()
+        .times(1)
+        .return_once(move |_, _, _, _, _| Ok(vec![stub_comment(1, None, true)]));
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment_replies()
+        .times(1)
+        .return_once(move |_, _| Ok(vec![stub_comment(2, Some(1), false), stub_comment(3, Some(1), true)]));
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .get_comments(None, String::from("stub-title"), stub_request())
+        .await
+        .unwrap();
+
+    // assert
+    let comments = response.comments;
+    assert_eq!(comments.len(), 1);
+    assert!(comments[0].deleted);
+    assert_eq!(comments[0].body, "[deleted]");
+    assert_eq!(comments[0].author.username, "[deleted]");
+    assert!(comments[0].body_html.is_none());
+    assert_eq!(
+        comments[0].replies.iter().map(|reply| reply.id).collect::<Vec<_>>(),
+        vec![2]
+    );
+}
+
+#[tokio::test]
+async fn return_next_cursor_when_page_is_full() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+    let created_at = OffsetDateTime::from_unix_timestamp(1_666_000_000);
+    let cursor = CommentCursor::new(0, OffsetDateTime::from_unix_timestamp(1_666_100_000), 5);
+    expect_article(&mut fixture);
+    expect_comments_count(&mut fixture, 5);
+    expect_comment_reactions(&mut fixture, vec![]);
+    expect_comment_mentions(&mut fixture, vec![]);
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comments()
+        .withf(move |_, _, sort, limit, requested_cursor| {
+            *sort == CommentSort::Newest && *limit == 1 && *requested_cursor == Some(cursor)
+        })
+        .times(1)
+        .return_once(move |_, _, _, _, _| {
+            Ok(vec![CommentQuery {
+                id: 4,
+                created_at,
+                ..stub_comment_query()
+            }])
+        });
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment_replies()
+        .times(1)
+        .return_once(move |_, _| Ok(vec![]));
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .get_comments(
+            None,
+            String::from("stub-title"),
+            GetCommentsServiceRequest {
+                limit: 1,
+                cursor: Some(cursor.encode()),
+                sort: CommentSort::Newest,
+            },
+        )
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(
+        response.next_cursor,
+        Some(CommentCursor::new(0, created_at, 4).encode())
+    );
+}
+
+#[tokio::test]
+async fn return_next_cursor_positioned_by_reaction_count_when_sorting_by_most_reacted() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+    let created_at = OffsetDateTime::from_unix_timestamp(1_666_000_000);
+    let cursor = CommentCursor::new(7, OffsetDateTime::from_unix_timestamp(1_666_100_000), 5);
+    expect_article(&mut fixture);
+    expect_comments_count(&mut fixture, 5);
+    expect_comment_reactions(&mut fixture, vec![]);
+    expect_comment_mentions(&mut fixture, vec![]);
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comments()
+        .withf(move |_, _, sort, _, requested_cursor| {
+            *sort == CommentSort::MostReacted && *requested_cursor == Some(cursor)
+        })
+        .times(1)
+        .return_once(move |_, _, _, _, _| {
+            Ok(vec![CommentQuery {
+                id: 4,
+                created_at,
+                reactions_count: 3,
+                ..stub_comment_query()
+            }])
+        });
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment_replies()
+        .times(1)
+        .return_once(move |_, _| Ok(vec![]));
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .get_comments(
+            None,
+            String::from("stub-title"),
+            GetCommentsServiceRequest {
+                limit: 1,
+                cursor: Some(cursor.encode()),
+                sort: CommentSort::MostReacted,
+            },
+        )
+        .await
+        .unwrap();
+
+    // assert
+    let next_cursor = CommentCursor::decode(&response.next_cursor.unwrap()).unwrap();
+    assert_eq!(next_cursor, CommentCursor::new(3, created_at, 4));
+}
+
+#[tokio::test]
+async fn return_bad_request_when_cursor_is_invalid() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+    expect_article(&mut fixture);
+
+    fixture.mock_comments_repository.expect_get_comments().times(0);
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .get_comments(
+            None,
+            String::from("stub-title"),
+            GetCommentsServiceRequest {
+                limit: 20,
+                cursor: Some(String::from("not a cursor")),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::BadRequest(_))));
+}
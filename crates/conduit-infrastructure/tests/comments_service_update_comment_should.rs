@@ -0,0 +1,258 @@
+use std::ops::Sub;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use conduit_core::articles::repository::DynArticlesRepository;
+use conduit_core::comments::repository::{CommentEntity, CommentQuery, DynCommentsRepository};
+use conduit_core::comments::service::CommentsService;
+use conduit_core::errors::ConduitError;
+use conduit_core::mentions::repository::DynMentionsRepository;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::reactions::repository::DynReactionsRepository;
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::users::roles::Role;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+use conduit_infrastructure::mocks::{
+    stub_comment_entity, stub_comment_query, stub_get_article_query, CommentsServiceTestFixture,
+};
+use mockall::predicate::*;
+use sqlx::types::time::OffsetDateTime;
+
+use conduit_infrastructure::services::comments_service::ConduitCommentsService;
+use conduit_infrastructure::services::utils::mentions_tracker::MentionsTracker;
+
+fn new_comments_service(fixture: CommentsServiceTestFixture) -> ConduitCommentsService {
+    ConduitCommentsService::new(
+        Arc::new(fixture.mock_comments_repository) as DynCommentsRepository,
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        MentionsTracker::new(
+            Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+            Arc::new(fixture.mock_mentions_repository) as DynMentionsRepository,
+            Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository,
+        ),
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+        fixture.config,
+    )
+}
+
+#[tokio::test]
+async fn record_previous_body_and_update_comment_within_edit_window() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .with(eq(None), eq(String::from("stub-title")))
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment()
+        .with(eq(1))
+        .times(1)
+        .return_once(move |_| Ok(Some(stub_comment_entity())));
+
+    fixture
+        .mock_comments_repository
+        .expect_create_comment_revision()
+        .withf(|_, comment_id| *comment_id == 1)
+        .times(1)
+        .return_once(move |_, _| Ok(()));
+
+    fixture
+        .mock_comments_repository
+        .expect_update_comment()
+        .withf(|_, comment_id, body| *comment_id == 1 && body == "edited body")
+        .times(1)
+        .return_once(move |_, _, body| {
+            Ok(CommentQuery {
+                body,
+                edited: true,
+                ..stub_comment_query()
+            })
+        });
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_mentions_repository
+        .expect_replace_mentions()
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(vec![]));
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let comment = comments_service
+        .update_comment(1, String::from("stub-title"), 1, String::from("edited body"))
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(comment.body, "edited body");
+    assert!(comment.edited);
+}
+
+#[tokio::test]
+async fn return_forbidden_when_user_is_not_the_commenter() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment()
+        .times(1)
+        .return_once(move |_| Ok(Some(stub_comment_entity())));
+
+    fixture.mock_comments_repository.expect_update_comment().times(0);
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .update_comment(2, String::from("stub-title"), 1, String::from("edited body"))
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::Forbidden)));
+}
+
+#[tokio::test]
+async fn return_bad_request_when_edit_window_has_passed() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment()
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(CommentEntity {
+                created_at: OffsetDateTime::from(SystemTime::now().sub(Duration::from_secs(16 * 60))),
+                ..stub_comment_entity()
+            }))
+        });
+
+    fixture.mock_comments_repository.expect_update_comment().times(0);
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .update_comment(1, String::from("stub-title"), 1, String::from("edited body"))
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::BadRequest(_))));
+}
+
+#[tokio::test]
+async fn return_forbidden_when_non_moderator_requests_comment_revisions() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+
+    fixture.mock_comments_repository.expect_get_comment_revisions().times(0);
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .get_comment_revisions(Role::User, String::from("stub-title"), 1)
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::Forbidden)));
+}
+
+#[tokio::test]
+async fn return_not_found_when_comment_belongs_to_another_article() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment()
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(CommentEntity {
+                article_id: 2,
+                ..stub_comment_entity()
+            }))
+        });
+
+    fixture.mock_comments_repository.expect_update_comment().times(0);
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .update_comment(1, String::from("stub-title"), 1, String::from("edited body"))
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn return_not_found_when_requesting_revisions_of_a_comment_on_another_article() {
+    // arrange
+    let mut fixture = CommentsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment()
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(CommentEntity {
+                article_id: 2,
+                ..stub_comment_entity()
+            }))
+        });
+
+    fixture.mock_comments_repository.expect_get_comment_revisions().times(0);
+
+    let comments_service = new_comments_service(fixture);
+
+    // act
+    let response = comments_service
+        .get_comment_revisions(Role::Moderator, String::from("stub-title"), 1)
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::NotFound(_))));
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use conduit_core::articles::repository::{DynArticlesRepository, GetArticleQuery};
+use conduit_core::feeds::service::FeedsService;
+use conduit_core::feeds::FeedFormat;
+use conduit_core::tags::repository::{ArticleTagQuery, DynTagsRepository};
+use conduit_infrastructure::mocks::{stub_get_article_query, FeedsServiceTestFixture};
+use sqlx::types::time::OffsetDateTime;
+
+use conduit_infrastructure::services::feeds_service::ConduitFeedsService;
+
+fn new_feeds_service(fixture: FeedsServiceTestFixture) -> ConduitFeedsService {
+    ConduitFeedsService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_tags_repository) as DynTagsRepository,
+        fixture.config,
+    )
+}
+
+fn stub_article() -> GetArticleQuery {
+    GetArticleQuery {
+        id: 1,
+        created_at: OffsetDateTime::from_unix_timestamp(1_666_000_000),
+        updated_at: OffsetDateTime::from_unix_timestamp(1_666_086_400),
+        title: String::from("Rust & Axum"),
+        body: String::from("Learn about **traits**"),
+        description: String::from("a stub description"),
+        slug: String::from("rust-axum"),
+        published_at: Some(OffsetDateTime::from_unix_timestamp(1_666_000_000)),
+        author_username: String::from("stub username"),
+        ..stub_get_article_query()
+    }
+}
+
+#[tokio::test]
+async fn return_atom_feed_updated_with_latest_article() {
+    // arrange
+    let mut fixture = FeedsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_articles()
+        .withf(|user_id, request, cursor| user_id.is_none() && request.author.is_none() && cursor.is_none())
+        .times(1)
+        .return_once(move |_, _, _| Ok(vec![stub_article()]));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_article_ids()
+        .times(1)
+        .return_once(move |_| {
+            Ok(vec![ArticleTagQuery {
+                id: 1,
+                tag_id: 1,
+                article_id: 1,
+                tag: String::from("rust"),
+            }])
+        });
+
+    let feeds_service = new_feeds_service(fixture);
+
+    // act
+    let feed = feeds_service
+        .get_articles_feed(FeedFormat::Atom, None, None)
+        .await
+        .unwrap();
+
+    // assert
+    assert!(feed.content.contains("<updated>2022-10-18T09:46:40Z</updated>"));
+    assert!(feed.content.contains("<title>Rust &amp; Axum</title>"));
+    assert!(
+        feed.content
+            .contains("<id>http://localhost:3000/article/rust-axum</id>")
+    );
+    assert!(feed.content.contains("&lt;strong&gt;traits&lt;/strong&gt;"));
+    assert!(feed.content.contains(r#"<category term="rust"/>"#));
+    assert!(feed.etag.starts_with('"') && feed.etag.ends_with('"'));
+}
+
+#[tokio::test]
+async fn return_rss_feed_filtered_by_author() {
+    // arrange
+    let mut fixture = FeedsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_articles()
+        .withf(|_, request, _| request.author == Some(String::from("stub username")))
+        .times(1)
+        .return_once(move |_, _, _| Ok(vec![stub_article()]));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_article_ids()
+        .times(1)
+        .return_once(move |_| Ok(vec![]));
+
+    let feeds_service = new_feeds_service(fixture);
+
+    // act
+    let feed = feeds_service
+        .get_articles_feed(FeedFormat::Rss, Some(String::from("stub username")), None)
+        .await
+        .unwrap();
+
+    // assert
+    assert!(feed.content.contains("<dc:creator>stub username</dc:creator>"));
+    assert!(
+        feed.content
+            .contains("<pubDate>Mon, 17 Oct 2022 09:46:40 +0000</pubDate>")
+    );
+    assert!(
+        feed.content
+            .contains("http://localhost:8080/feeds/profiles/stub%20username.rss")
+    );
+}
+
+#[tokio::test]
+async fn return_empty_feed_when_there_are_no_articles() {
+    // arrange
+    let mut fixture = FeedsServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_articles()
+        .times(1)
+        .return_once(move |_, _, _| Ok(vec![]));
+
+    fixture
+        .mock_tags_repository
+        .expect_get_article_tags_article_ids()
+        .times(0);
+
+    let feeds_service = new_feeds_service(fixture);
+
+    // act
+    let feed = feeds_service
+        .get_articles_feed(FeedFormat::Atom, None, Some(String::from("rust")))
+        .await
+        .unwrap();
+
+    // assert
+    assert!(!feed.content.contains("<entry>"));
+}
@@ -0,0 +1,42 @@
+use conduit_infrastructure::services::utils::markdown::render_markdown;
+
+#[test]
+fn render_markdown_as_html() {
+    // arrange
+    let markdown = "# Traits\n\nLearn about **traits** with [the book](https://doc.rust-lang.org/book/).";
+
+    // act
+    let rendered = render_markdown(markdown);
+
+    // assert
+    assert!(rendered.contains("<h1>Traits</h1>"));
+    assert!(rendered.contains("<strong>traits</strong>"));
+    assert!(rendered.contains(r#"<a href="https://doc.rust-lang.org/book/" rel="noopener noreferrer nofollow">"#));
+}
+
+#[test]
+fn strip_scripts_and_event_handlers_from_embedded_html() {
+    // arrange
+    let markdown =
+        "<script>alert('xss')</script>\n\n<img src=\"https://example.com/cat.png\" onerror=\"alert('xss')\">";
+
+    // act
+    let rendered = render_markdown(markdown);
+
+    // assert
+    assert!(!rendered.contains("<script"));
+    assert!(!rendered.contains("onerror"));
+    assert!(rendered.contains(r#"<img src="https://example.com/cat.png">"#));
+}
+
+#[test]
+fn strip_javascript_links() {
+    // arrange
+    let markdown = "[click me](javascript:alert('xss'))";
+
+    // act
+    let rendered = render_markdown(markdown);
+
+    // assert
+    assert!(!rendered.contains("javascript:"));
+}
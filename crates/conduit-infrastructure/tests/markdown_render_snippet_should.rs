@@ -0,0 +1,40 @@
+use conduit_infrastructure::services::utils::markdown::render_snippet;
+
+#[test]
+fn keep_highlighted_matches() {
+    // arrange
+    let snippet = "Learn about <mark>traits</mark> & generics";
+
+    // act
+    let rendered = render_snippet(snippet);
+
+    // assert
+    assert_eq!(rendered, "Learn about <mark>traits</mark> &amp; generics");
+}
+
+#[test]
+fn strip_scripts_and_other_markup_from_the_body() {
+    // arrange
+    let snippet = "<script>alert('xss')</script><mark>traits</mark> <img src=x onerror=\"alert('xss')\">";
+
+    // act
+    let rendered = render_snippet(snippet);
+
+    // assert
+    assert!(!rendered.contains("<script"));
+    assert!(!rendered.contains("alert"));
+    assert!(!rendered.contains("<img"));
+    assert!(rendered.contains("<mark>traits</mark>"));
+}
+
+#[test]
+fn close_marks_cut_off_by_the_snippet() {
+    // arrange
+    let snippet = "a <mark>trait</mark></mark> and an unclosed <mark>generic";
+
+    // act
+    let rendered = render_snippet(snippet);
+
+    // assert
+    assert_eq!(rendered, "a <mark>trait</mark> and an unclosed <mark>generic</mark>");
+}
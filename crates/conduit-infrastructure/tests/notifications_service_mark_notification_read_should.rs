@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use conduit_core::errors::ConduitError;
+use conduit_core::notifications::repository::DynNotificationsRepository;
+use conduit_core::notifications::service::NotificationsService;
+use conduit_infrastructure::mocks::NotificationsServiceTestFixture;
+use mockall::predicate::*;
+
+use conduit_infrastructure::services::notifications_service::ConduitNotificationsService;
+
+fn new_notifications_service(fixture: NotificationsServiceTestFixture) -> ConduitNotificationsService {
+    ConduitNotificationsService::new(Arc::new(fixture.mock_notifications_repository) as DynNotificationsRepository)
+}
+
+#[tokio::test]
+async fn mark_notification_read_when_it_belongs_to_the_user() {
+    // arrange
+    let mut fixture = NotificationsServiceTestFixture::default();
+
+    fixture
+        .mock_notifications_repository
+        .expect_mark_notifications_read()
+        .with(eq(1), eq(Some(2)))
+        .times(1)
+        .return_once(move |_, _| Ok(1));
+
+    let notifications_service = new_notifications_service(fixture);
+
+    // act
+    let response = notifications_service.mark_notification_read(1, 2).await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn return_not_found_when_notification_belongs_to_another_user() {
+    // arrange
+    let mut fixture = NotificationsServiceTestFixture::default();
+
+    fixture
+        .mock_notifications_repository
+        .expect_mark_notifications_read()
+        .times(1)
+        .return_once(move |_, _| Ok(0));
+
+    let notifications_service = new_notifications_service(fixture);
+
+    // act
+    let response = notifications_service.mark_notification_read(1, 2).await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::NotFound(_))));
+}
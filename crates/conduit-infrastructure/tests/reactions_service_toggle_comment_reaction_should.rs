@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use conduit_core::articles::repository::DynArticlesRepository;
+use conduit_core::comments::repository::{CommentEntity, DynCommentsRepository};
+use conduit_core::errors::ConduitError;
+use conduit_core::reactions::repository::{DynReactionsRepository, ReactionCountQuery};
+use conduit_core::reactions::service::ReactionsService;
+use conduit_core::reactions::ReactionTarget;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+use conduit_infrastructure::mocks::{
+    stub_comment_entity, stub_get_article_query, stub_reaction_count_query, ReactionsServiceTestFixture,
+};
+use mockall::predicate::*;
+
+use conduit_infrastructure::services::reactions_service::ConduitReactionsService;
+
+fn new_reactions_service(fixture: ReactionsServiceTestFixture) -> ConduitReactionsService {
+    ConduitReactionsService::new(
+        Arc::new(fixture.mock_reactions_repository) as DynReactionsRepository,
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_comments_repository) as DynCommentsRepository,
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+        fixture.config,
+    )
+}
+
+fn expect_article(fixture: &mut ReactionsServiceTestFixture) {
+    fixture
+        .mock_articles_repository
+        .expect_get_article_by_slug()
+        .with(eq(None), eq(String::from("stub-title")))
+        .times(1)
+        .return_once(move |_, _| Ok(Some(stub_get_article_query())));
+}
+
+#[tokio::test]
+async fn add_reaction_when_comment_belongs_to_the_article() {
+    // arrange
+    let mut fixture = ReactionsServiceTestFixture::default();
+    expect_article(&mut fixture);
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment()
+        .with(eq(1))
+        .times(1)
+        .return_once(move |_| Ok(Some(stub_comment_entity())));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_reactions_repository
+        .expect_remove_reaction()
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(0));
+
+    fixture
+        .mock_reactions_repository
+        .expect_add_reaction()
+        .withf(|_, user_id, target, emoji| *user_id == 2 && *target == ReactionTarget::Comment(1) && emoji == "👍")
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(()));
+
+    fixture
+        .mock_reactions_repository
+        .expect_get_comment_reactions()
+        .withf(|user_id, comment_ids| *user_id == Some(2) && *comment_ids == vec![1])
+        .times(1)
+        .return_once(move |_, _| {
+            Ok(vec![ReactionCountQuery {
+                reacted: true,
+                ..stub_reaction_count_query()
+            }])
+        });
+
+    let reactions_service = new_reactions_service(fixture);
+
+    // act
+    let response = reactions_service
+        .toggle_comment_reaction(2, String::from("stub-title"), 1, String::from("👍"))
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(response.reactions.len(), 1);
+    assert_eq!(response.my_reactions, vec!["👍"]);
+}
+
+#[tokio::test]
+async fn return_not_found_when_comment_belongs_to_another_article() {
+    // arrange
+    let mut fixture = ReactionsServiceTestFixture::default();
+    expect_article(&mut fixture);
+
+    fixture
+        .mock_comments_repository
+        .expect_get_comment()
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(CommentEntity {
+                article_id: 2,
+                ..stub_comment_entity()
+            }))
+        });
+
+    fixture.mock_reactions_repository.expect_add_reaction().times(0);
+
+    let reactions_service = new_reactions_service(fixture);
+
+    // act
+    let response = reactions_service
+        .toggle_comment_reaction(2, String::from("stub-title"), 1, String::from("👍"))
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::NotFound(_))));
+}
@@ -0,0 +1,199 @@
+use std::ops::Sub;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use conduit_core::sessions::service::SessionsService;
+use conduit_infrastructure::mocks::{stub_refresh_token_entity, SessionsServiceTestFixture};
+use mockall::predicate::*;
+use sqlx::types::time::OffsetDateTime;
+
+use conduit_core::errors::ConduitError;
+use conduit_core::sessions::repository::{DynSessionsRepository, RefreshTokenEntity};
+use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::users::roles::Role;
+use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+
+use conduit_infrastructure::services::sessions_service::ConduitSessionsService;
+
+fn new_sessions_service(fixture: SessionsServiceTestFixture) -> ConduitSessionsService {
+    ConduitSessionsService::new(
+        Arc::new(fixture.mock_sessions_repository) as DynSessionsRepository,
+        Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+        Arc::new(fixture.mock_token_service) as DynTokenService,
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+        fixture.config,
+    )
+}
+
+#[tokio::test]
+async fn return_rotated_token_when_refresh_token_is_active() {
+    // arrange
+    let mut fixture = SessionsServiceTestFixture::default();
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .returning(|token| format!("{} hash", token));
+
+    fixture
+        .mock_sessions_repository
+        .expect_get_refresh_token()
+        .with(eq(String::from("stub refresh token hash")))
+        .times(1)
+        .return_once(move |_| Ok(Some(stub_refresh_token_entity())));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_sessions_repository
+        .expect_revoke_refresh_token()
+        .withf(|_, id| *id == 1)
+        .times(1)
+        .return_once(move |_, _| Ok(true));
+
+    fixture
+        .mock_token_service
+        .expect_new_opaque_token()
+        .times(1)
+        .return_once(|| String::from("rotated refresh token"));
+
+    fixture
+        .mock_sessions_repository
+        .expect_create_refresh_token()
+        .withf(|_, user_id, token_hash, family, _| {
+            *user_id == 1 && token_hash == "rotated refresh token hash" && family == "stub family"
+        })
+        .times(1)
+        .return_once(move |_, _, _, _, _| Ok(stub_refresh_token_entity()));
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_id()
+        .with(eq(1))
+        .times(1)
+        .return_once(move |_| Ok(UserEntity::default()));
+
+    fixture
+        .mock_token_service
+        .expect_new_token()
+        .with(eq(1_i64), eq("stub email"), eq(Role::User))
+        .times(1)
+        .return_once(move |_, _, _| Ok(String::from("stub token")));
+
+    let sessions_service = new_sessions_service(fixture);
+
+    // act
+    let response = sessions_service
+        .refresh_session(String::from("stub refresh token"))
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+    assert_eq!(
+        response.unwrap().refresh_token,
+        Some(String::from("rotated refresh token"))
+    );
+}
+
+#[tokio::test]
+async fn revoke_token_family_when_revoked_token_is_reused() {
+    // arrange
+    let mut fixture = SessionsServiceTestFixture::default();
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .returning(|token| format!("{} hash", token));
+
+    fixture
+        .mock_sessions_repository
+        .expect_get_refresh_token()
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(RefreshTokenEntity {
+                revoked_at: Some(OffsetDateTime::from(SystemTime::now())),
+                ..stub_refresh_token_entity()
+            }))
+        });
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture.mock_sessions_repository.expect_revoke_refresh_token().times(0);
+
+    fixture
+        .mock_sessions_repository
+        .expect_revoke_refresh_token_family()
+        .withf(|_, family| family == "stub family")
+        .times(1)
+        .return_once(move |_, _| Ok(()));
+
+    fixture.mock_sessions_repository.expect_create_refresh_token().times(0);
+
+    fixture.mock_token_service.expect_new_token().times(0);
+
+    let sessions_service = new_sessions_service(fixture);
+
+    let expected_err = ConduitError::Unauthorized.to_string();
+
+    // act
+    let response = sessions_service
+        .refresh_session(String::from("stub refresh token"))
+        .await;
+
+    // assert
+    assert!(response.is_err());
+    assert_eq!(response.unwrap_err().to_string(), expected_err);
+}
+
+#[tokio::test]
+async fn return_error_when_refresh_token_has_expired() {
+    // arrange
+    let mut fixture = SessionsServiceTestFixture::default();
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .returning(|token| format!("{} hash", token));
+
+    fixture
+        .mock_sessions_repository
+        .expect_get_refresh_token()
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(RefreshTokenEntity {
+                expires_at: OffsetDateTime::from(SystemTime::now().sub(Duration::from_secs(60))),
+                ..stub_refresh_token_entity()
+            }))
+        });
+
+    fixture.mock_unit_of_work_factory.expect_begin().times(0);
+
+    fixture.mock_token_service.expect_new_token().times(0);
+
+    let sessions_service = new_sessions_service(fixture);
+
+    // act
+    let response = sessions_service
+        .refresh_session(String::from("stub refresh token"))
+        .await;
+
+    // assert
+    assert!(response.is_err());
+}
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use conduit_core::trash::service::TrashService;
+use conduit_infrastructure::mocks::TrashServiceTestFixture;
+
+use conduit_core::articles::repository::DynArticlesRepository;
+use conduit_core::comments::repository::DynCommentsRepository;
+use sqlx::types::time::OffsetDateTime;
+
+use conduit_infrastructure::services::trash_service::ConduitTrashService;
+
+fn new_trash_service(fixture: TrashServiceTestFixture) -> ConduitTrashService {
+    ConduitTrashService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_comments_repository) as DynCommentsRepository,
+        fixture.config,
+    )
+}
+
+#[tokio::test]
+async fn purge_items_deleted_before_the_retention_window() {
+    // arrange
+    let mut fixture = TrashServiceTestFixture::default();
+
+    // the stub configuration keeps deleted items for 30 days
+    let is_retention_cutoff = |deleted_before: &OffsetDateTime| {
+        let retention = OffsetDateTime::now_utc() - *deleted_before;
+        retention.whole_days() == 30
+    };
+
+    fixture
+        .mock_comments_repository
+        .expect_purge_deleted_comments()
+        .withf(is_retention_cutoff)
+        .times(1)
+        .return_once(move |_| Ok(3));
+
+    fixture
+        .mock_articles_repository
+        .expect_purge_deleted_articles()
+        .withf(is_retention_cutoff)
+        .times(1)
+        .return_once(move |_| Ok(2));
+
+    let trash_service = new_trash_service(fixture);
+
+    // act
+    let response = trash_service.purge_expired().await;
+
+    // assert
+    assert!(matches!(response, Ok(5)));
+}
@@ -0,0 +1,104 @@
+use std::ops::Sub;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use conduit_core::trash::service::TrashService;
+use conduit_infrastructure::mocks::{stub_deleted_article_entity, TrashServiceTestFixture};
+use mockall::predicate::*;
+
+use conduit_core::articles::repository::{DeletedArticleEntity, DynArticlesRepository};
+use conduit_core::comments::repository::DynCommentsRepository;
+use conduit_core::errors::ConduitError;
+use sqlx::types::time::OffsetDateTime;
+
+use conduit_infrastructure::services::trash_service::ConduitTrashService;
+
+fn new_trash_service(fixture: TrashServiceTestFixture) -> ConduitTrashService {
+    ConduitTrashService::new(
+        Arc::new(fixture.mock_articles_repository) as DynArticlesRepository,
+        Arc::new(fixture.mock_comments_repository) as DynCommentsRepository,
+        fixture.config,
+    )
+}
+
+#[tokio::test]
+async fn return_success_when_user_restores_their_deleted_article() {
+    // arrange
+    let mut fixture = TrashServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_deleted_article()
+        .with(eq(String::from("stub-title")))
+        .times(1)
+        .return_once(move |_| Ok(Some(stub_deleted_article_entity())));
+
+    fixture
+        .mock_articles_repository
+        .expect_restore_article()
+        .with(eq(1_i64))
+        .times(1)
+        .return_once(move |_| Ok(()));
+
+    let trash_service = new_trash_service(fixture);
+
+    // act
+    let response = trash_service.restore_article(1, String::from("stub-title")).await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn return_forbidden_when_article_was_deleted_by_another_user() {
+    // arrange
+    let mut fixture = TrashServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_deleted_article()
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(DeletedArticleEntity {
+                deleted_by: Some(2),
+                ..stub_deleted_article_entity()
+            }))
+        });
+
+    fixture.mock_articles_repository.expect_restore_article().times(0);
+
+    let trash_service = new_trash_service(fixture);
+
+    // act
+    let response = trash_service.restore_article(1, String::from("stub-title")).await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::Forbidden)));
+}
+
+#[tokio::test]
+async fn return_not_found_when_article_was_deleted_before_the_retention_window() {
+    // arrange
+    let mut fixture = TrashServiceTestFixture::default();
+
+    fixture
+        .mock_articles_repository
+        .expect_get_deleted_article()
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(DeletedArticleEntity {
+                deleted_at: OffsetDateTime::from(SystemTime::now().sub(Duration::from_secs(31 * 24 * 60 * 60))),
+                ..stub_deleted_article_entity()
+            }))
+        });
+
+    fixture.mock_articles_repository.expect_restore_article().times(0);
+
+    let trash_service = new_trash_service(fixture);
+
+    // act
+    let response = trash_service.restore_article(1, String::from("stub-title")).await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::NotFound(_))));
+}
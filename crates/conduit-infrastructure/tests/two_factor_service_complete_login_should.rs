@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::two_factor::service::TwoFactorService;
+use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::users::roles::Role;
+use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::totp_service::DynTotpService;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+use conduit_infrastructure::mocks::{stub_login_challenge_entity, TwoFactorServiceTestFixture};
+use conduit_infrastructure::services::two_factor_service::ConduitTwoFactorService;
+use mockall::predicate::*;
+use sqlx::types::time::OffsetDateTime;
+
+fn new_two_factor_service(fixture: TwoFactorServiceTestFixture) -> ConduitTwoFactorService {
+    ConduitTwoFactorService::new(
+        Arc::new(fixture.mock_users_repository) as DynUsersRepository,
+        Arc::new(fixture.mock_sessions_repository) as DynSessionsRepository,
+        Arc::new(fixture.mock_totp_service) as DynTotpService,
+        Arc::new(fixture.mock_token_service) as DynTokenService,
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+    )
+}
+
+fn arrange_pending_challenge(fixture: &mut TwoFactorServiceTestFixture) {
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .with(eq("stub challenge"))
+        .times(1)
+        .return_once(move |_| String::from("stub challenge hash"));
+
+    fixture
+        .mock_sessions_repository
+        .expect_get_login_challenge()
+        .with(eq(String::from("stub challenge hash")))
+        .times(1)
+        .return_once(move |_| Ok(Some(stub_login_challenge_entity())));
+
+    fixture
+        .mock_users_repository
+        .expect_get_user_by_id()
+        .times(1)
+        .return_once(move |_| {
+            Ok(UserEntity {
+                totp_secret: Some(String::from("stub secret")),
+                totp_enabled_at: Some(OffsetDateTime::now_utc()),
+                ..Default::default()
+            })
+        });
+}
+
+#[tokio::test]
+async fn return_success_when_code_is_valid() {
+    // arrange
+    let mut fixture = TwoFactorServiceTestFixture::default();
+
+    arrange_pending_challenge(&mut fixture);
+
+    fixture
+        .mock_totp_service
+        .expect_verify_code()
+        .with(eq("stub secret"), eq("123456"))
+        .times(1)
+        .return_once(move |_, _| Ok(Some(42)));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_users_repository
+        .expect_update_user_totp_last_used_step()
+        .withf(|_, id, step| *id == 1 && *step == 42)
+        .times(1)
+        .return_once(move |_, _, _| Ok(true));
+
+    fixture
+        .mock_sessions_repository
+        .expect_use_login_challenge()
+        .withf(|_, id| *id == 1)
+        .times(1)
+        .return_once(move |_, _| Ok(true));
+
+    fixture
+        .mock_token_service
+        .expect_new_token()
+        .with(eq(1_i64), eq("stub email"), eq(Role::User))
+        .times(1)
+        .return_once(move |_, _, _| Ok(String::from("stub token")));
+
+    let two_factor_service = new_two_factor_service(fixture);
+
+    // act
+    let response = two_factor_service
+        .complete_login(String::from("stub challenge"), String::from("123456"))
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+    assert_eq!(response.unwrap().token, "stub token");
+}
+
+#[tokio::test]
+async fn return_error_and_record_attempt_when_code_is_invalid() {
+    // arrange
+    let mut fixture = TwoFactorServiceTestFixture::default();
+
+    arrange_pending_challenge(&mut fixture);
+
+    fixture
+        .mock_totp_service
+        .expect_verify_code()
+        .times(1)
+        .return_once(move |_, _| Ok(None));
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .with(eq("000000"))
+        .times(1)
+        .return_once(move |_| String::from("stub recovery code hash"));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(0);
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_users_repository
+        .expect_use_recovery_code()
+        .withf(|_, user_id, code_hash| *user_id == 1 && code_hash == "stub recovery code hash")
+        .times(1)
+        .return_once(move |_, _, _| Ok(false));
+
+    fixture
+        .mock_sessions_repository
+        .expect_record_login_challenge_attempt()
+        .with(eq(1_i64))
+        .times(1)
+        .return_once(move |_| Ok(1));
+
+    fixture.mock_sessions_repository.expect_use_login_challenge().times(0);
+
+    fixture.mock_token_service.expect_new_token().times(0);
+
+    let two_factor_service = new_two_factor_service(fixture);
+
+    // act
+    let response = two_factor_service
+        .complete_login(String::from("stub challenge"), String::from("000000"))
+        .await;
+
+    // assert
+    assert!(response.is_err());
+}
+
+#[tokio::test]
+async fn return_error_when_code_was_already_used() {
+    // arrange
+    let mut fixture = TwoFactorServiceTestFixture::default();
+
+    arrange_pending_challenge(&mut fixture);
+
+    fixture
+        .mock_totp_service
+        .expect_verify_code()
+        .times(1)
+        .return_once(move |_, _| Ok(Some(42)));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(0);
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_users_repository
+        .expect_update_user_totp_last_used_step()
+        .times(1)
+        .return_once(move |_, _, _| Ok(false));
+
+    fixture
+        .mock_sessions_repository
+        .expect_record_login_challenge_attempt()
+        .times(1)
+        .return_once(move |_| Ok(1));
+
+    fixture.mock_token_service.expect_new_token().times(0);
+
+    let two_factor_service = new_two_factor_service(fixture);
+
+    // act
+    let response = two_factor_service
+        .complete_login(String::from("stub challenge"), String::from("123456"))
+        .await;
+
+    // assert
+    assert!(response.is_err());
+}
@@ -0,0 +1,187 @@
+use std::ops::Sub;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use conduit_core::users::service::UsersService;
+use conduit_infrastructure::mocks::{stub_password_reset_token_entity, UsersServiceTestFixture};
+use mockall::predicate::*;
+use sqlx::types::time::OffsetDateTime;
+
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::users::repository::{DynUsersRepository, PasswordResetTokenEntity};
+use conduit_core::utils::mail_service::DynMailService;
+use conduit_core::utils::security_service::DynSecurityService;
+use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+
+use conduit_infrastructure::services::users_service::ConduitUsersService;
+
+fn new_users_service(fixture: UsersServiceTestFixture) -> ConduitUsersService {
+    ConduitUsersService::new(
+        Arc::new(fixture.mock_repository) as DynUsersRepository,
+        Arc::new(fixture.mock_sessions_repository) as DynSessionsRepository,
+        Arc::new(fixture.mock_security_service) as DynSecurityService,
+        Arc::new(fixture.mock_token_service) as DynTokenService,
+        Arc::new(fixture.mock_mail_service) as DynMailService,
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+        fixture.config,
+    )
+}
+
+#[tokio::test]
+async fn reset_password_and_revoke_sessions_when_token_is_valid() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .returning(|token| format!("{} hash", token));
+
+    fixture
+        .mock_repository
+        .expect_get_password_reset_token()
+        .with(eq(String::from("stub reset token hash")))
+        .times(1)
+        .return_once(move |_| Ok(Some(stub_password_reset_token_entity())));
+
+    fixture
+        .mock_security_service
+        .expect_hash_password()
+        .with(eq("new password"))
+        .times(1)
+        .return_once(move |_| Ok(String::from("hashed password")));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_repository
+        .expect_use_password_reset_token()
+        .withf(|_, id| *id == 1)
+        .times(1)
+        .return_once(move |_, _| Ok(true));
+
+    fixture
+        .mock_repository
+        .expect_update_user_password()
+        .withf(|_, id, hashed_password| *id == 1 && hashed_password == "hashed password")
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture
+        .mock_sessions_repository
+        .expect_revoke_user_refresh_tokens()
+        .withf(|_, user_id| *user_id == 1)
+        .times(1)
+        .return_once(move |_, _| Ok(()));
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service
+        .confirm_password_reset(String::from("stub reset token"), String::from("new password"))
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn return_error_when_token_has_expired() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .returning(|token| format!("{} hash", token));
+
+    fixture
+        .mock_repository
+        .expect_get_password_reset_token()
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(PasswordResetTokenEntity {
+                expires_at: OffsetDateTime::from(SystemTime::now().sub(Duration::from_secs(60))),
+                ..stub_password_reset_token_entity()
+            }))
+        });
+
+    fixture.mock_unit_of_work_factory.expect_begin().times(0);
+
+    fixture.mock_repository.expect_update_user_password().times(0);
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service
+        .confirm_password_reset(String::from("stub reset token"), String::from("new password"))
+        .await;
+
+    // assert
+    assert!(response.is_err());
+}
+
+#[tokio::test]
+async fn not_commit_when_token_was_redeemed_concurrently() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .returning(|token| format!("{} hash", token));
+
+    fixture
+        .mock_repository
+        .expect_get_password_reset_token()
+        .times(1)
+        .return_once(move |_| Ok(Some(stub_password_reset_token_entity())));
+
+    fixture
+        .mock_security_service
+        .expect_hash_password()
+        .times(1)
+        .return_once(move |_| Ok(String::from("hashed password")));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(0);
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_repository
+        .expect_use_password_reset_token()
+        .times(1)
+        .return_once(move |_, _| Ok(false));
+
+    fixture.mock_repository.expect_update_user_password().times(0);
+
+    fixture
+        .mock_sessions_repository
+        .expect_revoke_user_refresh_tokens()
+        .times(0);
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service
+        .confirm_password_reset(String::from("stub reset token"), String::from("new password"))
+        .await;
+
+    // assert
+    assert!(response.is_err());
+}
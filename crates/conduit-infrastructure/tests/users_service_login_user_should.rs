@@ -1,21 +1,48 @@
+use std::ops::Add;
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
+use conduit_core::errors::ConduitError;
 use conduit_core::users::service::UsersService;
 use conduit_infrastructure::mocks::UsersServiceTestFixture;
 use mockall::predicate::*;
 
 use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::users::roles::Role;
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::utils::mail_service::DynMailService;
 use conduit_core::utils::security_service::DynSecurityService;
 use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
 use conduit_domain::users::requests::LoginUserDto;
+use conduit_domain::users::LoginResultDto;
+use sqlx::types::time::OffsetDateTime;
 
 use conduit_infrastructure::services::users_service::ConduitUsersService;
 
+fn new_users_service(fixture: UsersServiceTestFixture) -> ConduitUsersService {
+    ConduitUsersService::new(
+        Arc::new(fixture.mock_repository) as DynUsersRepository,
+        Arc::new(fixture.mock_sessions_repository) as DynSessionsRepository,
+        Arc::new(fixture.mock_security_service) as DynSecurityService,
+        Arc::new(fixture.mock_token_service) as DynTokenService,
+        Arc::new(fixture.mock_mail_service) as DynMailService,
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+        fixture.config,
+    )
+}
+
 #[tokio::test]
 async fn return_success_when_downstream_services_succeedand_user_exists() {
     // arrange
     let mut fixture = UsersServiceTestFixture::default();
 
+    fixture
+        .mock_sessions_repository
+        .expect_get_login_lockout()
+        .times(1)
+        .return_once(move |_| Ok(None));
+
     fixture
         .mock_repository
         .expect_get_user_by_email()
@@ -30,21 +57,33 @@ async fn return_success_when_downstream_services_succeedand_user_exists() {
         .times(1)
         .return_once(move |_, _| Ok(true));
 
+    fixture
+        .mock_security_service
+        .expect_needs_rehash()
+        .with(eq("hashed password"))
+        .times(1)
+        .return_once(move |_| Ok(false));
+
+    fixture.mock_repository.expect_update_user_password().times(0);
+
     fixture
         .mock_token_service
         .expect_new_token()
-        .with(eq(1_i64), eq("stub email"))
+        .with(eq(1_i64), eq("stub email"), eq(Role::User))
         .times(1)
-        .return_once(move |_, _| Ok(String::from("stub token")));
+        .return_once(move |_, _, _| Ok(String::from("stub token")));
 
-    let users_service = ConduitUsersService::new(
-        Arc::new(fixture.mock_repository) as DynUsersRepository,
-        Arc::new(fixture.mock_security_service) as DynSecurityService,
-        Arc::new(fixture.mock_token_service) as DynTokenService,
-    );
+    fixture
+        .mock_sessions_repository
+        .expect_clear_login_failures()
+        .with(eq(String::from("account:stub email")))
+        .times(1)
+        .return_once(move |_| Ok(()));
+
+    let users_service = new_users_service(fixture);
 
     // act
-    let response = users_service.login_user(LoginUserDto::new_stub()).await;
+    let response = users_service.login_user(LoginUserDto::new_stub(), None).await;
 
     // assert
     assert!(response.is_ok());
@@ -55,6 +94,12 @@ async fn return_error_when_user_password_is_invalid() {
     // arrange
     let mut fixture = UsersServiceTestFixture::default();
 
+    fixture
+        .mock_sessions_repository
+        .expect_get_login_lockout()
+        .times(1)
+        .return_once(move |_| Ok(None));
+
     fixture
         .mock_repository
         .expect_get_user_by_email()
@@ -71,14 +116,19 @@ async fn return_error_when_user_password_is_invalid() {
 
     fixture.mock_token_service.expect_new_token().times(0);
 
-    let users_service = ConduitUsersService::new(
-        Arc::new(fixture.mock_repository) as DynUsersRepository,
-        Arc::new(fixture.mock_security_service) as DynSecurityService,
-        Arc::new(fixture.mock_token_service) as DynTokenService,
-    );
+    fixture
+        .mock_sessions_repository
+        .expect_record_login_failure()
+        .with(eq(String::from("account:stub email")), eq(15_i64))
+        .times(1)
+        .return_once(move |_, _| Ok(1));
+
+    fixture.mock_sessions_repository.expect_lock_login().times(0);
+
+    let users_service = new_users_service(fixture);
 
     // act
-    let response = users_service.login_user(LoginUserDto::new_stub()).await;
+    let response = users_service.login_user(LoginUserDto::new_stub(), None).await;
 
     // assert
     assert!(response.is_err());
@@ -89,6 +139,12 @@ async fn return_error_when_user_exixst_does_not_exist() {
     // arrange
     let mut fixture = UsersServiceTestFixture::default();
 
+    fixture
+        .mock_sessions_repository
+        .expect_get_login_lockout()
+        .times(1)
+        .return_once(move |_| Ok(None));
+
     fixture
         .mock_repository
         .expect_get_user_by_email()
@@ -96,19 +152,265 @@ async fn return_error_when_user_exixst_does_not_exist() {
         .times(1)
         .return_once(move |_| Ok(None));
 
-    fixture.mock_security_service.expect_verify_password().times(0);
+    fixture
+        .mock_security_service
+        .expect_verify_password()
+        .with(eq("stub dummy password hash"), eq("stub password".to_string()))
+        .times(1)
+        .return_once(move |_, _| Ok(false));
 
     fixture.mock_token_service.expect_new_token().times(0);
 
-    let users_service = ConduitUsersService::new(
-        Arc::new(fixture.mock_repository) as DynUsersRepository,
-        Arc::new(fixture.mock_security_service) as DynSecurityService,
-        Arc::new(fixture.mock_token_service) as DynTokenService,
-    );
+    fixture
+        .mock_sessions_repository
+        .expect_record_login_failure()
+        .with(eq(String::from("account:stub email")), eq(15_i64))
+        .times(1)
+        .return_once(move |_, _| Ok(1));
+
+    fixture.mock_sessions_repository.expect_lock_login().times(0);
+
+    let users_service = new_users_service(fixture);
 
     // act
-    let response = users_service.login_user(LoginUserDto::new_stub()).await;
+    let response = users_service.login_user(LoginUserDto::new_stub(), None).await;
 
     // assert
     assert!(response.is_err());
 }
+
+#[tokio::test]
+async fn rehash_password_when_stored_hash_is_outdated() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_sessions_repository
+        .expect_get_login_lockout()
+        .times(1)
+        .return_once(move |_| Ok(None));
+
+    fixture
+        .mock_repository
+        .expect_get_user_by_email()
+        .with(eq("stub email"))
+        .times(1)
+        .return_once(move |_| Ok(Some(UserEntity::default())));
+
+    fixture
+        .mock_security_service
+        .expect_verify_password()
+        .with(eq("hashed password"), eq("stub password".to_string()))
+        .times(1)
+        .return_once(move |_, _| Ok(true));
+
+    fixture
+        .mock_security_service
+        .expect_needs_rehash()
+        .with(eq("hashed password"))
+        .times(1)
+        .return_once(move |_| Ok(true));
+
+    fixture
+        .mock_security_service
+        .expect_hash_password()
+        .with(eq("stub password"))
+        .times(1)
+        .return_once(move |_| Ok(String::from("rehashed password")));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_repository
+        .expect_update_user_password()
+        .withf(|_, id, hashed_password| *id == 1 && hashed_password == "rehashed password")
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture
+        .mock_token_service
+        .expect_new_token()
+        .with(eq(1_i64), eq("stub email"), eq(Role::User))
+        .times(1)
+        .return_once(move |_, _, _| Ok(String::from("stub token")));
+
+    fixture
+        .mock_sessions_repository
+        .expect_clear_login_failures()
+        .with(eq(String::from("account:stub email")))
+        .times(1)
+        .return_once(move |_| Ok(()));
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service.login_user(LoginUserDto::new_stub(), None).await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn return_challenge_when_two_factor_is_enabled() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_sessions_repository
+        .expect_get_login_lockout()
+        .times(1)
+        .return_once(move |_| Ok(None));
+
+    fixture
+        .mock_repository
+        .expect_get_user_by_email()
+        .with(eq("stub email"))
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(UserEntity {
+                totp_secret: Some(String::from("stub secret")),
+                totp_enabled_at: Some(OffsetDateTime::now_utc()),
+                ..Default::default()
+            }))
+        });
+
+    fixture
+        .mock_security_service
+        .expect_verify_password()
+        .times(1)
+        .return_once(move |_, _| Ok(true));
+
+    fixture
+        .mock_security_service
+        .expect_needs_rehash()
+        .times(1)
+        .return_once(move |_| Ok(false));
+
+    fixture
+        .mock_token_service
+        .expect_new_opaque_token()
+        .times(1)
+        .return_once(move || String::from("stub challenge"));
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .with(eq("stub challenge"))
+        .times(1)
+        .return_once(move |_| String::from("stub challenge hash"));
+
+    fixture
+        .mock_sessions_repository
+        .expect_create_login_challenge()
+        .withf(|user_id, token_hash, _| *user_id == 1 && token_hash == "stub challenge hash")
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
+    fixture.mock_token_service.expect_new_token().times(0);
+
+    fixture
+        .mock_sessions_repository
+        .expect_clear_login_failures()
+        .with(eq(String::from("account:stub email")))
+        .times(1)
+        .return_once(move |_| Ok(()));
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service.login_user(LoginUserDto::new_stub(), None).await;
+
+    // assert
+    assert!(matches!(
+        response,
+        Ok(LoginResultDto::TwoFactorRequired(challenge)) if challenge.challenge_token == "stub challenge"
+    ));
+}
+
+#[tokio::test]
+async fn return_too_many_attempts_when_login_is_locked_out() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_sessions_repository
+        .expect_get_login_lockout()
+        .withf(|keys| keys == &vec![String::from("account:stub email"), String::from("ip:127.0.0.1")])
+        .times(1)
+        .return_once(move |_| {
+            Ok(Some(OffsetDateTime::from(
+                SystemTime::now().add(Duration::from_secs(60)),
+            )))
+        });
+
+    fixture.mock_repository.expect_get_user_by_email().times(0);
+
+    fixture.mock_security_service.expect_verify_password().times(0);
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service
+        .login_user(LoginUserDto::new_stub(), Some(String::from("127.0.0.1")))
+        .await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::TooManyLoginAttempts(seconds)) if seconds > 0 && seconds <= 60));
+}
+
+#[tokio::test]
+async fn lock_out_account_with_increasing_delay_after_too_many_failures() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_sessions_repository
+        .expect_get_login_lockout()
+        .times(1)
+        .return_once(move |_| Ok(None));
+
+    fixture
+        .mock_repository
+        .expect_get_user_by_email()
+        .times(1)
+        .return_once(move |_| Ok(Some(UserEntity::default())));
+
+    fixture
+        .mock_security_service
+        .expect_verify_password()
+        .times(1)
+        .return_once(move |_, _| Ok(false));
+
+    fixture
+        .mock_sessions_repository
+        .expect_record_login_failure()
+        .times(1)
+        .return_once(move |_, _| Ok(7));
+
+    // two failures past the limit of five doubles the 30 second lockout twice
+    fixture
+        .mock_sessions_repository
+        .expect_lock_login()
+        .withf(|key, locked_until| {
+            let lockout = *locked_until - OffsetDateTime::now_utc();
+            key == "account:stub email" && lockout.whole_seconds() > 110 && lockout.whole_seconds() <= 120
+        })
+        .times(1)
+        .return_once(move |_, _| Ok(()));
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service.login_user(LoginUserDto::new_stub(), None).await;
+
+    // assert
+    assert!(matches!(response, Err(ConduitError::InvalidLoginAttmpt)));
+}
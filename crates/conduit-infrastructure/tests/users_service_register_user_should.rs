@@ -1,16 +1,33 @@
 use std::sync::Arc;
 
+use conduit_core::errors::ConduitError;
 use conduit_core::users::service::UsersService;
 use conduit_infrastructure::mocks::UsersServiceTestFixture;
 use mockall::predicate::*;
 
 use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::users::roles::Role;
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::utils::mail_service::DynMailService;
 use conduit_core::utils::security_service::DynSecurityService;
 use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
 use conduit_domain::users::requests::RegisterUserDto;
 
 use conduit_infrastructure::services::users_service::ConduitUsersService;
 
+fn new_users_service(fixture: UsersServiceTestFixture) -> ConduitUsersService {
+    ConduitUsersService::new(
+        Arc::new(fixture.mock_repository) as DynUsersRepository,
+        Arc::new(fixture.mock_sessions_repository) as DynSessionsRepository,
+        Arc::new(fixture.mock_security_service) as DynSecurityService,
+        Arc::new(fixture.mock_token_service) as DynTokenService,
+        Arc::new(fixture.mock_mail_service) as DynMailService,
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+        fixture.config,
+    )
+}
+
 #[tokio::test]
 async fn return_success_when_downstream_services_succeed() {
     // arrange
@@ -26,9 +43,11 @@ async fn return_success_when_downstream_services_succeed() {
     fixture
         .mock_repository
         .expect_create_user()
-        .with(eq("stub email"), eq("stub username"), eq("hashed password"))
+        .withf(|_, email, username, hashed_password| {
+            email == "stub email" && username == "stub username" && hashed_password == "hashed password"
+        })
         .times(1)
-        .return_once(move |_, _, _| Ok(UserEntity::default()));
+        .return_once(move |_, _, _, _| Ok(UserEntity::default()));
 
     fixture
         .mock_security_service
@@ -37,24 +56,58 @@ async fn return_success_when_downstream_services_succeed() {
         .times(1)
         .return_once(move |_| Ok(String::from("hashed password")));
 
+    fixture
+        .mock_token_service
+        .expect_new_opaque_token()
+        .times(1)
+        .return_once(|| String::from("stub verification token"));
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .returning(|token| format!("{} hash", token));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_repository
+        .expect_create_email_verification_token()
+        .withf(|_, user_id, email, token_hash, _| {
+            *user_id == 1 && email == "stub email" && token_hash == "stub verification token hash"
+        })
+        .times(1)
+        .return_once(move |_, _, _, _, _| Ok(()));
+
+    fixture
+        .mock_mail_service
+        .expect_send_mail()
+        .withf(|to, _, body| to == "stub email" && body.contains("/users/verify?token=stub verification token"))
+        .times(1)
+        .return_once(move |_, _, _| Ok(()));
+
     fixture
         .mock_token_service
         .expect_new_token()
-        .with(eq(1), eq("stub email"))
+        .with(eq(1), eq("stub email"), eq(Role::User))
         .times(1)
-        .return_once(move |_, _| Ok(String::from("stub token")));
+        .return_once(move |_, _, _| Ok(String::from("stub token")));
 
-    let users_service = ConduitUsersService::new(
-        Arc::new(fixture.mock_repository) as DynUsersRepository,
-        Arc::new(fixture.mock_security_service) as DynSecurityService,
-        Arc::new(fixture.mock_token_service) as DynTokenService,
-    );
+    let users_service = new_users_service(fixture);
 
     // act
     let response = users_service.register_user(RegisterUserDto::new_stub()).await;
 
     // assert
     assert!(response.is_ok());
+    assert!(!response.unwrap().verified);
 }
 
 #[tokio::test]
@@ -75,11 +128,7 @@ async fn return_error_when_user_exixsts() {
 
     fixture.mock_token_service.expect_new_token().times(0);
 
-    let users_service = ConduitUsersService::new(
-        Arc::new(fixture.mock_repository) as DynUsersRepository,
-        Arc::new(fixture.mock_security_service) as DynSecurityService,
-        Arc::new(fixture.mock_token_service) as DynTokenService,
-    );
+    let users_service = new_users_service(fixture);
 
     // act
     let response = users_service.register_user(RegisterUserDto::new_stub()).await;
@@ -87,3 +136,84 @@ async fn return_error_when_user_exixsts() {
     // assert
     assert!(response.is_err());
 }
+
+#[tokio::test]
+async fn keep_user_when_verification_mail_cannot_be_sent() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_repository
+        .expect_search_user_by_email_or_username()
+        .with(eq("stub email"), eq("stub username"))
+        .times(1)
+        .return_once(move |_, _| Ok(None));
+
+    fixture
+        .mock_repository
+        .expect_create_user()
+        .withf(|_, email, username, hashed_password| {
+            email == "stub email" && username == "stub username" && hashed_password == "hashed password"
+        })
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(UserEntity::default()));
+
+    fixture
+        .mock_security_service
+        .expect_hash_password()
+        .with(eq("stub password"))
+        .times(1)
+        .return_once(move |_| Ok(String::from("hashed password")));
+
+    fixture
+        .mock_token_service
+        .expect_new_opaque_token()
+        .times(1)
+        .return_once(|| String::from("stub verification token"));
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .returning(|token| format!("{} hash", token));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_repository
+        .expect_create_email_verification_token()
+        .withf(|_, user_id, email, token_hash, _| {
+            *user_id == 1 && email == "stub email" && token_hash == "stub verification token hash"
+        })
+        .times(1)
+        .return_once(move |_, _, _, _, _| Ok(()));
+
+    fixture
+        .mock_mail_service
+        .expect_send_mail()
+        .times(1)
+        .return_once(move |_, _, _| Err(ConduitError::InternalServerErrorWithContext(String::from("stub error"))));
+
+    fixture
+        .mock_token_service
+        .expect_new_token()
+        .with(eq(1), eq("stub email"), eq(Role::User))
+        .times(1)
+        .return_once(move |_, _, _| Ok(String::from("stub token")));
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service.register_user(RegisterUserDto::new_stub()).await;
+
+    // assert
+    assert!(response.is_ok());
+    assert!(!response.unwrap().verified);
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use conduit_core::errors::ConduitError;
+use conduit_core::users::service::UsersService;
+use conduit_infrastructure::mocks::UsersServiceTestFixture;
+use mockall::predicate::*;
+
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::utils::mail_service::DynMailService;
+use conduit_core::utils::security_service::DynSecurityService;
+use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+
+use conduit_infrastructure::services::users_service::ConduitUsersService;
+
+fn new_users_service(fixture: UsersServiceTestFixture) -> ConduitUsersService {
+    ConduitUsersService::new(
+        Arc::new(fixture.mock_repository) as DynUsersRepository,
+        Arc::new(fixture.mock_sessions_repository) as DynSessionsRepository,
+        Arc::new(fixture.mock_security_service) as DynSecurityService,
+        Arc::new(fixture.mock_token_service) as DynTokenService,
+        Arc::new(fixture.mock_mail_service) as DynMailService,
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+        fixture.config,
+    )
+}
+
+#[tokio::test]
+async fn return_success_without_mailing_when_user_does_not_exist() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_repository
+        .expect_get_user_by_email()
+        .with(eq("unknown email"))
+        .times(1)
+        .return_once(move |_| Ok(None));
+
+    fixture.mock_unit_of_work_factory.expect_begin().times(0);
+    fixture.mock_mail_service.expect_send_mail().times(0);
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service
+        .request_password_reset(String::from("unknown email"))
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn return_success_when_mail_delivery_fails() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_repository
+        .expect_get_user_by_email()
+        .with(eq("stub email"))
+        .times(1)
+        .return_once(move |_| Ok(Some(UserEntity::default())));
+
+    fixture
+        .mock_token_service
+        .expect_new_opaque_token()
+        .times(1)
+        .return_once(|| String::from("stub reset token"));
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .returning(|token| format!("{} hash", token));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_repository
+        .expect_invalidate_password_reset_tokens()
+        .withf(|_, user_id| *user_id == 1)
+        .times(1)
+        .return_once(move |_, _| Ok(()));
+
+    fixture
+        .mock_repository
+        .expect_create_password_reset_token()
+        .withf(|_, user_id, token_hash, _| *user_id == 1 && token_hash == "stub reset token hash")
+        .times(1)
+        .return_once(move |_, _, _, _| Ok(()));
+
+    fixture
+        .mock_mail_service
+        .expect_send_mail()
+        .times(1)
+        .return_once(move |_, _, _| Err(ConduitError::InternalServerErrorWithContext(String::from("stub error"))));
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service.request_password_reset(String::from("stub email")).await;
+
+    // assert
+    assert!(response.is_ok());
+}
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use conduit_core::errors::ConduitError;
+use conduit_core::users::service::UsersService;
+use conduit_infrastructure::mocks::UsersServiceTestFixture;
+use mockall::predicate::*;
+
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::users::repository::{DynUsersRepository, UserEntity};
+use conduit_core::users::roles::Role;
+use conduit_core::utils::mail_service::DynMailService;
+use conduit_core::utils::security_service::DynSecurityService;
+use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+use conduit_domain::users::requests::UpdateUserDto;
+
+use conduit_infrastructure::services::users_service::ConduitUsersService;
+
+fn new_users_service(fixture: UsersServiceTestFixture) -> ConduitUsersService {
+    ConduitUsersService::new(
+        Arc::new(fixture.mock_repository) as DynUsersRepository,
+        Arc::new(fixture.mock_sessions_repository) as DynSessionsRepository,
+        Arc::new(fixture.mock_security_service) as DynSecurityService,
+        Arc::new(fixture.mock_token_service) as DynTokenService,
+        Arc::new(fixture.mock_mail_service) as DynMailService,
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+        fixture.config,
+    )
+}
+
+fn new_update_email_request() -> UpdateUserDto {
+    UpdateUserDto {
+        email: Some(String::from("updated email")),
+        username: None,
+        password: None,
+        bio: None,
+        image: None,
+    }
+}
+
+#[tokio::test]
+async fn keep_updated_email_when_verification_mail_cannot_be_sent() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_repository
+        .expect_get_user_by_id()
+        .with(eq(1))
+        .times(1)
+        .return_once(move |_| Ok(UserEntity::default()));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_repository
+        .expect_update_user()
+        .withf(|_, id, email, _, _, _, _| *id == 1 && email == "updated email")
+        .times(1)
+        .return_once(move |_, _, email, _, _, _, _| {
+            Ok(UserEntity {
+                email,
+                ..UserEntity::default()
+            })
+        });
+
+    fixture
+        .mock_token_service
+        .expect_new_opaque_token()
+        .times(1)
+        .return_once(|| String::from("stub verification token"));
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .returning(|token| format!("{} hash", token));
+
+    fixture
+        .mock_repository
+        .expect_create_email_verification_token()
+        .withf(|_, user_id, email, _, _| *user_id == 1 && email == "updated email")
+        .times(1)
+        .return_once(move |_, _, _, _, _| Ok(()));
+
+    fixture
+        .mock_mail_service
+        .expect_send_mail()
+        .withf(|to, _, _| to == "updated email")
+        .times(1)
+        .return_once(move |_, _, _| Err(ConduitError::InternalServerErrorWithContext(String::from("stub error"))));
+
+    fixture
+        .mock_token_service
+        .expect_new_token()
+        .with(eq(1), eq("updated email"), eq(Role::User))
+        .times(1)
+        .return_once(move |_, _, _| Ok(String::from("stub token")));
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service.updated_user(1, new_update_email_request()).await;
+
+    // assert
+    assert!(response.is_ok());
+    assert_eq!(response.unwrap().email, "updated email");
+}
+
+#[tokio::test]
+async fn not_create_verification_token_when_email_is_unchanged() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_repository
+        .expect_get_user_by_id()
+        .with(eq(1))
+        .times(1)
+        .return_once(move |_| Ok(UserEntity::default()));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_repository
+        .expect_update_user()
+        .withf(|_, id, email, _, _, _, _| *id == 1 && email == "stub email")
+        .times(1)
+        .return_once(move |_, _, _, _, _, _, _| Ok(UserEntity::default()));
+
+    fixture
+        .mock_repository
+        .expect_create_email_verification_token()
+        .times(0);
+
+    fixture.mock_mail_service.expect_send_mail().times(0);
+
+    fixture
+        .mock_token_service
+        .expect_new_token()
+        .with(eq(1), eq("stub email"), eq(Role::User))
+        .times(1)
+        .return_once(move |_, _, _| Ok(String::from("stub token")));
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service
+        .updated_user(
+            1,
+            UpdateUserDto {
+                email: Some(String::from("stub email")),
+                ..new_update_email_request()
+            },
+        )
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use conduit_core::users::service::UsersService;
+use conduit_infrastructure::mocks::{stub_email_verification_token_entity, UsersServiceTestFixture};
+use mockall::predicate::*;
+
+use conduit_core::sessions::repository::DynSessionsRepository;
+use conduit_core::users::repository::DynUsersRepository;
+use conduit_core::utils::mail_service::DynMailService;
+use conduit_core::utils::security_service::DynSecurityService;
+use conduit_core::utils::token_service::DynTokenService;
+use conduit_core::utils::unit_of_work::{DynUnitOfWorkFactory, MockUnitOfWork, UnitOfWork};
+
+use conduit_infrastructure::services::users_service::ConduitUsersService;
+
+fn new_users_service(fixture: UsersServiceTestFixture) -> ConduitUsersService {
+    ConduitUsersService::new(
+        Arc::new(fixture.mock_repository) as DynUsersRepository,
+        Arc::new(fixture.mock_sessions_repository) as DynSessionsRepository,
+        Arc::new(fixture.mock_security_service) as DynSecurityService,
+        Arc::new(fixture.mock_token_service) as DynTokenService,
+        Arc::new(fixture.mock_mail_service) as DynMailService,
+        Arc::new(fixture.mock_unit_of_work_factory) as DynUnitOfWorkFactory,
+        fixture.config,
+    )
+}
+
+#[tokio::test]
+async fn verify_email_when_token_is_valid() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .returning(|token| format!("{} hash", token));
+
+    fixture
+        .mock_repository
+        .expect_get_email_verification_token()
+        .with(eq(String::from("stub verification token hash")))
+        .times(1)
+        .return_once(move |_| Ok(Some(stub_email_verification_token_entity())));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(1).return_once(|| Ok(()));
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_repository
+        .expect_use_email_verification_token()
+        .withf(|_, id| *id == 1)
+        .times(1)
+        .return_once(move |_, _| Ok(true));
+
+    fixture
+        .mock_repository
+        .expect_verify_user_email()
+        .withf(|_, user_id, email| *user_id == 1 && email == "stub email")
+        .times(1)
+        .return_once(move |_, _, _| Ok(true));
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service
+        .verify_email(String::from("stub verification token"))
+        .await;
+
+    // assert
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn not_commit_when_email_has_changed_since_token_was_issued() {
+    // arrange
+    let mut fixture = UsersServiceTestFixture::default();
+
+    fixture
+        .mock_token_service
+        .expect_hash_opaque_token()
+        .returning(|token| format!("{} hash", token));
+
+    fixture
+        .mock_repository
+        .expect_get_email_verification_token()
+        .times(1)
+        .return_once(move |_| Ok(Some(stub_email_verification_token_entity())));
+
+    fixture
+        .mock_unit_of_work_factory
+        .expect_begin()
+        .times(1)
+        .return_once(|| {
+            let mut unit_of_work = MockUnitOfWork::new();
+            unit_of_work.expect_commit().times(0);
+            Ok(Box::new(unit_of_work) as Box<dyn UnitOfWork>)
+        });
+
+    fixture
+        .mock_repository
+        .expect_use_email_verification_token()
+        .times(1)
+        .return_once(move |_, _| Ok(true));
+
+    fixture
+        .mock_repository
+        .expect_verify_user_email()
+        .times(1)
+        .return_once(move |_, _, _| Ok(false));
+
+    let users_service = new_users_service(fixture);
+
+    // act
+    let response = users_service
+        .verify_email(String::from("stub verification token"))
+        .await;
+
+    // assert
+    assert!(response.is_err());
+}
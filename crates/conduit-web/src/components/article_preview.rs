@@ -2,7 +2,10 @@ use conduit_domain::articles::models::AuthorDto;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
-use crate::{components::author_profile_meta::AuthorProfileMeta, router::ConduitRouter};
+use crate::{
+    components::author_profile_meta::AuthorProfileMeta, router::ConduitRouter,
+    utilities::formatting::split_highlighted_snippet,
+};
 
 #[derive(Properties, PartialEq, Clone)]
 pub struct ArticlePreviewProps {
@@ -12,10 +15,25 @@ pub struct ArticlePreviewProps {
     pub created_date: String,
     pub description: String,
     pub favorites: usize,
+    #[prop_or_default]
+    pub snippet: Option<String>,
 }
 
 #[function_component(ArticlePreview)]
 pub fn article_preview(props: &ArticlePreviewProps) -> Html {
+    let snippet = props.snippet.as_ref().map(|snippet| {
+        split_highlighted_snippet(snippet)
+            .into_iter()
+            .map(|(text, is_highlighted)| {
+                if is_highlighted {
+                    html! { <mark>{text}</mark> }
+                } else {
+                    html! { {text} }
+                }
+            })
+            .collect::<Html>()
+    });
+
     html! {
         <div class="article-preview">
             <div class="article-meta">
@@ -31,6 +49,9 @@ pub fn article_preview(props: &ArticlePreviewProps) -> Html {
             <Link<ConduitRouter> classes="preview-link" to={ConduitRouter::Article { slug: props.slug.clone() }}>
                 <h1>{props.title.clone()}</h1>
                 <p>{props.description.clone()}</p>
+                if let Some(snippet) = snippet {
+                    <p class="article-snippet">{snippet}</p>
+                }
                 <>
                     { "Read more..." }
                 </>
@@ -23,6 +23,7 @@ pub fn article_previews(props: &ArticlePreviewsProps) -> Html {
                     created_date={article.created_at}
                     description={article.description}
                     favorites={article.favorites_count as usize}
+                    snippet={article.snippet}
                 />
             }
         })
@@ -0,0 +1,43 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct ArticleSearchProps {
+    pub on_search: Callback<String>,
+}
+
+#[function_component(ArticleSearch)]
+pub fn article_search(props: &ArticleSearchProps) -> Html {
+    let search = use_state(String::default);
+
+    let oninput = {
+        let search = search.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            search.set(input.value());
+        })
+    };
+
+    let onsubmit = {
+        let search = search.clone();
+        let on_search = props.on_search.clone();
+        Callback::from(move |e: FocusEvent| {
+            e.prevent_default();
+            on_search.emit((*search).clone());
+        })
+    };
+
+    html! {
+        <form class="article-search" {onsubmit}>
+            <fieldset class="form-group">
+                <input
+                    class="form-control"
+                    type="search"
+                    placeholder="Search articles"
+                    value={(*search).clone()}
+                    {oninput}
+                />
+            </fieldset>
+        </form>
+    }
+}
@@ -1,12 +1,17 @@
 use yew::prelude::*;
+use yew_router::prelude::*;
 
-use crate::utilities::formatting::convert_to_friend_date_string;
+use crate::router::ConduitRouter;
+use crate::utilities::formatting::{convert_to_friend_date_string, split_mentions};
 
 #[derive(Properties, PartialEq, Clone)]
 pub struct CommentCardProps {
     pub image: String,
     pub username: String,
     pub created_date: String,
+    pub body: String,
+    /// The usernames mentioned in the body, rendered as links to their profiles.
+    pub mentions: Vec<String>,
 }
 
 #[function_component(CommentCard)]
@@ -16,10 +21,20 @@ pub fn comment_card(props: &CommentCardProps) -> Html {
         convert_to_friend_date_string(props.created_date).unwrap_or_default()
     };
 
+    let body = split_mentions(&props.body, &props.mentions)
+        .into_iter()
+        .map(|(segment, username)| match username {
+            Some(username) => html! {
+                <Link<ConduitRouter> to={ConduitRouter::Profile { username }}>{segment}</Link<ConduitRouter>>
+            },
+            None => html! { <>{segment}</> },
+        })
+        .collect::<Html>();
+
     html! {
         <div class="card">
             <div class="card-block">
-                <p class="card-text">{"With supporting text below as a natural lead-in to additional content."}</p>
+                <p class="card-text">{body}</p>
             </div>
             <div class="card-footer">
                 <a href="" class="comment-author">
@@ -1,6 +1,7 @@
 pub mod active_link;
 pub mod article_preview;
 pub mod article_previews;
+pub mod article_search;
 pub mod authentication_error_list;
 pub mod authentication_form;
 pub mod author_profile_meta;
@@ -4,6 +4,7 @@ use crate::{
     components::{author_profile_meta::AuthorProfileMeta, comment_card::CommentCard},
     contexts::authentication_context::use_authentication_context,
     hooks::use_selected_article::{use_selected_article, UseSelectedArticleHook},
+    utilities::formatting::link_mentions,
 };
 
 #[derive(Properties, PartialEq, Clone)]
@@ -94,6 +95,17 @@ pub fn article(props: &ArticleProps) -> Html {
         }
     };
 
+    // the body is sanitized by the server before it's rendered, so its HTML is safe to attach to the page once
+    // mentions, which only ever name existing users, are linked to their profiles
+    let article_body = match &article.body_html {
+        Some(body_html) => {
+            let container = gloo_utils::document().create_element("div").unwrap();
+            container.set_inner_html(&link_mentions(body_html, &article.mentions));
+            Html::VRef(container.into())
+        },
+        None => html! { <p>{article.body.clone()}</p> },
+    };
+
     let user_comments = move || -> Html {
         let mapped_comments = comments
             .into_iter()
@@ -103,6 +115,8 @@ pub fn article(props: &ArticleProps) -> Html {
                         username={comment.author.username.clone()}
                         image={comment.author.image.as_ref().unwrap_or(&String::default()).to_owned()}
                         created_date={comment.created_at.clone()}
+                        body={comment.body.clone()}
+                        mentions={comment.mentions.clone()}
                     />
                 }
             })
@@ -134,7 +148,8 @@ pub fn article(props: &ArticleProps) -> Html {
                             {article.description}
                         </p>
                         <h2 id="introducing-ionic">{article.title}</h2>
-                        <p>{article.body}</p>
+                        <p class="reading-time">{format!("{} min read", article.reading_time_minutes)}</p>
+                        {article_body}
                     </div>
                 </div>
 
@@ -3,7 +3,9 @@ use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 
 use crate::{
-    components::{article_previews::ArticlePreviews, feed_toggle::FeedToggle, tag_list::TagList},
+    components::{
+        article_previews::ArticlePreviews, article_search::ArticleSearch, feed_toggle::FeedToggle, tag_list::TagList,
+    },
     contexts::articles_context::{use_article_context, ArticleActions},
     services::article_service::get_articles,
 };
@@ -15,6 +17,7 @@ pub fn home() -> Html {
     let author = use_state(String::default);
     let tag = use_state(String::default);
     let favorited = use_state(String::default);
+    let search = use_state(String::default);
 
     let context = use_article_context();
 
@@ -25,6 +28,7 @@ pub fn home() -> Html {
             current_author,
             current_tag,
             current_favorited,
+            current_search,
             current_articles_context,
         )| {
             let current_limit = (*current_limit).clone();
@@ -32,12 +36,13 @@ pub fn home() -> Html {
             let current_author = current_author.clone();
             let current_tag = current_tag.clone();
             let current_favorited = current_favorited.clone();
+            let current_search = current_search.clone();
             let current_articles_context = current_articles_context.clone();
 
             spawn_local(async move {
                 info!(
-                    "retrieving articles: limit={}, offset={}, author=\"{}\", tag=\"{}\", favorited=\"{}\"",
-                    *current_limit, *current_offset, *current_author, *current_tag, *current_favorited
+                    "retrieving articles: limit={}, offset={}, author=\"{}\", tag=\"{}\", favorited=\"{}\", search=\"{}\"",
+                    *current_limit, *current_offset, *current_author, *current_tag, *current_favorited, *current_search
                 );
 
                 let articles_response = get_articles(
@@ -46,6 +51,7 @@ pub fn home() -> Html {
                     (*current_author).clone(),
                     (*current_tag).clone(),
                     (*current_favorited).clone(),
+                    (*current_search).clone(),
                 )
                 .await;
 
@@ -62,9 +68,11 @@ pub fn home() -> Html {
 
             || ()
         },
-        (limit, offset, author, tag, favorited, context.clone()),
+        (limit, offset, author, tag, favorited, search.clone(), context.clone()),
     );
 
+    let on_search = Callback::from(move |query: String| search.set(query));
+
     html! {
         <div class="home-page">
             <div class="banner">
@@ -77,6 +85,7 @@ pub fn home() -> Html {
             <div class="container page">
                 <div class="row">
                     <div class="col-md-9">
+                        <ArticleSearch {on_search} />
                         <FeedToggle />
                         <ArticlePreviews articles={context.articles.clone()} />
                     </div>
@@ -29,6 +29,8 @@ pub async fn create_article(
         description: Some(description),
         body: Some(body),
         tag_list: tags,
+        status: None,
+        publish_at: None,
     };
 
     let create_article_response = post::<ArticleResponse, CreateArticleRequest>(
@@ -60,6 +62,7 @@ pub async fn get_articles(
     author: String,
     tag: String,
     favorited: String,
+    search: String,
 ) -> ConduitWebResult<ArticlesResponse> {
     let url = (*ARTICLES_ENDPOINT).to_string();
 
@@ -69,6 +72,7 @@ pub async fn get_articles(
         .with_author(author)
         .with_tag(tag)
         .with_favorited(favorited)
+        .with_search(search)
         .build();
 
     let get_article_response = get::<ArticlesResponse>(&param_builder.to_query_string()).await;
@@ -10,6 +10,143 @@ pub fn convert_to_friend_date_string(utc_date: String) -> ConduitWebResult<Strin
     Err(ConduitWebError::DateTimeInvalid)
 }
 
+/// Splits a search snippet on its `<mark>` tags into `(text, is_highlighted)` segments, so matches
+/// can be rendered as elements rather than injecting the snippet as raw HTML. The API escapes all
+/// other markup within the snippet, which is decoded back to text here.
+pub fn split_highlighted_snippet(snippet: &str) -> Vec<(String, bool)> {
+    let mut segments = Vec::new();
+    let mut remaining = snippet;
+
+    while let Some(start) = remaining.find("<mark>") {
+        if start > 0 {
+            segments.push((unescape_html(&remaining[..start]), false));
+        }
+
+        let highlighted = &remaining[start + "<mark>".len()..];
+        let end = highlighted.find("</mark>").unwrap_or(highlighted.len());
+        segments.push((unescape_html(&highlighted[..end]), true));
+        remaining = highlighted.get(end + "</mark>".len()..).unwrap_or_default();
+    }
+
+    if !remaining.is_empty() {
+        segments.push((unescape_html(remaining), false));
+    }
+
+    segments
+}
+
+/// Decodes the entities escaped within the text of sanitized HTML.
+fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&nbsp;", "\u{a0}")
+        .replace("&amp;", "&")
+}
+
+/// Splits text on the `@mentions` of the given usernames into `(text, mentioned_username)` segments, so mentions
+/// can be rendered as links to the mentioned users' profiles. Mentions of other usernames are left as plain text.
+pub fn split_mentions(text: &str, mentions: &[String]) -> Vec<(String, Option<String>)> {
+    let mut segments = Vec::new();
+    let mut plain_start = 0;
+    let mut previous: Option<char> = None;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((index, current)) = chars.next() {
+        let follows_boundary = !matches!(previous, Some(previous) if is_username_char(previous) || previous == '@');
+        previous = Some(current);
+
+        if current != '@' || !follows_boundary {
+            continue;
+        }
+
+        let username_start = index + current.len_utf8();
+        let mut username_end = username_start;
+
+        while let Some(&(next_index, next)) = chars.peek() {
+            if !is_username_char(next) {
+                break;
+            }
+
+            username_end = next_index + next.len_utf8();
+            previous = Some(next);
+            chars.next();
+        }
+
+        let username = &text[username_start..username_end];
+
+        if mentions.iter().any(|mention| mention == username) {
+            if index > plain_start {
+                segments.push((text[plain_start..index].to_owned(), None));
+            }
+
+            segments.push((text[index..username_end].to_owned(), Some(username.to_owned())));
+            plain_start = username_end;
+        }
+    }
+
+    if plain_start < text.len() {
+        segments.push((text[plain_start..].to_owned(), None));
+    }
+
+    segments
+}
+
+/// Links the `@mentions` of the given usernames within sanitized HTML to the mentioned users' profiles, leaving
+/// mentions within existing links and code untouched.
+pub fn link_mentions(html: &str, mentions: &[String]) -> String {
+    if mentions.is_empty() {
+        return html.to_owned();
+    }
+
+    let mut linked = String::with_capacity(html.len());
+    let mut unlinkable_depth = 0usize;
+    let mut remaining = html;
+
+    while !remaining.is_empty() {
+        let text_end = remaining.find('<').unwrap_or(remaining.len());
+        let (text, rest) = remaining.split_at(text_end);
+
+        for (segment, username) in split_mentions(text, mentions) {
+            match username {
+                Some(username) if unlinkable_depth == 0 => {
+                    linked.push_str(&format!("<a href=\"/profile/{}\">{}</a>", username, segment))
+                },
+                _ => linked.push_str(&segment),
+            }
+        }
+
+        if rest.is_empty() {
+            break;
+        }
+
+        let tag_end = rest.find('>').map_or(rest.len(), |end| end + 1);
+        let (tag, rest) = rest.split_at(tag_end);
+        let tag_name = tag
+            .trim_start_matches('<')
+            .split(|c: char| c.is_whitespace() || c == '>' || c == '/')
+            .find(|name| !name.is_empty())
+            .unwrap_or_default();
+
+        if matches!(tag_name, "a" | "code" | "pre") {
+            if tag.starts_with("</") {
+                unlinkable_depth = unlinkable_depth.saturating_sub(1);
+            } else {
+                unlinkable_depth += 1;
+            }
+        }
+
+        linked.push_str(tag);
+        remaining = rest;
+    }
+
+    linked
+}
+
+fn is_username_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
 #[cfg(test)]
 mod convert_to_friend_date_string_should {
     use super::convert_to_friend_date_string;
@@ -40,3 +177,100 @@ mod convert_to_friend_date_string_should {
         assert!(result.is_err());
     }
 }
+
+#[cfg(test)]
+mod split_highlighted_snippet_should {
+    use super::split_highlighted_snippet;
+
+    #[test]
+    pub fn return_highlighted_segments_for_marked_terms() {
+        // arrange
+        let snippet = "Learn about <mark>traits</mark> and <mark>generics</mark>";
+
+        // act
+        let result = split_highlighted_snippet(snippet);
+
+        // assert
+        assert_eq!(
+            result,
+            vec![
+                ("Learn about ".to_owned(), false),
+                ("traits".to_owned(), true),
+                (" and ".to_owned(), false),
+                ("generics".to_owned(), true),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn decode_escaped_markup_within_segments() {
+        // arrange
+        let snippet = "use &lt;mark&gt; &amp;&amp; <mark>Vec&lt;T&gt;</mark>";
+
+        // act
+        let result = split_highlighted_snippet(snippet);
+
+        // assert
+        assert_eq!(
+            result,
+            vec![("use <mark> && ".to_owned(), false), ("Vec<T>".to_owned(), true),]
+        );
+    }
+
+    #[test]
+    pub fn return_single_segment_when_nothing_is_marked() {
+        // arrange
+        let snippet = "nothing to see here";
+
+        // act
+        let result = split_highlighted_snippet(snippet);
+
+        // assert
+        assert_eq!(result, vec![("nothing to see here".to_owned(), false)]);
+    }
+}
+
+#[cfg(test)]
+mod split_mentions_should {
+    use super::split_mentions;
+
+    #[test]
+    pub fn return_mention_segments_for_mentioned_usernames() {
+        // arrange
+        let mentions = vec!["alice".to_owned()];
+
+        // act
+        let result = split_mentions("thanks @alice, ask @bob or alice@example.com", &mentions);
+
+        // assert
+        assert_eq!(
+            result,
+            vec![
+                ("thanks ".to_owned(), None),
+                ("@alice".to_owned(), Some("alice".to_owned())),
+                (", ask @bob or alice@example.com".to_owned(), None),
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod link_mentions_should {
+    use super::link_mentions;
+
+    #[test]
+    pub fn link_mentions_outside_of_code_and_existing_links() {
+        // arrange
+        let mentions = vec!["alice".to_owned()];
+        let html = "<p>hi @alice, see <code>@alice</code> and <a href=\"/x\">@alice</a></p>";
+
+        // act
+        let result = link_mentions(html, &mentions);
+
+        // assert
+        assert_eq!(
+            result,
+            "<p>hi <a href=\"/profile/alice\">@alice</a>, see <code>@alice</code> and <a href=\"/x\">@alice</a></p>"
+        );
+    }
+}
@@ -13,6 +13,7 @@ impl PaginationQueryBuilder {
                 author: None,
                 tag: None,
                 favorited: None,
+                search: None,
             },
         }
     }
@@ -42,6 +43,11 @@ impl PaginationQueryBuilder {
         self.clone()
     }
 
+    pub fn with_search(&mut self, search: String) -> Self {
+        self.params.search = Some(search);
+        self.clone()
+    }
+
     pub fn build(self) -> ArticlePaginationQueryParams {
         self.params
     }
@@ -55,6 +61,7 @@ pub struct ArticlePaginationQueryParams {
     pub author: Option<String>,
     pub tag: Option<String>,
     pub favorited: Option<String>,
+    pub search: Option<String>,
 }
 
 impl ArticlePaginationQueryParams {
@@ -92,7 +99,15 @@ impl ArticlePaginationQueryParams {
             // `let` bindings with additional conditions are unstable for now, so we add a nested `if` here
             if !favorited.is_empty() {
                 param_count += 1;
-                query_string.push_str(&format!("favorited={}", *favorited));
+                query_string.push_str(&format!("favorited={}&", *favorited));
+            }
+        }
+
+        if let Some(search) = &self.search {
+            // `let` bindings with additional conditions are unstable for now, so we add a nested `if` here
+            if !search.trim().is_empty() {
+                param_count += 1;
+                query_string.push_str(&format!("q={}", encode_query_value(search)));
             }
         }
 
@@ -109,6 +124,17 @@ impl ArticlePaginationQueryParams {
     }
 }
 
+/// Percent-encodes a free-form query value, as search terms may contain spaces, quotes and other reserved characters.
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod article_pagination_query_params_should {
     use super::*;
@@ -276,4 +302,23 @@ mod article_pagination_query_params_should {
         // assert
         assert_eq!(expected_url.to_string(), query_string);
     }
+
+    #[test]
+    fn should_encode_search_terms_in_query_string() {
+        // arrange
+        let limit = 20_usize;
+        let search = "\"rust traits\" -async".to_string();
+        let expected_url = "https://reddit.com/r/rust?limit=20&q=%22rust%20traits%22%20-async";
+
+        // act
+        let params = PaginationQueryBuilder::new("https://reddit.com/r/rust".to_string())
+            .with_limit(limit)
+            .with_search(search)
+            .build();
+
+        let query_string = params.to_query_string();
+
+        // assert
+        assert_eq!(expected_url.to_string(), query_string);
+    }
 }